@@ -0,0 +1,15 @@
+-- Add owner column so that URLs are scoped to the JWT subject that created them
+-- Existing rows were all created with the shared guest token
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS owner VARCHAR(255) NOT NULL DEFAULT 'guest';
+
+-- Duplicate detection is now per owner: the same destinations created by
+-- different owners must result in separate URLs
+DROP INDEX IF EXISTS idx_urls_hashed_value_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_urls_owner_hashed_value_unique
+    ON urls(owner, hashed_value) WHERE deleted_at IS NULL;
+
+-- Index for listing an owner's URLs (ordered by ID)
+CREATE INDEX IF NOT EXISTS idx_urls_owner_id
+    ON urls(owner, id);
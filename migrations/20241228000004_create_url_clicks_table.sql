@@ -0,0 +1,22 @@
+-- Create url_clicks table for click analytics
+-- One row is written (in batches) for every successful redirect
+
+CREATE TABLE IF NOT EXISTS url_clicks (
+    id BIGSERIAL PRIMARY KEY,
+    -- Clicked URL
+    url_id BIGINT NOT NULL REFERENCES urls(id) ON DELETE CASCADE,
+    -- Time the redirect was served
+    clicked_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Raw User-Agent header (truncated)
+    user_agent TEXT,
+    -- Referer header (truncated)
+    referer TEXT,
+    -- Platform detected from the User-Agent (ios, android, other)
+    platform VARCHAR(16) NOT NULL,
+    -- Client IP truncated to its network prefix (/24 for IPv4, /48 for IPv6)
+    ip_prefix VARCHAR(64)
+);
+
+-- Index for per-URL time range queries (stats)
+CREATE INDEX IF NOT EXISTS idx_url_clicks_url_id_clicked_at
+    ON url_clicks(url_id, clicked_at);
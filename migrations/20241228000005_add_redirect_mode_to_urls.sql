@@ -0,0 +1,10 @@
+-- Add redirect_mode column
+-- interstitial: render the HTML redirect page (client-side platform detection)
+-- http_302 / http_301 / http_307: respond with a Location header directly
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS redirect_mode VARCHAR(16) NOT NULL DEFAULT 'interstitial';
+
+ALTER TABLE urls
+ADD CONSTRAINT urls_redirect_mode_check
+    CHECK (redirect_mode IN ('interstitial', 'http_302', 'http_301', 'http_307'));
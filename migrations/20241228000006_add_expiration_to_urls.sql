@@ -0,0 +1,11 @@
+-- Add link expiration by timestamp and by maximum click count
+-- Expired links respond with 410 Gone
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ,
+ADD COLUMN IF NOT EXISTS max_clicks BIGINT,
+-- Clicks counted against max_clicks (only incremented for capped links)
+ADD COLUMN IF NOT EXISTS click_count BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE urls
+ADD CONSTRAINT urls_max_clicks_positive CHECK (max_clicks IS NULL OR max_clicks > 0);
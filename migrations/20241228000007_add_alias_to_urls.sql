@@ -0,0 +1,8 @@
+-- Add vanity alias column (e.g. /spring-sale)
+-- Aliases are stored lowercase and are unique among non-deleted URLs
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS alias VARCHAR(64);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_urls_alias_unique
+    ON urls(alias) WHERE deleted_at IS NULL AND alias IS NOT NULL;
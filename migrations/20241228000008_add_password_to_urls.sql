@@ -0,0 +1,5 @@
+-- Add optional passcode protection
+-- Stores an Argon2id PHC string (salted hash), never the plain password
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS password_hash VARCHAR(255);
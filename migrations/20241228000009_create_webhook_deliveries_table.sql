@@ -0,0 +1,40 @@
+-- Durable webhook delivery
+-- Each link gets a signing secret; webhook requests are queued in an outbox
+-- and retried with exponential backoff until delivered or dead-lettered
+
+-- Per-link HMAC-SHA256 signing secret (64 hex characters)
+-- Volatile default: existing rows each get their own random secret
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS webhook_secret VARCHAR(64) NOT NULL
+    DEFAULT (replace(gen_random_uuid()::text, '-', '') || replace(gen_random_uuid()::text, '-', ''));
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id BIGSERIAL PRIMARY KEY,
+    -- Link whose webhook is called (its secret signs the body)
+    url_id BIGINT NOT NULL REFERENCES urls(id) ON DELETE CASCADE,
+    -- Target URL at the time the event happened
+    webhook_url TEXT NOT NULL,
+    -- Request body
+    payload JSONB NOT NULL,
+    -- pending: waiting for (re)delivery, delivered: 2xx received, dead: retries exhausted
+    status VARCHAR(16) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'delivered', 'dead')),
+    -- Delivery attempts made so far
+    attempts INTEGER NOT NULL DEFAULT 0,
+    -- Earliest time of the next attempt (also the lease of an in-flight attempt)
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- HTTP status and error of the last failed attempt
+    last_status_code INTEGER,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ
+);
+
+-- Index for polling due deliveries
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due
+    ON webhook_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+
+-- Index for inspecting a link's deliveries (e.g. dead letters)
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_url_id_status
+    ON webhook_deliveries(url_id, status);
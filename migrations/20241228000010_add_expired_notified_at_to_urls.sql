@@ -0,0 +1,20 @@
+-- Track the link.expired webhook event
+-- Set once the event for the current expiry has been queued, cleared when an
+-- update lifts the expiry so that a later expiry is reported again
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS expired_notified_at TIMESTAMPTZ;
+
+-- Links that expired before webhook events existed are not reported
+UPDATE urls
+SET expired_notified_at = NOW()
+WHERE expires_at <= NOW()
+   OR (max_clicks IS NOT NULL AND click_count >= max_clicks);
+
+-- Index for the expiry sweep (links with a webhook and a pending expiry event)
+CREATE INDEX IF NOT EXISTS idx_urls_expiry_sweep
+    ON urls(id)
+    WHERE expired_notified_at IS NULL
+      AND deleted_at IS NULL
+      AND webhook_url IS NOT NULL
+      AND (expires_at IS NOT NULL OR max_clicks IS NOT NULL);
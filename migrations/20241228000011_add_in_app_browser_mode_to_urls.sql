@@ -0,0 +1,12 @@
+-- Add in_app_browser_mode column
+-- escape: try to leave in-app webviews (KakaoTalk scheme, Android intent),
+--         showing the "open in browser" page when no escape is possible
+-- prompt: always show the "open in browser" page inside in-app webviews
+-- off: treat in-app webviews like regular browsers
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS in_app_browser_mode VARCHAR(16) NOT NULL DEFAULT 'escape';
+
+ALTER TABLE urls
+ADD CONSTRAINT urls_in_app_browser_mode_check
+    CHECK (in_app_browser_mode IN ('escape', 'prompt', 'off'));
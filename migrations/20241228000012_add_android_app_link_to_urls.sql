@@ -0,0 +1,10 @@
+-- Add Android app link columns
+-- The redirect page builds an intent:// URL from these instead of
+-- navigating to android_deep_link and falling back on a timer:
+-- intent://{android_path}#Intent;scheme={android_scheme};package={android_package};
+--     S.browser_fallback_url={android fallback};end
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS android_package VARCHAR(255),
+ADD COLUMN IF NOT EXISTS android_scheme VARCHAR(64),
+ADD COLUMN IF NOT EXISTS android_path TEXT;
@@ -0,0 +1,9 @@
+-- Add desktop app deep link and per-OS desktop fallback columns
+-- macOS, Windows and Linux visitors try desktop_deep_link first, then fall
+-- back to their OS fallback URL or default_fallback_url
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS desktop_deep_link TEXT,
+ADD COLUMN IF NOT EXISTS macos_fallback_url TEXT,
+ADD COLUMN IF NOT EXISTS windows_fallback_url TEXT,
+ADD COLUMN IF NOT EXISTS linux_fallback_url TEXT;
@@ -0,0 +1,7 @@
+-- Add ordered per-link routing rules
+-- Each rule sends visits matching its conditions (country, language, time
+-- window, device type, query parameters) to its destination; the first
+-- matching rule wins, and platform fallbacks apply when none matches
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS routing_rules JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,23 @@
+-- A/B split-traffic destinations
+-- A link with variants sends each visitor to one weighted destination,
+-- chosen by hashing a visitor cookie so repeat visits get the same variant
+
+CREATE TABLE IF NOT EXISTS url_variants (
+    id BIGSERIAL PRIMARY KEY,
+    -- Link whose traffic is split
+    url_id BIGINT NOT NULL REFERENCES urls(id) ON DELETE CASCADE,
+    -- Order of the variant within the link (weight ranges are laid out in this order)
+    position INTEGER NOT NULL,
+    -- Name recorded with clicks and webhook events (e.g. "a", "control")
+    name VARCHAR(64) NOT NULL,
+    -- URL the assigned visitors are sent to
+    destination TEXT NOT NULL,
+    -- Share of the traffic in percent; the weights of a link sum to 100
+    weight INTEGER NOT NULL CHECK (weight BETWEEN 1 AND 100),
+    UNIQUE (url_id, position),
+    UNIQUE (url_id, name)
+);
+
+-- Variant served by each click (NULL for links without variants)
+ALTER TABLE url_clicks
+ADD COLUMN IF NOT EXISTS variant VARCHAR(64);
@@ -0,0 +1,7 @@
+-- Add per-link UTM defaults and query passthrough
+-- utm_params holds the utm_* values merged into every destination;
+-- query_passthrough forwards the short URL's query parameters as well
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS utm_params JSONB NOT NULL DEFAULT '{}'::jsonb,
+ADD COLUMN IF NOT EXISTS query_passthrough BOOLEAN NOT NULL DEFAULT FALSE;
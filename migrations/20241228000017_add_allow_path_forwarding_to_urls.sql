@@ -0,0 +1,6 @@
+-- Add per-link path forwarding
+-- When enabled, /{short_key}/{*rest} resolves the link and appends the
+-- rest of the path to its destinations
+
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS allow_path_forwarding BOOLEAN NOT NULL DEFAULT FALSE;
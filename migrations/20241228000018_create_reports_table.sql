@@ -0,0 +1,32 @@
+-- Abuse reports and link takedown
+-- Visitors report malicious links; admins review the reports and disable
+-- the links, which then render a "link disabled" page
+
+-- Time an admin disabled the link (owners cannot re-activate it)
+ALTER TABLE urls
+ADD COLUMN IF NOT EXISTS disabled_at TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS reports (
+    id BIGSERIAL PRIMARY KEY,
+    -- Reported URL
+    url_id BIGINT NOT NULL REFERENCES urls(id) ON DELETE CASCADE,
+    -- phishing, malware, spam or other
+    reason VARCHAR(16) NOT NULL,
+    -- Free-form description by the reporter
+    details TEXT,
+    -- Contact address of the reporter
+    reporter_email VARCHAR(255),
+    -- Reporter IP truncated to its network prefix (/24 for IPv4, /48 for IPv6)
+    ip_prefix VARCHAR(64),
+    -- open: waiting for review, resolved: the link was disabled
+    status VARCHAR(16) NOT NULL DEFAULT 'open'
+        CHECK (status IN ('open', 'resolved')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+
+-- Index for the review queue (newest first)
+CREATE INDEX IF NOT EXISTS idx_reports_status_id ON reports(status, id DESC);
+
+-- Index for a link's reports (deduplication, resolution)
+CREATE INDEX IF NOT EXISTS idx_reports_url_id_status ON reports(url_id, status);
@@ -1,27 +1,53 @@
 //! HTTP request handler module.
 
-use std::borrow::Cow;
+use std::net::SocketAddr;
 
 use askama::Template;
 use axum::{
-    extract::{Path, State},
-    http::header,
-    response::{Html, IntoResponse, Response},
-    Json,
+    extract::{ConnectInfo, Extension, Path, Query, RawQuery, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{Html, IntoResponse, Redirect, Response},
+    Form, Json,
 };
 use axum_extra::extract::CookieJar;
+use chrono::{DateTime, Utc};
 use cookie::Cookie;
 use deadpool_redis::redis::AsyncCommands;
 use once_cell::sync::Lazy;
 use validator::Validate;
 use xxhash_rust::xxh3::xxh3_128;
 
-use crate::api::schemas::{validate_short_key, CreateShortUrlRequest, CreateShortUrlResponse};
+use crate::api::middlewares::AuthUser;
+use crate::api::schemas::{
+    normalize_alias, validate_android_app_link, validate_short_key, AdminUrlResponse,
+    BatchCreateItemResult, BatchCreateShortUrlResponse, CreateShortUrlRequest,
+    CreateShortUrlResponse, DeferredMatchRequest, DeferredMatchResponse, DisableUrlResponse,
+    ListAdminUrlsResponse, ListReportsQuery, ListReportsResponse, ListUrlsQuery, ListUrlsResponse,
+    PurgeRequest, PurgeResponse, QrCodeQuery, ReassignOwnerRequest, ReassignOwnerResponse,
+    RedirectPath, RedirectQuery, ReportResponse, ReportUrlRequest, ReportUrlResponse,
+    UnlockUrlForm, UpdateShortUrlRequest, UrlResponse, UrlStatsQuery, UrlStatsResponse,
+    MAX_BATCH_CREATE_ITEMS,
+};
 use crate::api::state::AppState;
-use crate::config::APP_CONFIG;
+use crate::config::{DomainBlocklist, APP_CONFIG};
 use crate::error::{AppError, AppResult, ValidationErrorExt};
-use crate::models::{CreateOrFindResult, NewUrl, UrlCacheData, UrlRepository};
-use crate::utils::{gen_rand_str, gen_token, merge_short_key, split_short_key};
+use crate::models::{
+    ClickRepository, CreateOrFindResult, DeferredClick, DeferredLinkRepository, InAppBrowserMode,
+    NewClick, NewReport, NewUrl, NewWebhookDelivery, RedirectMode, ReportListFilter,
+    ReportRepository, RouteContext, RoutingRules, Url, UrlCacheData, UrlListFilter, UrlRepository,
+    UrlUpdate, UrlVariant, UtmParams, WebhookEvent, WebhookEventType, WebhookRepository,
+};
+use crate::utils::{
+    check_url_safety, client_country, client_ip, gen_rand_str, gen_token, hash_password,
+    in_app_escape_url, is_unlocked, join_path, merge_query, merge_short_key, new_visitor_id,
+    parse_query, parse_token, path_suffix, preferred_language, render_qr, split_short_key,
+    truncate_ip, unlock_cookie, unlock_cookie_name, verify_password, visitor_cookie, visitor_id,
+    InAppBrowser, Platform, SchemePolicy, Scope, UserAgentInfo, GUEST_SUBJECT_PREFIX,
+    VISITOR_COOKIE_NAME,
+};
+
+/// Upper bound of the deferred deep link window (one week).
+const MAX_DEFERRED_LINK_TTL_SECS: i64 = 7 * 24 * 60 * 60;
 
 /// Index page template.
 #[derive(Template)]
@@ -33,8 +59,37 @@ struct IndexTemplate {}
 #[template(path = "redirect.html")]
 struct RedirectTemplate {
     object: TemplateUrlData,
+    /// Platform detected on the server, used by the page script
+    platform: &'static str,
+}
+
+/// "Open in browser" page template, shown inside in-app browsers.
+#[derive(Template)]
+#[template(path = "in_app.html")]
+struct InAppTemplate {
+    /// Name of the app hosting the webview
+    app_name: &'static str,
+    /// URL reopening the link in the system browser (empty if the app offers none)
+    escape_url: String,
+    /// Whether the page follows `escape_url` on load
+    auto_escape: bool,
+    /// URL continuing the redirect inside the in-app browser
+    continue_url: String,
+}
+
+/// Password prompt page template.
+#[derive(Template)]
+#[template(path = "password.html")]
+struct PasswordTemplate {
+    /// Whether the previous attempt used a wrong password
+    invalid: bool,
 }
 
+/// "Link disabled" page template, shown for deactivated links.
+#[derive(Template)]
+#[template(path = "disabled.html")]
+struct DisabledTemplate {}
+
 /// URL data for template rendering.
 #[derive(Clone)]
 struct TemplateUrlData {
@@ -42,6 +97,10 @@ struct TemplateUrlData {
     pub ios_fallback_url: String,
     pub android_deep_link: String,
     pub android_fallback_url: String,
+    pub desktop_deep_link: String,
+    pub macos_fallback_url: String,
+    pub windows_fallback_url: String,
+    pub linux_fallback_url: String,
     pub default_fallback_url: String,
     pub og_title: String,
     pub og_description: String,
@@ -53,8 +112,15 @@ impl From<&UrlCacheData> for TemplateUrlData {
         Self {
             ios_deep_link: url.ios_deep_link.clone().unwrap_or_default(),
             ios_fallback_url: url.ios_fallback_url.clone().unwrap_or_default(),
-            android_deep_link: url.android_deep_link.clone().unwrap_or_default(),
+            android_deep_link: url
+                .android_intent_url()
+                .or_else(|| url.android_deep_link.clone())
+                .unwrap_or_default(),
             android_fallback_url: url.android_fallback_url.clone().unwrap_or_default(),
+            desktop_deep_link: url.desktop_deep_link.clone().unwrap_or_default(),
+            macos_fallback_url: url.macos_fallback_url.clone().unwrap_or_default(),
+            windows_fallback_url: url.windows_fallback_url.clone().unwrap_or_default(),
+            linux_fallback_url: url.linux_fallback_url.clone().unwrap_or_default(),
             default_fallback_url: url.default_fallback_url.clone(),
             og_title: url.og_title.clone().unwrap_or_default(),
             og_description: url.og_description.clone().unwrap_or_default(),
@@ -75,13 +141,24 @@ static INDEX_HTML: Lazy<String> = Lazy::new(|| {
 
 /// Main page handler.
 ///
-/// Renders the main page and generates a guest token.
+/// Renders the main page and issues a guest token, scoped to creating and
+/// managing the guest's own links.
+///
+/// Each visitor gets their own guest subject so that links they create are
+/// not visible to other guests. A still-valid token cookie is renewed with
+/// the same subject, keeping previously created links manageable.
 ///
 /// # Route
 ///
 /// `GET /`
 pub async fn index_handler(jar: CookieJar) -> AppResult<impl IntoResponse> {
-    let token = gen_token("guest").map_err(|e| AppError::Internal(e.to_string()))?;
+    let subject = jar
+        .get("token")
+        .and_then(|c| parse_token(c.value()).ok())
+        .map(|claims| claims.sub)
+        .filter(|sub| sub.starts_with(GUEST_SUBJECT_PREFIX))
+        .unwrap_or_else(|| format!("{GUEST_SUBJECT_PREFIX}{}", gen_rand_str(16)));
+    let token = gen_token(&subject, Scope::GUEST).map_err(|e| AppError::Internal(e.to_string()))?;
 
     let mut cookie_builder = Cookie::build(("token", token))
         .path("/")
@@ -100,114 +177,840 @@ pub async fn index_handler(jar: CookieJar) -> AppResult<impl IntoResponse> {
 /// Short URL creation handler.
 ///
 /// Validates the input URL information and creates a short URL.
-/// If the URL already exists, returns the existing short key.
+/// If the caller already owns a URL with the same destinations,
+/// returns the existing short key.
 ///
 /// # Route
 ///
 /// `POST /v1/urls`
 pub async fn create_short_url_handler(
     State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
     Json(req_body): Json<CreateShortUrlRequest>,
 ) -> AppResult<Json<CreateShortUrlResponse>> {
+    // 1. Validate and prepare new URL data
+    let new_url = build_new_url(claims.sub, req_body, Utc::now(), &state.blocklist).await?;
+
+    // 2. Create or find existing URL (race-condition safe with ON CONFLICT)
+    match UrlRepository::create_or_find(&state.db, &new_url).await? {
+        CreateOrFindResult::Created(url) => {
+            queue_link_events(&state, WebhookEventType::Created, [&url]).await;
+
+            #[allow(clippy::cast_sign_loss)]
+            let short_key = merge_short_key(&url.random_key, url.id as u64);
+            Ok(Json(
+                CreateShortUrlResponse::created(short_key).with_alias(url.alias),
+            ))
+        }
+        CreateOrFindResult::Existing(url) => {
+            #[allow(clippy::cast_sign_loss)]
+            let short_key = merge_short_key(&url.random_key, url.id as u64);
+            Ok(Json(
+                CreateShortUrlResponse::already_exists_with_key(short_key).with_alias(url.alias),
+            ))
+        }
+    }
+}
+
+/// Batch short URL creation handler.
+///
+/// Validates each item independently and inserts the valid ones with
+/// multi-row statements. Responds with one result per item, so invalid
+/// items or taken aliases do not fail the rest of the batch.
+///
+/// # Route
+///
+/// `POST /v1/urls/batch`
+pub async fn batch_create_short_urls_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Json(items): Json<Vec<CreateShortUrlRequest>>,
+) -> AppResult<Json<BatchCreateShortUrlResponse>> {
+    if items.is_empty() {
+        return Err(AppError::Validation(
+            "At least one item is required".to_string(),
+        ));
+    }
+    if items.len() > MAX_BATCH_CREATE_ITEMS {
+        return Err(AppError::Validation(format!(
+            "At most {MAX_BATCH_CREATE_ITEMS} items can be created at once"
+        )));
+    }
+
+    // 1. Validate each item, collecting failures as per-item results
+    let now = Utc::now();
+    let mut results = Vec::with_capacity(items.len());
+    let mut indexes = Vec::with_capacity(items.len());
+    let mut new_urls = Vec::with_capacity(items.len());
+    for (index, item) in items.into_iter().enumerate() {
+        match build_new_url(claims.sub.clone(), item, now, &state.blocklist).await {
+            Ok(new_url) => {
+                indexes.push(index);
+                new_urls.push(new_url);
+            }
+            Err(AppError::Validation(message)) => {
+                results.push(BatchCreateItemResult::error(index, message));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    // 2. Insert the valid items
+    if !new_urls.is_empty() {
+        let outcomes = UrlRepository::create_or_find_many(&state.db, &new_urls).await?;
+        let mut created = Vec::new();
+        for (index, outcome) in indexes.into_iter().zip(outcomes) {
+            let result = match outcome {
+                Ok(CreateOrFindResult::Created(url)) => {
+                    #[allow(clippy::cast_sign_loss)]
+                    let short_key = merge_short_key(&url.random_key, url.id as u64);
+                    let result =
+                        BatchCreateItemResult::created(index, short_key, url.alias.clone());
+                    created.push(url);
+                    result
+                }
+                Ok(CreateOrFindResult::Existing(url)) => {
+                    #[allow(clippy::cast_sign_loss)]
+                    let short_key = merge_short_key(&url.random_key, url.id as u64);
+                    BatchCreateItemResult::existing(index, short_key, url.alias)
+                }
+                Err(AppError::Conflict(message)) => BatchCreateItemResult::error(index, message),
+                Err(e) => return Err(e),
+            };
+            results.push(result);
+        }
+        queue_link_events(&state, WebhookEventType::Created, &created).await;
+    }
+
+    Ok(Json(BatchCreateShortUrlResponse::new(results)))
+}
+
+/// Validates a creation request and prepares the URL record to insert.
+///
+/// Validation failures, including unsafe or blocked destinations, are
+/// [`AppError::Validation`]; a failure to hash the password is
+/// [`AppError::Internal`].
+async fn build_new_url(
+    owner: String,
+    req_body: CreateShortUrlRequest,
+    now: DateTime<Utc>,
+    blocklist: &DomainBlocklist,
+) -> AppResult<NewUrl> {
     // 1. Validation
     req_body.validate().map_err(|e| e.to_validation_error())?;
 
     let default_fallback_url = req_body
         .default_fallback_url
-        .as_ref()
         .ok_or_else(|| AppError::Validation("Default fallback URL is required".to_string()))?;
 
-    // 2. Generate hash for duplicate detection using xxHash (fast non-crypto hash)
-    let hash_input = format!(
-        "{}:{}:{}:{}:{}",
-        req_body.ios_deep_link.as_deref().unwrap_or(""),
-        req_body.ios_fallback_url.as_deref().unwrap_or(""),
-        req_body.android_deep_link.as_deref().unwrap_or(""),
-        req_body.android_fallback_url.as_deref().unwrap_or(""),
-        default_fallback_url
-    );
+    if req_body
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        return Err(AppError::Validation(
+            "expiresAt must be in the future".to_string(),
+        ));
+    }
 
-    let hashed_value = format!("{:032x}", xxh3_128(hash_input.as_bytes()));
+    let android_package = req_body.android_package.filter(|s| !s.is_empty());
+    let android_scheme = req_body.android_scheme.filter(|s| !s.is_empty());
+    let android_path = req_body.android_path.filter(|s| !s.is_empty());
+    validate_android_app_link(
+        android_package.as_deref(),
+        android_scheme.as_deref(),
+        android_path.as_deref(),
+    )?;
+
+    let routing_rules = req_body.routing_rules.normalize()?;
+    let variants = req_body.variants;
+    let utm_params = req_body.utm_params.normalize()?;
+
+    let alias = req_body
+        .alias
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(normalize_alias)
+        .transpose()?;
+
+    let password_hash = match req_body.password {
+        Some(password) => Some(hash_link_password(password).await?),
+        None => None,
+    };
 
-    // 3. Prepare new URL data (4-char random key: 2 prefix + 2 suffix)
-    let rand_key = gen_rand_str(4);
-    let new_url = NewUrl {
-        random_key: rand_key,
+    // 2. Prepare new URL data (4-char random key: 2 prefix + 2 suffix)
+    let mut new_url = NewUrl {
+        owner,
+        random_key: gen_rand_str(4),
         ios_deep_link: req_body.ios_deep_link.filter(|s| !s.is_empty()),
         ios_fallback_url: req_body.ios_fallback_url.filter(|s| !s.is_empty()),
         android_deep_link: req_body.android_deep_link.filter(|s| !s.is_empty()),
         android_fallback_url: req_body.android_fallback_url.filter(|s| !s.is_empty()),
-        default_fallback_url: default_fallback_url.clone(),
-        hashed_value,
+        android_package,
+        android_scheme,
+        android_path,
+        desktop_deep_link: req_body.desktop_deep_link.filter(|s| !s.is_empty()),
+        macos_fallback_url: req_body.macos_fallback_url.filter(|s| !s.is_empty()),
+        windows_fallback_url: req_body.windows_fallback_url.filter(|s| !s.is_empty()),
+        linux_fallback_url: req_body.linux_fallback_url.filter(|s| !s.is_empty()),
+        routing_rules,
+        variants,
+        utm_params,
+        query_passthrough: req_body.query_passthrough,
+        allow_path_forwarding: req_body.allow_path_forwarding,
+        default_fallback_url,
+        hashed_value: String::new(),
         webhook_url: req_body.webhook_url.filter(|s| !s.is_empty()),
         og_title: req_body.og_title.filter(|s| !s.is_empty()),
         og_description: req_body.og_description.filter(|s| !s.is_empty()),
         og_image_url: req_body.og_image_url.filter(|s| !s.is_empty()),
         is_active: true,
+        redirect_mode: req_body.redirect_mode,
+        in_app_browser_mode: req_body.in_app_browser_mode,
+        expires_at: req_body.expires_at,
+        max_clicks: req_body.max_clicks,
+        alias,
+        password_hash,
     };
 
-    // 4. Create or find existing URL (race-condition safe with ON CONFLICT)
-    match UrlRepository::create_or_find(&state.db, &new_url).await? {
-        CreateOrFindResult::Created(url) => {
-            #[allow(clippy::cast_sign_loss)]
-            let short_key = merge_short_key(&url.random_key, url.id as u64);
-            Ok(Json(CreateShortUrlResponse::created(short_key)))
+    // 3. Check the destinations and generate hash for duplicate detection
+    new_url.check_link_urls(blocklist)?;
+    new_url.hashed_value = new_url.link_hashed_value();
+    Ok(new_url)
+}
+
+/// Hashes a link password on the blocking thread pool.
+async fn hash_link_password(password: String) -> AppResult<String> {
+    tokio::task::spawn_blocking(move || hash_password(&password))
+        .await
+        .map_err(|e| AppError::Internal(format!("Password hashing task failed: {e}")))?
+}
+
+/// Short URL listing handler.
+///
+/// Returns a page of the caller's URLs ordered by ID descending. Pass `next_cursor`
+/// from the response as `cursor` to fetch the next page.
+///
+/// # Route
+///
+/// `GET /v1/urls`
+pub async fn list_short_urls_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Query(query): Query<ListUrlsQuery>,
+) -> AppResult<Json<ListUrlsResponse>> {
+    let (urls, next_cursor) = list_url_page(&state, Some(&claims.sub), query).await?;
+
+    Ok(Json(ListUrlsResponse {
+        items: urls.into_iter().map(UrlResponse::from).collect(),
+        next_cursor,
+    }))
+}
+
+/// Fetches one page of URLs for the listing handlers.
+///
+/// Lists only `owner`'s URLs when given, or those of every owner otherwise.
+/// Returns the page together with the cursor of the next page, if any.
+async fn list_url_page(
+    state: &AppState,
+    owner: Option<&str>,
+    query: ListUrlsQuery,
+) -> AppResult<(Vec<Url>, Option<i64>)> {
+    query.validate().map_err(|e| e.to_validation_error())?;
+
+    let limit = query.limit.unwrap_or(ListUrlsQuery::DEFAULT_LIMIT);
+    let filter = UrlListFilter {
+        owner: owner.map(str::to_owned),
+        cursor: query.cursor,
+        // Fetch one extra row to know whether another page exists
+        limit: limit + 1,
+        status: query.status,
+        created_from: query.created_from,
+        created_to: query.created_to,
+        search: query.q,
+    };
+
+    let mut urls = UrlRepository::list(&state.db, &filter).await?;
+
+    let has_more = i64::try_from(urls.len()).is_ok_and(|len| len > limit);
+    if has_more {
+        urls.pop();
+    }
+    let next_cursor = has_more.then(|| urls.last().map(|url| url.id)).flatten();
+
+    Ok((urls, next_cursor))
+}
+
+/// Short URL detail handler.
+///
+/// # Route
+///
+/// `GET /v1/urls/:short_key`
+pub async fn get_short_url_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Path(short_key): Path<String>,
+) -> AppResult<Json<UrlResponse>> {
+    let url = find_url_by_short_key(&state, Some(&claims.sub), &short_key).await?;
+    Ok(Json(UrlResponse::from(url)))
+}
+
+/// Short URL update handler.
+///
+/// Applies the provided fields on top of the stored URL, recomputes the
+/// duplicate-detection hash, and evicts the cached redirect data.
+///
+/// # Route
+///
+/// `PATCH /v1/urls/:short_key`
+pub async fn update_short_url_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Path(short_key): Path<String>,
+    Json(req_body): Json<UpdateShortUrlRequest>,
+) -> AppResult<Json<UrlResponse>> {
+    // 1. Validation
+    req_body.validate().map_err(|e| e.to_validation_error())?;
+
+    let alias_patch = req_body
+        .alias
+        .map(|alias| {
+            alias
+                .filter(|s| !s.is_empty())
+                .map(|s| normalize_alias(&s))
+                .transpose()
+        })
+        .transpose()?;
+
+    let password_patch = match req_body.password {
+        Some(Some(password)) => Some(Some(hash_link_password(password).await?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    // 2. Load current state
+    let url = find_url_by_short_key(&state, Some(&claims.sub), &short_key).await?;
+    let stale_cache_keys = url_cache_keys(&url);
+
+    // 3. Merge changes (absent = keep, null = clear)
+    let ios_deep_link = merge_patch_field(req_body.ios_deep_link, url.ios_deep_link);
+    let ios_fallback_url = merge_patch_field(req_body.ios_fallback_url, url.ios_fallback_url);
+    let android_deep_link = merge_patch_field(req_body.android_deep_link, url.android_deep_link);
+    let android_fallback_url =
+        merge_patch_field(req_body.android_fallback_url, url.android_fallback_url);
+    let android_package = merge_patch_field(req_body.android_package, url.android_package);
+    let android_scheme = merge_patch_field(req_body.android_scheme, url.android_scheme);
+    let android_path = merge_patch_field(req_body.android_path, url.android_path);
+    let desktop_deep_link = merge_patch_field(req_body.desktop_deep_link, url.desktop_deep_link);
+    let macos_fallback_url = merge_patch_field(req_body.macos_fallback_url, url.macos_fallback_url);
+    let windows_fallback_url =
+        merge_patch_field(req_body.windows_fallback_url, url.windows_fallback_url);
+    let linux_fallback_url = merge_patch_field(req_body.linux_fallback_url, url.linux_fallback_url);
+    validate_android_app_link(
+        android_package.as_deref(),
+        android_scheme.as_deref(),
+        android_path.as_deref(),
+    )?;
+    let routing_rules = req_body
+        .routing_rules
+        .map(RoutingRules::normalize)
+        .transpose()?
+        .unwrap_or(url.routing_rules);
+    let utm_params = req_body
+        .utm_params
+        .map(UtmParams::normalize)
+        .transpose()?
+        .unwrap_or(url.utm_params);
+    let default_fallback_url = req_body
+        .default_fallback_url
+        .filter(|s| !s.is_empty())
+        .unwrap_or(url.default_fallback_url);
+
+    let redirect_mode = req_body.redirect_mode.unwrap_or(url.redirect_mode);
+    let in_app_browser_mode = req_body
+        .in_app_browser_mode
+        .unwrap_or(url.in_app_browser_mode);
+    let expires_at = req_body.expires_at.unwrap_or(url.expires_at);
+    let max_clicks = req_body.max_clicks.unwrap_or(url.max_clicks);
+    let alias = alias_patch.unwrap_or(url.alias);
+    let password_hash = password_patch.unwrap_or(url.password_hash);
+
+    let mut changes = UrlUpdate {
+        ios_deep_link,
+        ios_fallback_url,
+        android_deep_link,
+        android_fallback_url,
+        android_package,
+        android_scheme,
+        android_path,
+        desktop_deep_link,
+        macos_fallback_url,
+        windows_fallback_url,
+        linux_fallback_url,
+        routing_rules,
+        variants: req_body.variants.unwrap_or(url.variants),
+        utm_params,
+        query_passthrough: req_body.query_passthrough.unwrap_or(url.query_passthrough),
+        allow_path_forwarding: req_body
+            .allow_path_forwarding
+            .unwrap_or(url.allow_path_forwarding),
+        default_fallback_url,
+        hashed_value: String::new(),
+        webhook_url: merge_patch_field(req_body.webhook_url, url.webhook_url),
+        og_title: merge_patch_field(req_body.og_title, url.og_title),
+        og_description: merge_patch_field(req_body.og_description, url.og_description),
+        og_image_url: merge_patch_field(req_body.og_image_url, url.og_image_url),
+        is_active: req_body.is_active.unwrap_or(url.is_active),
+        redirect_mode,
+        in_app_browser_mode,
+        expires_at,
+        max_clicks,
+        alias,
+        password_hash,
+    };
+    changes.check_link_urls(&state.blocklist)?;
+    changes.hashed_value = changes.link_hashed_value();
+
+    // 4. Persist and evict stale redirect data
+    let updated = UrlRepository::update(&state.db, &claims.sub, url.id, &changes)
+        .await?
+        .ok_or_else(|| AppError::NotFound("URL not found".to_string()))?;
+    evict_url_cache(&state, &stale_cache_keys).await;
+    queue_link_events(&state, WebhookEventType::Updated, [&updated]).await;
+
+    Ok(Json(UrlResponse::from(updated)))
+}
+
+/// Short URL deletion handler.
+///
+/// Soft-deletes the URL and evicts the cached redirect data.
+///
+/// # Route
+///
+/// `DELETE /v1/urls/:short_key`
+pub async fn delete_short_url_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Path(short_key): Path<String>,
+) -> AppResult<StatusCode> {
+    let url = find_url_by_short_key(&state, Some(&claims.sub), &short_key).await?;
+
+    if !UrlRepository::soft_delete(&state.db, &claims.sub, url.id).await? {
+        return Err(AppError::NotFound("URL not found".to_string()));
+    }
+    evict_url_cache(&state, &url_cache_keys(&url)).await;
+    queue_link_events(&state, WebhookEventType::Deleted, [&url]).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Short URL click statistics handler.
+///
+/// Returns the all-time click count, plus per-platform and time-bucketed
+/// counts within the requested range.
+///
+/// # Route
+///
+/// `GET /v1/urls/:short_key/stats`
+pub async fn get_short_url_stats_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Path(short_key): Path<String>,
+    Query(query): Query<UrlStatsQuery>,
+) -> AppResult<Json<UrlStatsResponse>> {
+    let (from, to) = query.resolve_range(Utc::now())?;
+    let url = find_url_by_short_key(&state, Some(&claims.sub), &short_key).await?;
+
+    let stats = ClickRepository::stats(&state.db, url.id, query.bucket, from, to).await?;
+
+    #[allow(clippy::cast_sign_loss)]
+    let short_key = merge_short_key(&url.random_key, url.id as u64);
+    Ok(Json(UrlStatsResponse::new(
+        short_key,
+        query.bucket,
+        from,
+        to,
+        stats,
+    )))
+}
+
+/// Short URL QR code handler.
+///
+/// Renders a QR code of the full public short URL as PNG or SVG.
+/// Rendered images are cached in Redis next to the redirect data.
+///
+/// # Route
+///
+/// `GET /v1/urls/:short_key/qr`
+pub async fn get_short_url_qr_handler(
+    State(state): State<AppState>,
+    Extension(AuthUser(claims)): Extension<AuthUser>,
+    Path(short_key): Path<String>,
+    Query(query): Query<QrCodeQuery>,
+) -> AppResult<Response> {
+    query.validate().map_err(|e| e.to_validation_error())?;
+    let options = query.options();
+
+    // Ownership check comes first, so deleted URLs never serve a cached image
+    let url = find_url_by_short_key(&state, Some(&claims.sub), &short_key).await?;
+
+    #[allow(clippy::cast_sign_loss)]
+    let short_key = merge_short_key(&url.random_key, url.id as u64);
+    let cache_key = format!("urls:{short_key}:qr:{}", options.cache_suffix());
+
+    let mut conn = match state.cache.get().await {
+        Ok(conn) => Some(conn),
+        Err(e) => {
+            tracing::warn!(error = %e, "Redis unavailable - rendering QR code without cache");
+            None
         }
-        CreateOrFindResult::Existing(url) => {
-            #[allow(clippy::cast_sign_loss)]
-            let short_key = merge_short_key(&url.random_key, url.id as u64);
-            Ok(Json(CreateShortUrlResponse::already_exists_with_key(
-                short_key,
-            )))
+    };
+
+    let cached = match conn.as_mut() {
+        Some(conn) => conn
+            .get::<_, Option<Vec<u8>>>(&cache_key)
+            .await
+            .ok()
+            .flatten(),
+        None => None,
+    };
+
+    let image = if let Some(image) = cached {
+        image
+    } else {
+        let short_url = format!("{}/{short_key}", APP_CONFIG.public_base_url);
+        let image = render_qr(&short_url, &options)?;
+
+        if let Some(conn) = conn.as_mut() {
+            let cache_result: Result<(), deadpool_redis::redis::RedisError> = conn
+                .set_ex(&cache_key, image.as_slice(), APP_CONFIG.cache_ttl_secs)
+                .await;
+            if let Err(e) = cache_result {
+                tracing::error!(cache_key = %cache_key, error = %e, "Failed to cache QR code");
+            }
         }
-    }
+        image
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, options.format.content_type()),
+            (header::CACHE_CONTROL, "private, max-age=3600"),
+        ],
+        image,
+    )
+        .into_response())
 }
 
 /// Short URL redirect handler.
 ///
 /// Takes the short URL key, looks up the original URL information,
-/// and renders the redirect page.
+/// records the click, and either renders the redirect page or, in the
+/// HTTP redirect modes, redirects straight to the platform fallback URL.
+/// A path after the short key is appended to the destinations of links
+/// that allow path forwarding; other links answer it with `NotFound`.
+/// Password-protected links render the password prompt instead until
+/// the visitor holds a valid unlock cookie. Visits from in-app browsers
+/// get the "open in browser" page first, according to the link's
+/// in-app browser mode; these are counted once the visitor continues.
+/// Bot visits (link previews, crawlers) are redirected but never counted:
+/// they spend no click budget and record no click, deferred deep link
+/// fingerprint or webhook event.
 ///
 /// # Route
 ///
-/// `GET /:short_key`
+/// `GET /:short_key`, `GET /:short_key/*rest`
 pub async fn redirect_to_original_handler(
     State(state): State<AppState>,
-    Path(short_key): Path<String>,
-    headers: axum::http::HeaderMap,
+    Path(RedirectPath { short_key, rest }): Path<RedirectPath>,
+    Query(query): Query<RedirectQuery>,
+    RawQuery(raw_query): RawQuery,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    jar: CookieJar,
 ) -> AppResult<Response> {
-    // 1. Validation
-    validate_short_key(&short_key)?;
+    // 1. Load URL data (short key or alias, cache first, then DB)
+    let mut url_data = load_url_data(&state, &short_key).await?;
+    if !url_data.is_active {
+        return render_disabled_page();
+    }
+    let suffix = rest.as_deref().and_then(path_suffix);
+    if suffix.is_some() && !url_data.allow_path_forwarding {
+        return Err(AppError::NotFound("URL not found".to_string()));
+    }
 
-    // Get user agent for webhook (use Cow to avoid allocation when possible)
-    let user_agent: Cow<'static, str> = headers
+    // 2. Ask for the password until the link is unlocked
+    if let Some(password_hash) = &url_data.password_hash {
+        if !is_unlocked(jar.get(&unlock_cookie_name(url_data.id)), password_hash) {
+            return render_password_page(StatusCode::OK, false);
+        }
+    }
+
+    // 3. Offer to leave in-app browsers before the visit is counted
+    let user_agent_header = headers
         .get(header::USER_AGENT)
-        .and_then(|h| h.to_str().ok())
-        .map_or(Cow::Borrowed("Unknown"), |s| Cow::Owned(s.to_string()));
+        .and_then(|h| h.to_str().ok());
+    let client = UserAgentInfo::parse(user_agent_header.unwrap_or_default());
+    if let Some(browser) = client.in_app_browser {
+        if query.stay_in_app.is_none() && shows_in_app_page(&url_data) {
+            return render_in_app_page(
+                &url_data,
+                &short_path(&short_key, suffix.as_deref()),
+                raw_query.as_deref(),
+                browser,
+                client.platform,
+            );
+        }
+    }
 
-    // 2. Check cache (MessagePack format for speed)
-    let cache_key = format!("urls:{short_key}");
+    // 4. Enforce the click limit (atomic DB check, capped links only). Bots
+    // such as link preview fetchers are redirected without being counted.
+    let counts_visit = client.platform != Platform::Bot;
+    if counts_visit
+        && url_data.max_clicks.is_some()
+        && !UrlRepository::consume_click(&state.db, url_data.id).await?
+    {
+        return Err(AppError::Gone(
+            "URL has reached its click limit".to_string(),
+        ));
+    }
+
+    // 5. Pick the destination: a matching routing rule, else the visitor's A/B variant
+    let country = client_country(&headers);
+    let visit_query = parse_query(raw_query.as_deref());
+    let rule_destination = matching_rule_destination(
+        &url_data,
+        &headers,
+        country.as_deref(),
+        client.platform,
+        &visit_query,
+    )
+    .map(str::to_string);
+    let (variant, jar) = match rule_destination {
+        Some(_) => (None, jar),
+        None => assign_variant(&url_data, jar),
+    };
+    if let Some(variant) = &variant {
+        url_data
+            .default_fallback_url
+            .clone_from(&variant.destination);
+    }
+    let variant = variant.map(|variant| variant.name);
+
+    // 6. Forward the path suffix, UTM parameters and query to the destinations
+    let rule_destination = forward_visit_path(&mut url_data, rule_destination, suffix.as_deref());
+    let rule_destination = merge_visit_query(&mut url_data, rule_destination, &visit_query);
+
+    // 7. Build the response first, then record the visit (avoids clones)
+    let response = redirect_response(
+        &url_data,
+        client.platform,
+        rule_destination.as_deref(),
+        variant.is_some(),
+    )?;
+    if !counts_visit {
+        return Ok((jar, response).into_response());
+    }
+
+    // 8. Write the click webhook to the outbox before answering, so the
+    // event survives even when the click queue drops the click
+    let referer = headers.get(header::REFERER).and_then(|h| h.to_str().ok());
+    if let Some(webhook_url) = url_data.webhook_url.clone().filter(|u| !u.is_empty()) {
+        let event = WebhookEvent::clicked(
+            url_data.id,
+            &url_data.random_key,
+            url_data.alias.clone(),
+            user_agent_header,
+            referer,
+            country,
+            variant.clone(),
+        );
+        let delivery = NewWebhookDelivery::new(url_data.id, webhook_url, &event)?;
+        WebhookRepository::enqueue(&state.db, &delivery).await?;
+    }
+
+    // 9. Queue the click for the batched writer
+    let peer_ip = connect_info.map(|Extension(ConnectInfo(addr))| addr.ip());
+    let ip_prefix = client_ip(&headers, peer_ip).map(truncate_ip);
+    state.clicks.record(
+        NewClick::new(url_data.id, user_agent_header, referer, ip_prefix.clone())
+            .with_variant(variant),
+    );
+
+    // 10. Remember app link clicks for deferred deep linking
+    if let Some(ip_prefix) = ip_prefix {
+        remember_deferred_click(&state, &url_data, short_key, client, ip_prefix);
+    }
+
+    Ok((jar, response).into_response())
+}
+
+/// Short URL unlock handler.
+///
+/// Verifies the password posted from the prompt page. On success, issues a
+/// signed unlock cookie and redirects back to the short URL with its query
+/// (303 See Other), which then redirects as usual; otherwise renders the
+/// prompt again.
+///
+/// # Route
+///
+/// `POST /:short_key`, `POST /:short_key/*rest`
+pub async fn unlock_short_url_handler(
+    State(state): State<AppState>,
+    Path(RedirectPath { short_key, rest }): Path<RedirectPath>,
+    RawQuery(raw_query): RawQuery,
+    jar: CookieJar,
+    Form(form): Form<UnlockUrlForm>,
+) -> AppResult<Response> {
+    let url_data = load_url_data(&state, &short_key).await?;
+    if !url_data.is_active {
+        return render_disabled_page();
+    }
+    let short_path = short_path(&short_key, rest.as_deref().and_then(path_suffix).as_deref());
+    let redirect = Redirect::to(&with_query(format!("/{short_path}"), raw_query.as_deref()));
+
+    let Some(password_hash) = url_data.password_hash else {
+        return Ok(redirect.into_response());
+    };
+
+    if !verify_link_password(form.password, password_hash.clone()).await? {
+        return render_password_page(StatusCode::UNAUTHORIZED, true);
+    }
+
+    let jar = jar.add(unlock_cookie(url_data.id, &password_hash));
+    Ok((jar, redirect).into_response())
+}
+
+/// Queues a lifecycle webhook event for each of the URLs that has a webhook.
+///
+/// The change is already saved at this point, so failures are logged
+/// instead of failing the request.
+async fn queue_link_events<'a>(
+    state: &AppState,
+    event_type: WebhookEventType,
+    urls: impl IntoIterator<Item = &'a Url>,
+) {
+    let deliveries = urls
+        .into_iter()
+        .filter_map(|url| {
+            let webhook_url = url.webhook_url.as_ref().filter(|u| !u.is_empty())?;
+            let event = WebhookEvent::new(event_type, url.id, &url.random_key, url.alias.clone());
+            Some(NewWebhookDelivery::new(url.id, webhook_url.clone(), &event))
+        })
+        .collect::<AppResult<Vec<_>>>();
+
+    let result = match deliveries {
+        Ok(deliveries) => WebhookRepository::enqueue_many(&state.db, &deliveries).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        tracing::error!(event_type = ?event_type, error = %e, "Failed to queue webhook events");
+    }
+}
+
+/// Records the fingerprint of a click that may lead to an app install.
+///
+/// Only clicks on links with a deep link for the visitor's platform are
+/// recorded, since only those can be claimed by an installed app.
+fn remember_deferred_click(
+    state: &AppState,
+    url_data: &UrlCacheData,
+    short_key: String,
+    client: UserAgentInfo,
+    ip_prefix: String,
+) {
+    if url_data.deep_link_for(client.platform).is_none() {
+        return;
+    }
+
+    let click = DeferredClick {
+        short_key,
+        ip_prefix,
+        platform: client.platform,
+        clicked_at: Utc::now(),
+        browser: client.browser,
+    };
+    DeferredLinkRepository::spawn_record(state.cache.clone(), click, deferred_link_ttl());
+}
+
+/// Returns how long click fingerprints can be claimed by an app install.
+fn deferred_link_ttl() -> chrono::Duration {
+    let secs = i64::try_from(APP_CONFIG.deferred_link_ttl_secs).unwrap_or(i64::MAX);
+    chrono::Duration::seconds(secs.clamp(1, MAX_DEFERRED_LINK_TTL_SECS))
+}
+
+/// Verifies a link password on the blocking thread pool.
+async fn verify_link_password(password: String, password_hash: String) -> AppResult<bool> {
+    tokio::task::spawn_blocking(move || verify_password(&password, &password_hash))
+        .await
+        .map_err(|e| AppError::Internal(format!("Password verification task failed: {e}")))
+}
+
+/// Loads the redirect data of a short key or vanity alias, reading through the cache.
+///
+/// Base62 keys take precedence; the key is looked up as an alias when it
+/// cannot be decoded or does not match a URL.
+///
+/// Inactive links are returned as well, for callers to render the disabled
+/// page. Returns `NotFound` for unknown, non-canonical, or deleted keys, and
+/// `Gone` for expired ones.
+async fn load_url_data(state: &AppState, short_key: &str) -> AppResult<UrlCacheData> {
     let mut conn = state
         .cache
         .get()
         .await
         .map_err(|e| AppError::Internal(format!("Redis connection error: {e}")))?;
 
-    if let Ok(cached_val) = conn.get::<_, Vec<u8>>(&cache_key).await {
-        if let Ok(url_data) = rmp_serde::from_slice::<UrlCacheData>(&cached_val) {
-            // Render page first, then spawn webhook (avoids clone)
-            let response = render_redirect_page(&url_data)?;
-            url_data.spawn_webhook_task(Cow::Owned(short_key), user_agent);
-            return Ok(response);
-        }
+    // 1. Base62 short key
+    let key_error = match validate_short_key(short_key) {
+        Ok(()) => match load_url_data_by_key(state, &mut conn, short_key).await {
+            Err(AppError::NotFound(_)) => None,
+            result => return result,
+        },
+        Err(e) => Some(e),
+    };
+
+    // 2. Fall back to a vanity alias
+    let Ok(alias) = normalize_alias(short_key) else {
+        return Err(key_error.unwrap_or_else(|| AppError::NotFound("URL not found".to_string())));
+    };
+
+    let cache_key = alias_cache_key(&alias);
+    if let Some(url_data) = get_cached_url_data(&mut conn, &cache_key).await? {
+        return Ok(url_data);
+    }
+
+    let url_cache_data = UrlRepository::find_by_alias_for_cache(&state.db, &alias)
+        .await?
+        .ok_or_else(|| AppError::NotFound("URL not found".to_string()))?;
+
+    cache_url_data(&mut conn, &cache_key, &url_cache_data).await;
+    Ok(url_cache_data)
+}
+
+/// Loads the redirect data of a Base62 short key, reading through the cache.
+async fn load_url_data_by_key(
+    state: &AppState,
+    conn: &mut deadpool_redis::Connection,
+    short_key: &str,
+) -> AppResult<UrlCacheData> {
+    // 1. Check cache (MessagePack format for speed)
+    let cache_key = format!("urls:{short_key}");
+    if let Some(url_data) = get_cached_url_data(conn, &cache_key).await? {
+        return Ok(url_data);
     }
 
-    // 3. If not in cache, query DB (optimized query)
-    let (id, rand_key) = split_short_key(&short_key);
+    // 2. If not in cache, query DB (optimized query)
+    let (id, rand_key) = split_short_key(short_key);
     if id == 0 {
         return Err(AppError::NotFound("URL not found".to_string()));
     }
 
+    // Only canonical keys are cached, so cache eviction by key stays exhaustive
+    // (e.g. "Ab03D7Xy" decodes to the same ID as "Ab3D7Xy")
+    if merge_short_key(&rand_key, id) != short_key {
+        return Err(AppError::NotFound("URL not found".to_string()));
+    }
+
     #[allow(clippy::cast_possible_wrap)]
     let url_cache_data = UrlRepository::find_by_id_for_cache(&state.db, id as i64)
         .await?
@@ -218,12 +1021,47 @@ pub async fn redirect_to_original_handler(
         return Err(AppError::NotFound("URL not found".to_string()));
     }
 
-    // 4. Save to cache with MessagePack serialization
-    match rmp_serde::to_vec(&url_cache_data) {
+    // 3. Save to cache
+    cache_url_data(conn, &cache_key, &url_cache_data).await;
+    Ok(url_cache_data)
+}
+
+/// Reads cached redirect data, treating unreadable entries as a cache miss.
+///
+/// Returns `Gone` if the cached URL has expired.
+async fn get_cached_url_data(
+    conn: &mut deadpool_redis::Connection,
+    cache_key: &str,
+) -> AppResult<Option<UrlCacheData>> {
+    let Ok(cached_val) = conn.get::<_, Vec<u8>>(cache_key).await else {
+        return Ok(None);
+    };
+    let Ok(url_data) = rmp_serde::from_slice::<UrlCacheData>(&cached_val) else {
+        return Ok(None);
+    };
+
+    // The TTL never outlives the expiration, but guard against clock skew
+    if url_data.is_expired(Utc::now()) {
+        return Err(AppError::Gone("URL has expired".to_string()));
+    }
+    Ok(Some(url_data))
+}
+
+/// Saves redirect data to the cache in `MessagePack` format.
+///
+/// The TTL is clamped so that an expired link is never served from the cache.
+/// Failures are logged, since the data can always be reloaded from the DB.
+async fn cache_url_data(
+    conn: &mut deadpool_redis::Connection,
+    cache_key: &str,
+    url_data: &UrlCacheData,
+) {
+    let ttl_secs = url_data.cache_ttl_secs(APP_CONFIG.cache_ttl_secs, Utc::now());
+    match rmp_serde::to_vec(url_data) {
+        Ok(_) if ttl_secs == 0 => {}
         Ok(data) => {
-            let cache_result: Result<(), deadpool_redis::redis::RedisError> = conn
-                .set_ex(&cache_key, data, APP_CONFIG.cache_ttl_secs)
-                .await;
+            let cache_result: Result<(), deadpool_redis::redis::RedisError> =
+                conn.set_ex(cache_key, data, ttl_secs).await;
 
             if let Err(e) = cache_result {
                 tracing::error!(
@@ -241,32 +1079,919 @@ pub async fn redirect_to_original_handler(
             );
         }
     }
+}
 
-    // 5. Render page first, then spawn webhook (avoids clone)
-    let response = render_redirect_page(&url_cache_data)?;
-    url_cache_data.spawn_webhook_task(Cow::Owned(short_key), user_agent);
-
-    Ok(response)
+/// Returns the cache key of an alias.
+fn alias_cache_key(alias: &str) -> String {
+    format!("urls:alias:{alias}")
 }
 
-/// Renders the redirect page template.
-fn render_redirect_page(url_data: &UrlCacheData) -> AppResult<Response> {
-    let template = RedirectTemplate {
-        object: TemplateUrlData::from(url_data),
+/// Resolves a short key or vanity alias to the owner's (non-deleted) URL record;
+/// `None` as the owner resolves URLs of every owner.
+///
+/// Returns `NotFound` if the key cannot be decoded, its random key does not match,
+/// no URL of the owner has the alias, or the URL belongs to another owner.
+async fn find_url_by_short_key(
+    state: &AppState,
+    owner: Option<&str>,
+    short_key: &str,
+) -> AppResult<Url> {
+    let key_error = match validate_short_key(short_key) {
+        Ok(()) => {
+            let (id, rand_key) = split_short_key(short_key);
+
+            #[allow(clippy::cast_possible_wrap)]
+            let url = if id == 0 {
+                None
+            } else {
+                UrlRepository::find_by_id(&state.db, owner, id as i64)
+                    .await?
+                    .filter(|url| url.random_key == rand_key)
+            };
+
+            if let Some(url) = url {
+                return Ok(url);
+            }
+            None
+        }
+        Err(e) => Some(e),
     };
 
-    let html = template.render()?;
-    Ok(Html(html).into_response())
+    let Ok(alias) = normalize_alias(short_key) else {
+        return Err(key_error.unwrap_or_else(|| AppError::NotFound("URL not found".to_string())));
+    };
+
+    UrlRepository::find_by_alias(&state.db, owner, &alias)
+        .await?
+        .ok_or_else(|| AppError::NotFound("URL not found".to_string()))
 }
 
-/// Health check response.
-#[derive(serde::Serialize)]
-pub struct HealthResponse {
-    pub status: &'static str,
-    pub version: &'static str,
+/// Returns every cache key that may hold the redirect data of a URL.
+fn url_cache_keys(url: &Url) -> Vec<String> {
+    #[allow(clippy::cast_sign_loss)]
+    let mut keys = vec![format!(
+        "urls:{}",
+        merge_short_key(&url.random_key, url.id as u64)
+    )];
+    if let Some(alias) = &url.alias {
+        keys.push(alias_cache_key(alias));
+    }
+    keys
 }
 
-/// Liveness probe handler.
+/// Removes cached redirect data.
+///
+/// Failures are logged rather than returned, since the database change
+/// has already been committed at this point.
+async fn evict_url_cache(state: &AppState, cache_keys: &[String]) {
+    let result = match state.cache.get().await {
+        Ok(mut conn) => conn.del::<_, ()>(cache_keys).await.map_err(AppError::from),
+        Err(e) => Err(AppError::from(e)),
+    };
+
+    if let Err(e) = result {
+        tracing::error!(
+            cache_keys = ?cache_keys,
+            error = %e,
+            "Failed to evict URL cache - stale data may be served until TTL expires"
+        );
+    }
+}
+
+/// Duplicate-detection hash of a link's destinations and settings.
+trait LinkHash {
+    fn link_hashed_value(&self) -> String;
+}
+
+/// Implements [`LinkHash`] for the URL structs sharing the link fields.
+macro_rules! impl_link_hash {
+    ($($ty:ty),*) => {$(
+        impl LinkHash for $ty {
+            fn link_hashed_value(&self) -> String {
+                compute_hashed_value(
+                    &[
+                        self.ios_deep_link.as_deref().unwrap_or(""),
+                        self.ios_fallback_url.as_deref().unwrap_or(""),
+                        self.android_deep_link.as_deref().unwrap_or(""),
+                        self.android_fallback_url.as_deref().unwrap_or(""),
+                        &self.default_fallback_url,
+                    ],
+                    &[
+                        android_app_hash_extras(
+                            self.android_package.as_deref(),
+                            self.android_scheme.as_deref(),
+                            self.android_path.as_deref(),
+                        )
+                        .as_slice(),
+                        desktop_hash_extras(
+                            self.desktop_deep_link.as_deref(),
+                            self.macos_fallback_url.as_deref(),
+                            self.windows_fallback_url.as_deref(),
+                            self.linux_fallback_url.as_deref(),
+                        )
+                        .as_slice(),
+                        &[
+                            (
+                                "routing_rules",
+                                (!self.routing_rules.is_empty())
+                                    .then(|| self.routing_rules.to_json()),
+                            ),
+                            (
+                                "variants",
+                                (!self.variants.is_empty()).then(|| self.variants.to_json()),
+                            ),
+                            (
+                                "utm_params",
+                                (!self.utm_params.is_empty()).then(|| self.utm_params.to_json()),
+                            ),
+                            (
+                                "query_passthrough",
+                                self.query_passthrough.then(|| "true".to_string()),
+                            ),
+                            (
+                                "allow_path_forwarding",
+                                self.allow_path_forwarding.then(|| "true".to_string()),
+                            ),
+                        ],
+                        link_settings_hash_extras(
+                            self.redirect_mode,
+                            self.in_app_browser_mode,
+                            self.expires_at,
+                            self.max_clicks,
+                            self.alias.as_deref(),
+                            self.password_hash.as_deref(),
+                        )
+                        .as_slice(),
+                    ]
+                    .concat(),
+                )
+            }
+        }
+    )*};
+}
+
+impl_link_hash!(NewUrl, UrlUpdate);
+
+/// Safety check of the URLs of a link.
+trait LinkUrls {
+    /// Checks every URL against the schemes of its field and the blocklist
+    /// (see [`check_url_safety`]).
+    fn check_link_urls(&self, blocklist: &DomainBlocklist) -> AppResult<()>;
+}
+
+/// Implements [`LinkUrls`] for the URL structs sharing the link fields.
+///
+/// Deep links may use custom app schemes; every other URL must be `http(s)`.
+macro_rules! impl_link_urls {
+    ($($ty:ty),*) => {$(
+        impl LinkUrls for $ty {
+            fn check_link_urls(&self, blocklist: &DomainBlocklist) -> AppResult<()> {
+                let fields = [
+                    ("iosDeepLink", self.ios_deep_link.as_deref(), SchemePolicy::App),
+                    ("androidDeepLink", self.android_deep_link.as_deref(), SchemePolicy::App),
+                    ("desktopDeepLink", self.desktop_deep_link.as_deref(), SchemePolicy::App),
+                    ("iosFallbackUrl", self.ios_fallback_url.as_deref(), SchemePolicy::Web),
+                    (
+                        "androidFallbackUrl",
+                        self.android_fallback_url.as_deref(),
+                        SchemePolicy::Web,
+                    ),
+                    ("macosFallbackUrl", self.macos_fallback_url.as_deref(), SchemePolicy::Web),
+                    (
+                        "windowsFallbackUrl",
+                        self.windows_fallback_url.as_deref(),
+                        SchemePolicy::Web,
+                    ),
+                    ("linuxFallbackUrl", self.linux_fallback_url.as_deref(), SchemePolicy::Web),
+                    (
+                        "defaultFallbackUrl",
+                        Some(self.default_fallback_url.as_str()),
+                        SchemePolicy::Web,
+                    ),
+                    ("webhookUrl", self.webhook_url.as_deref(), SchemePolicy::Web),
+                    ("ogImageUrl", self.og_image_url.as_deref(), SchemePolicy::Web),
+                ];
+                for (field, value, policy) in fields {
+                    if let Some(value) = value {
+                        check_url_safety(field, value, policy, blocklist)?;
+                    }
+                }
+
+                for (index, rule) in self.routing_rules.0.iter().enumerate() {
+                    check_url_safety(
+                        &format!("routingRules[{index}].destination"),
+                        &rule.destination,
+                        SchemePolicy::Web,
+                        blocklist,
+                    )?;
+                }
+                for (index, variant) in self.variants.0.iter().enumerate() {
+                    check_url_safety(
+                        &format!("variants[{index}].destination"),
+                        &variant.destination,
+                        SchemePolicy::Web,
+                        blocklist,
+                    )?;
+                }
+
+                Ok(())
+            }
+        }
+    )*};
+}
+
+impl_link_urls!(NewUrl, UrlUpdate);
+
+/// Generates the duplicate-detection hash from a URL's destination fields.
+///
+/// Fields are joined with `:` and hashed with xxHash (fast non-crypto hash).
+/// Named extras are appended as `:name=value` only when set, so URLs that
+/// do not use them keep their previously stored hash.
+fn compute_hashed_value(fields: &[&str], extras: &[(&str, Option<String>)]) -> String {
+    let mut input = fields.join(":");
+    for (name, value) in extras {
+        if let Some(value) = value {
+            input.push(':');
+            input.push_str(name);
+            input.push('=');
+            input.push_str(value);
+        }
+    }
+    format!("{:032x}", xxh3_128(input.as_bytes()))
+}
+
+/// Hash extras for the Android app link destination.
+///
+/// Kept out of the joined destination fields so that URLs without an app
+/// link keep their previously stored hash.
+fn android_app_hash_extras(
+    package: Option<&str>,
+    scheme: Option<&str>,
+    path: Option<&str>,
+) -> [(&'static str, Option<String>); 3] {
+    [
+        ("android_package", package.map(str::to_string)),
+        ("android_scheme", scheme.map(str::to_string)),
+        ("android_path", path.map(str::to_string)),
+    ]
+}
+
+/// Hash extras for the desktop destinations.
+///
+/// Kept out of the joined destination fields so that URLs without desktop
+/// targets keep their previously stored hash.
+fn desktop_hash_extras(
+    deep_link: Option<&str>,
+    macos_fallback_url: Option<&str>,
+    windows_fallback_url: Option<&str>,
+    linux_fallback_url: Option<&str>,
+) -> [(&'static str, Option<String>); 4] {
+    [
+        ("desktop_deep_link", deep_link.map(str::to_string)),
+        ("macos_fallback_url", macos_fallback_url.map(str::to_string)),
+        (
+            "windows_fallback_url",
+            windows_fallback_url.map(str::to_string),
+        ),
+        ("linux_fallback_url", linux_fallback_url.map(str::to_string)),
+    ]
+}
+
+/// Hash extras for link settings that make otherwise identical links distinct.
+///
+/// Settings left at their default are omitted. The password hash is salted,
+/// so a protected link never matches another link.
+fn link_settings_hash_extras(
+    redirect_mode: RedirectMode,
+    in_app_browser_mode: InAppBrowserMode,
+    expires_at: Option<DateTime<Utc>>,
+    max_clicks: Option<i64>,
+    alias: Option<&str>,
+    password_hash: Option<&str>,
+) -> [(&'static str, Option<String>); 6] {
+    [
+        (
+            "redirect_mode",
+            (redirect_mode != RedirectMode::default()).then(|| redirect_mode.as_str().to_string()),
+        ),
+        (
+            "in_app_browser_mode",
+            (in_app_browser_mode != InAppBrowserMode::default())
+                .then(|| in_app_browser_mode.as_str().to_string()),
+        ),
+        ("expires_at", expires_at.map(|t| t.to_rfc3339())),
+        ("max_clicks", max_clicks.map(|n| n.to_string())),
+        ("alias", alias.map(str::to_string)),
+        ("password", password_hash.map(str::to_string)),
+    ]
+}
+
+/// Applies a PATCH field on top of the current value.
+///
+/// Absent keeps the current value; `null` or an empty string clears it.
+#[allow(clippy::option_option)]
+fn merge_patch_field(patch: Option<Option<String>>, current: Option<String>) -> Option<String> {
+    patch.map_or(current, |value| value.filter(|s| !s.is_empty()))
+}
+
+/// Returns the HTTP status of a server-side redirect mode.
+///
+/// Returns `None` for the interstitial mode.
+const fn http_redirect_status(mode: RedirectMode) -> Option<StatusCode> {
+    match mode {
+        RedirectMode::Interstitial => None,
+        RedirectMode::Http302 => Some(StatusCode::FOUND),
+        RedirectMode::Http301 => Some(StatusCode::MOVED_PERMANENTLY),
+        RedirectMode::Http307 => Some(StatusCode::TEMPORARY_REDIRECT),
+    }
+}
+
+/// Returns the redirect status for a destination chosen per visit.
+///
+/// Routing rule matches and A/B variants depend on the visit, so the
+/// redirect is never permanent.
+const fn per_visit_redirect_status(mode: RedirectMode) -> StatusCode {
+    match mode {
+        RedirectMode::Http307 => StatusCode::TEMPORARY_REDIRECT,
+        _ => StatusCode::FOUND,
+    }
+}
+
+/// Cache policy of a permanent (`301`) redirect.
+///
+/// Only the visitor's browser may cache it, and briefly, so that edits,
+/// takedowns and click counting still reach returning visitors.
+const PERMANENT_REDIRECT_CACHE_CONTROL: &str = "private, max-age=300";
+
+/// Returns whether a link may answer with a permanent redirect.
+///
+/// Links that expire or run out of clicks must be checked on every visit,
+/// so `http_301` falls back to `302` for them.
+const fn allows_permanent_redirect(url_data: &UrlCacheData) -> bool {
+    url_data.expires_at.is_none() && url_data.max_clicks.is_none()
+}
+
+/// Builds the response of a counted visit.
+///
+/// A matching routing rule redirects to its destination; otherwise the
+/// link's redirect mode applies, with a temporary redirect when the visitor
+/// was assigned an A/B variant.
+fn redirect_response(
+    url_data: &UrlCacheData,
+    platform: Platform,
+    rule_destination: Option<&str>,
+    has_variant: bool,
+) -> AppResult<Response> {
+    let per_visit_status = per_visit_redirect_status(url_data.redirect_mode);
+    let mut response = match (
+        rule_destination,
+        http_redirect_status(url_data.redirect_mode),
+    ) {
+        (Some(destination), _) => http_redirect(per_visit_status, destination)?,
+        (None, Some(_)) if has_variant || !allows_permanent_redirect(url_data) => {
+            http_redirect(per_visit_status, url_data.fallback_url_for(platform))?
+        }
+        (None, Some(status)) => http_redirect(status, url_data.fallback_url_for(platform))?,
+        (None, None) => return render_redirect_page(url_data, platform),
+    };
+
+    set_redirect_cache_headers(&mut response, url_data);
+    Ok(response)
+}
+
+/// Sets the cache headers of an HTTP redirect of a link.
+///
+/// The destination depends on the User-Agent, and also on the visitor
+/// cookie and `Accept-Language` for links with variants or routing rules.
+/// Only permanent redirects may be cached.
+fn set_redirect_cache_headers(response: &mut Response, url_data: &UrlCacheData) {
+    let vary = match (
+        url_data.variants.is_empty(),
+        url_data.routing_rules.is_empty(),
+    ) {
+        (true, true) => "User-Agent",
+        (false, true) => "User-Agent, Cookie",
+        (true, false) => "User-Agent, Accept-Language",
+        (false, false) => "User-Agent, Cookie, Accept-Language",
+    };
+    let cache_control = if response.status() == StatusCode::MOVED_PERMANENTLY {
+        PERMANENT_REDIRECT_CACHE_CONTROL
+    } else {
+        "no-store"
+    };
+
+    let headers = response.headers_mut();
+    headers.insert(header::VARY, HeaderValue::from_static(vary));
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(cache_control),
+    );
+}
+
+/// Assigns the visitor to one of the link's A/B variants.
+///
+/// Visitors are identified by the visitor cookie, which is issued on their
+/// first visit to a split link. Returns `None` for links without variants.
+fn assign_variant(url_data: &UrlCacheData, jar: CookieJar) -> (Option<UrlVariant>, CookieJar) {
+    if url_data.variants.is_empty() {
+        return (None, jar);
+    }
+
+    let (visitor_id, jar) = if let Some(visitor_id) = visitor_id(jar.get(VISITOR_COOKIE_NAME)) {
+        (visitor_id, jar)
+    } else {
+        let visitor_id = new_visitor_id();
+        let jar = jar.add(visitor_cookie(visitor_id.clone()));
+        (visitor_id, jar)
+    };
+    let variant = url_data.variants.assign(url_data.id, &visitor_id).cloned();
+    (variant, jar)
+}
+
+/// Returns the destination of the first routing rule matching the visit.
+fn matching_rule_destination<'a>(
+    url_data: &'a UrlCacheData,
+    headers: &HeaderMap,
+    country: Option<&str>,
+    platform: Platform,
+    query: &[(String, String)],
+) -> Option<&'a str> {
+    if url_data.routing_rules.is_empty() {
+        return None;
+    }
+
+    let language = preferred_language(headers);
+    url_data.routing_rules.resolve(&RouteContext {
+        country,
+        language: language.as_deref(),
+        platform,
+        query,
+        now: Utc::now(),
+    })
+}
+
+/// Appends the path suffix of the visit to the destinations of the visit.
+///
+/// Returns the routing rule destination with the suffix appended.
+fn forward_visit_path(
+    url_data: &mut UrlCacheData,
+    rule_destination: Option<String>,
+    suffix: Option<&str>,
+) -> Option<String> {
+    let Some(suffix) = suffix else {
+        return rule_destination;
+    };
+
+    url_data.join_path(suffix);
+    rule_destination.map(|destination| join_path(&destination, suffix))
+}
+
+/// Merges the link's UTM parameters and, with query passthrough, the visit's
+/// query parameters into the destinations of the visit.
+///
+/// Forwarded parameters replace those of the same name in a destination,
+/// and UTM parameters are only added where neither sets them. Returns the
+/// routing rule destination with the parameters merged.
+fn merge_visit_query(
+    url_data: &mut UrlCacheData,
+    rule_destination: Option<String>,
+    visit_query: &[(String, String)],
+) -> Option<String> {
+    let utm_params = url_data.utm_params.clone();
+    let defaults = utm_params.query_params();
+    let forwarded: Vec<(String, String)> = if url_data.query_passthrough {
+        visit_query
+            .iter()
+            .filter(|(name, _)| name != RedirectQuery::STAY_IN_APP_PARAM)
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    url_data.merge_query(&defaults, &forwarded);
+    rule_destination.map(|destination| merge_query(&destination, &defaults, &forwarded))
+}
+
+/// Builds a redirect response with a `Location` header.
+fn http_redirect(status: StatusCode, location: &str) -> AppResult<Response> {
+    let location = HeaderValue::from_str(location)
+        .map_err(|e| AppError::Internal(format!("Invalid redirect location: {e}")))?;
+
+    Ok((status, [(header::LOCATION, location)]).into_response())
+}
+
+/// Renders the redirect page template.
+fn render_redirect_page(url_data: &UrlCacheData, platform: Platform) -> AppResult<Response> {
+    let template = RedirectTemplate {
+        object: TemplateUrlData::from(url_data),
+        platform: platform.as_str(),
+    };
+
+    let html = template.render()?;
+    Ok(Html(html).into_response())
+}
+
+/// Returns whether in-app browser visits of a link get the "open in browser" page.
+///
+/// HTTP redirect modes never attempt deep links, so they redirect as usual.
+fn shows_in_app_page(url_data: &UrlCacheData) -> bool {
+    url_data.redirect_mode == RedirectMode::Interstitial
+        && url_data.in_app_browser_mode != InAppBrowserMode::Off
+}
+
+/// Renders the "open in browser" page for an in-app browser visit.
+///
+/// The escape URL reopens the public short URL with the visit's path and
+/// query, so the system browser runs the regular redirect. Where Android
+/// cannot hand over to Chrome, it falls back to continuing inside the webview.
+fn render_in_app_page(
+    url_data: &UrlCacheData,
+    short_path: &str,
+    raw_query: Option<&str>,
+    browser: InAppBrowser,
+    platform: Platform,
+) -> AppResult<Response> {
+    let short_url = with_query(
+        format!("{}/{short_path}", APP_CONFIG.public_base_url),
+        raw_query,
+    );
+    let separator = if short_url.contains('?') { '&' } else { '?' };
+    let continue_url = format!("{short_url}{separator}{}", RedirectQuery::STAY_IN_APP);
+    let escape_url = in_app_escape_url(browser, platform, &short_url, &continue_url);
+
+    let template = InAppTemplate {
+        app_name: browser.display_name(),
+        auto_escape: escape_url.is_some()
+            && url_data.in_app_browser_mode == InAppBrowserMode::Escape,
+        escape_url: escape_url.unwrap_or_default(),
+        continue_url,
+    };
+
+    let html = template.render()?;
+    Ok(([(header::CACHE_CONTROL, "no-store")], Html(html)).into_response())
+}
+
+/// Returns the path of a short URL visit: the short key and the encoded path suffix.
+fn short_path(short_key: &str, suffix: Option<&str>) -> String {
+    suffix.map_or_else(
+        || short_key.to_string(),
+        |suffix| format!("{short_key}/{suffix}"),
+    )
+}
+
+/// Appends the raw query of the visit to a short URL.
+fn with_query(mut short_url: String, raw_query: Option<&str>) -> String {
+    if let Some(query) = raw_query.filter(|q| !q.is_empty()) {
+        short_url.push('?');
+        short_url.push_str(query);
+    }
+    short_url
+}
+
+/// Renders the password prompt page.
+///
+/// The page never reveals the destination and is not cached by browsers.
+fn render_password_page(status: StatusCode, invalid: bool) -> AppResult<Response> {
+    let html = PasswordTemplate { invalid }.render()?;
+    Ok((status, [(header::CACHE_CONTROL, "no-store")], Html(html)).into_response())
+}
+
+/// Renders the "link disabled" page with `410 Gone`.
+fn render_disabled_page() -> AppResult<Response> {
+    let html = DisabledTemplate {}.render()?;
+    Ok((
+        StatusCode::GONE,
+        [(header::CACHE_CONTROL, "no-store")],
+        Html(html),
+    )
+        .into_response())
+}
+
+/// Deferred deep link match handler.
+///
+/// Called by a freshly installed app to claim the deep link of the short
+/// URL that led its user to the store. Clicks are matched by IP prefix
+/// and platform within the deferred link window, preferring the browser
+/// family of the app's `userAgent` (or request `User-Agent`), and the most
+/// recent one is returned with a confidence score; a claimed click cannot
+/// be claimed again. Returns `NotFound` when no click matches, including
+/// clicks on links that have since been deactivated or expired.
+///
+/// # Route
+///
+/// `POST /v1/deferred/match`
+pub async fn deferred_match_handler(
+    State(state): State<AppState>,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    Json(req_body): Json<DeferredMatchRequest>,
+) -> AppResult<Json<DeferredMatchResponse>> {
+    if !matches!(req_body.platform, Platform::Ios | Platform::Android) {
+        return Err(AppError::Validation(
+            "Platform must be ios or android".to_string(),
+        ));
+    }
+
+    let no_match = || AppError::NotFound("No matching click".to_string());
+    let peer_ip = connect_info.map(|Extension(ConnectInfo(addr))| addr.ip());
+    let ip_prefix = client_ip(&headers, peer_ip)
+        .map(truncate_ip)
+        .ok_or_else(no_match)?;
+
+    let user_agent = req_body
+        .user_agent
+        .as_deref()
+        .or_else(|| {
+            headers
+                .get(header::USER_AGENT)
+                .and_then(|h| h.to_str().ok())
+        })
+        .unwrap_or_default();
+    let matched = DeferredLinkRepository::claim(
+        &state.cache,
+        &ip_prefix,
+        req_body.platform,
+        UserAgentInfo::parse(user_agent).browser,
+        deferred_link_ttl(),
+        Utc::now(),
+    )
+    .await?
+    .ok_or_else(no_match)?;
+
+    // The link may have been changed, expired or removed since the click
+    let url_data = match load_url_data(&state, &matched.click.short_key).await {
+        Ok(url_data) if url_data.is_active => url_data,
+        Ok(_) | Err(AppError::Gone(_)) => return Err(no_match()),
+        Err(e) => return Err(e),
+    };
+    Ok(Json(DeferredMatchResponse {
+        short_url: format!("{}/{}", APP_CONFIG.public_base_url, matched.click.short_key),
+        deep_link: url_data.deep_link_for(req_body.platform),
+        short_key: matched.click.short_key,
+        clicked_at: matched.click.clicked_at,
+        confidence: matched.confidence,
+    }))
+}
+
+/// Abuse report handler.
+///
+/// Lets any visitor report a malicious link for admin review. Reports
+/// from an IP prefix that already has an open report on the link are
+/// accepted but not stored again.
+///
+/// # Route
+///
+/// `POST /report/:short_key`
+pub async fn report_url_handler(
+    State(state): State<AppState>,
+    Path(short_key): Path<String>,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    Json(req_body): Json<ReportUrlRequest>,
+) -> AppResult<(StatusCode, Json<ReportUrlResponse>)> {
+    req_body.validate().map_err(|e| e.to_validation_error())?;
+
+    let url_data = load_url_data(&state, &short_key).await?;
+    let peer_ip = connect_info.map(|Extension(ConnectInfo(addr))| addr.ip());
+    let non_empty = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let report = NewReport {
+        url_id: url_data.id,
+        reason: req_body.reason,
+        details: non_empty(req_body.details),
+        reporter_email: non_empty(req_body.email),
+        ip_prefix: client_ip(&headers, peer_ip).map(truncate_ip),
+    };
+    if !ReportRepository::create(&state.db, &report).await? {
+        tracing::debug!(url_id = url_data.id, "Duplicate abuse report dropped");
+    }
+
+    Ok((StatusCode::ACCEPTED, Json(ReportUrlResponse::received())))
+}
+
+/// Link listing handler (admin only).
+///
+/// Same as `GET /v1/urls`, but lists the links of every owner and includes
+/// each link's owner.
+///
+/// # Route
+///
+/// `GET /v1/admin/urls`
+pub async fn list_all_urls_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ListUrlsQuery>,
+) -> AppResult<Json<ListAdminUrlsResponse>> {
+    let (urls, next_cursor) = list_url_page(&state, None, query).await?;
+
+    Ok(Json(ListAdminUrlsResponse {
+        items: urls.into_iter().map(AdminUrlResponse::from).collect(),
+        next_cursor,
+    }))
+}
+
+/// Abuse report listing handler (admin only).
+///
+/// Returns a page of reports of all owners' links ordered by ID descending.
+///
+/// # Route
+///
+/// `GET /v1/admin/reports`
+pub async fn list_reports_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ListReportsQuery>,
+) -> AppResult<Json<ListReportsResponse>> {
+    query.validate().map_err(|e| e.to_validation_error())?;
+
+    let limit = query.limit.unwrap_or(ListReportsQuery::DEFAULT_LIMIT);
+    let filter = ReportListFilter {
+        cursor: query.cursor,
+        // Fetch one extra row to know whether another page exists
+        limit: limit + 1,
+        status: query.status,
+    };
+
+    let mut reports = ReportRepository::list(&state.db, &filter).await?;
+
+    let has_more = i64::try_from(reports.len()).is_ok_and(|len| len > limit);
+    if has_more {
+        reports.pop();
+    }
+    let next_cursor = has_more.then(|| reports.last().map(|r| r.id)).flatten();
+
+    Ok(Json(ListReportsResponse {
+        items: reports.into_iter().map(ReportResponse::from).collect(),
+        next_cursor,
+    }))
+}
+
+/// Link takedown handler (admin only).
+///
+/// Disables a link of any owner, evicts its cached redirect data so the
+/// disabled page is shown right away, and resolves its open reports.
+/// The owner cannot re-activate a disabled link.
+///
+/// # Route
+///
+/// `POST /v1/admin/urls/:short_key/disable`
+pub async fn disable_url_handler(
+    State(state): State<AppState>,
+    Path(short_key): Path<String>,
+) -> AppResult<Json<DisableUrlResponse>> {
+    let url = find_url_by_short_key(&state, None, &short_key).await?;
+
+    let disabled = UrlRepository::disable(&state.db, url.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("URL not found".to_string()))?;
+    evict_url_cache(&state, &url_cache_keys(&disabled)).await;
+    let resolved_reports = ReportRepository::resolve_for_url(&state.db, disabled.id).await?;
+    queue_link_events(&state, WebhookEventType::Updated, [&disabled]).await;
+
+    tracing::info!(
+        url_id = disabled.id,
+        resolved_reports,
+        "Link disabled by admin"
+    );
+
+    #[allow(clippy::cast_sign_loss)]
+    let short_key = merge_short_key(&disabled.random_key, disabled.id as u64);
+    Ok(Json(DisableUrlResponse {
+        short_key,
+        is_active: disabled.is_active,
+        resolved_reports,
+    }))
+}
+
+/// Data purge handler (admin only).
+///
+/// Permanently deletes links soft-deleted more than `olderThanDays` days
+/// ago, with their clicks and reports, and the finished webhook deliveries
+/// of the same age.
+///
+/// # Route
+///
+/// `POST /v1/admin/maintenance/purge`
+pub async fn purge_handler(
+    State(state): State<AppState>,
+    Json(req_body): Json<PurgeRequest>,
+) -> AppResult<Json<PurgeResponse>> {
+    req_body.validate().map_err(|e| e.to_validation_error())?;
+
+    let days = req_body
+        .older_than_days
+        .unwrap_or(PurgeRequest::DEFAULT_OLDER_THAN_DAYS);
+    let cutoff = Utc::now() - chrono::Duration::days(days);
+
+    let purged_urls = UrlRepository::purge_deleted(&state.db, cutoff).await?;
+    let purged_webhook_deliveries = WebhookRepository::purge_finished(&state.db, cutoff).await?;
+
+    tracing::info!(
+        older_than_days = days,
+        purged_urls,
+        purged_webhook_deliveries,
+        "Purged old data"
+    );
+
+    Ok(Json(PurgeResponse {
+        purged_urls,
+        purged_webhook_deliveries,
+    }))
+}
+
+/// Owner reassignment handler (admin only).
+///
+/// Moves every link of one owner to another, for example the links created
+/// with the shared `guest` subject before links were scoped to owners,
+/// which no issued token can reach.
+///
+/// # Route
+///
+/// `POST /v1/admin/maintenance/reassign-owner`
+pub async fn reassign_owner_handler(
+    State(state): State<AppState>,
+    Json(req_body): Json<ReassignOwnerRequest>,
+) -> AppResult<Json<ReassignOwnerResponse>> {
+    req_body.validate().map_err(|e| e.to_validation_error())?;
+    if req_body.from == req_body.to {
+        return Err(AppError::Validation(
+            "from and to must be different owners".to_string(),
+        ));
+    }
+
+    let reassigned_urls =
+        UrlRepository::reassign_owner(&state.db, &req_body.from, &req_body.to).await?;
+    let remaining_urls = UrlRepository::count_by_owner(&state.db, &req_body.from).await?;
+
+    tracing::info!(
+        from = %req_body.from,
+        to = %req_body.to,
+        reassigned_urls,
+        remaining_urls,
+        "Reassigned link owner"
+    );
+
+    Ok(Json(ReassignOwnerResponse {
+        reassigned_urls,
+        remaining_urls,
+    }))
+}
+
+/// Cache policy of the `/.well-known/` association files.
+const ASSOCIATION_FILE_CACHE_CONTROL: &str = "public, max-age=3600";
+
+/// Apple App Site Association handler.
+///
+/// Serves the Universal Links association file of the registered iOS apps
+/// as `application/json`, which Apple fetches without following redirects.
+/// Returns `NotFound` when no iOS app is registered.
+///
+/// # Route
+///
+/// `GET /.well-known/apple-app-site-association`
+pub async fn apple_app_site_association_handler(
+    State(state): State<AppState>,
+) -> AppResult<Response> {
+    association_file_response(state.app_links.apple_app_site_association.as_deref())
+}
+
+/// Digital Asset Links handler.
+///
+/// Serves the App Links statement list of the registered Android apps.
+/// Returns `NotFound` when no Android app is registered.
+///
+/// # Route
+///
+/// `GET /.well-known/assetlinks.json`
+pub async fn asset_links_handler(State(state): State<AppState>) -> AppResult<Response> {
+    association_file_response(state.app_links.asset_links.as_deref())
+}
+
+/// Builds the response of a pre-rendered association file.
+fn association_file_response(body: Option<&str>) -> AppResult<Response> {
+    let body = body.ok_or_else(|| AppError::NotFound("No apps registered".to_string()))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json"),
+            (header::CACHE_CONTROL, ASSOCIATION_FILE_CACHE_CONTROL),
+        ],
+        body.to_string(),
+    )
+        .into_response())
+}
+
+/// Health check response.
+#[derive(serde::Serialize)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub version: &'static str,
+}
+
+/// Liveness probe handler.
 ///
 /// Returns OK if the server is running. Used for Kubernetes liveness probe.
 ///
@@ -324,6 +2049,7 @@ pub async fn readiness_handler(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::UrlVariants;
 
     // ============ TemplateUrlData 테스트 ============
 
@@ -335,12 +2061,30 @@ mod tests {
             ios_fallback_url: Some("https://apps.apple.com/app".to_string()),
             android_deep_link: Some("app://android/path".to_string()),
             android_fallback_url: Some("https://play.google.com/app".to_string()),
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://example.com".to_string(),
             webhook_url: Some("https://webhook.example.com".to_string()),
             og_title: Some("Test Title".to_string()),
             og_description: Some("Test Description".to_string()),
             og_image_url: Some("https://example.com/image.png".to_string()),
             is_active: true,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            password_hash: None,
+            alias: None,
         }
     }
 
@@ -352,12 +2096,30 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://minimal.com".to_string(),
             webhook_url: None,
             og_title: None,
             og_description: None,
             og_image_url: None,
             is_active: true,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            password_hash: None,
+            alias: None,
         }
     }
 
@@ -416,12 +2178,30 @@ mod tests {
             ios_fallback_url: Some(String::new()),
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://test.com".to_string(),
             webhook_url: None,
             og_title: Some(String::new()),
             og_description: None,
             og_image_url: None,
             is_active: true,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            password_hash: None,
+            alias: None,
         };
 
         let template_data = TemplateUrlData::from(&cache_data);
@@ -441,12 +2221,30 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://example.com/한글".to_string(),
             webhook_url: None,
             og_title: Some("한글 제목 🚀".to_string()),
             og_description: Some("日本語の説明".to_string()),
             og_image_url: None,
             is_active: true,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            password_hash: None,
+            alias: None,
         };
 
         let template_data = TemplateUrlData::from(&cache_data);
@@ -465,12 +2263,30 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://example.com/path?param=value&other=123".to_string(),
             webhook_url: None,
             og_title: Some("Title with <script> & \"quotes\"".to_string()),
             og_description: None,
             og_image_url: None,
             is_active: true,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            password_hash: None,
+            alias: None,
         };
 
         let template_data = TemplateUrlData::from(&cache_data);
@@ -479,6 +2295,21 @@ mod tests {
         assert!(template_data.og_title.contains("<script>"));
     }
 
+    #[test]
+    fn test_template_url_data_prefers_android_intent_url() {
+        let mut cache_data = create_test_url_cache_data();
+        cache_data.android_package = Some("com.example.app".to_string());
+        cache_data.android_scheme = Some("app".to_string());
+        cache_data.android_path = Some("android/path".to_string());
+
+        let template_data = TemplateUrlData::from(&cache_data);
+
+        assert!(template_data
+            .android_deep_link
+            .starts_with("intent://android/path#Intent;scheme=app;package=com.example.app;"));
+        assert_eq!(template_data.ios_deep_link, "app://ios/path");
+    }
+
     // ============ INDEX_HTML 테스트 ============
 
     #[test]
@@ -501,14 +2332,14 @@ mod tests {
     #[test]
     fn test_render_redirect_page_success() {
         let cache_data = create_test_url_cache_data();
-        let result = render_redirect_page(&cache_data);
+        let result = render_redirect_page(&cache_data, Platform::Ios);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_render_redirect_page_minimal() {
         let cache_data = create_minimal_url_cache_data();
-        let result = render_redirect_page(&cache_data);
+        let result = render_redirect_page(&cache_data, Platform::Ios);
         assert!(result.is_ok());
     }
 
@@ -517,7 +2348,7 @@ mod tests {
         use axum::body::to_bytes;
 
         let cache_data = create_test_url_cache_data();
-        let response = render_redirect_page(&cache_data).unwrap();
+        let response = render_redirect_page(&cache_data, Platform::Ios).unwrap();
 
         let body = to_bytes(response.into_body(), 10240).await.unwrap();
         let html = String::from_utf8_lossy(&body);
@@ -530,7 +2361,7 @@ mod tests {
         use axum::body::to_bytes;
 
         let cache_data = create_test_url_cache_data();
-        let response = render_redirect_page(&cache_data).unwrap();
+        let response = render_redirect_page(&cache_data, Platform::Ios).unwrap();
 
         let body = to_bytes(response.into_body(), 10240).await.unwrap();
         let html = String::from_utf8_lossy(&body);
@@ -538,23 +2369,145 @@ mod tests {
         assert!(html.contains("https://example.com"));
     }
 
-    // ============ CreateShortUrlRequest 해시 생성 로직 테스트 ============
+    #[tokio::test]
+    async fn test_render_redirect_page_contains_desktop_targets() {
+        use axum::body::to_bytes;
 
-    #[test]
-    fn test_hash_input_format() {
-        // 핸들러에서 사용하는 해시 입력 형식 테스트
-        let ios_deep_link = "app://ios";
-        let ios_fallback = "https://apps.apple.com";
-        let android_deep_link = "app://android";
-        let android_fallback = "https://play.google.com";
-        let default_fallback = "https://example.com";
+        let mut cache_data = create_test_url_cache_data();
+        cache_data.desktop_deep_link = Some("myapp://open".to_string());
+        cache_data.windows_fallback_url = Some("https://example.com/windows".to_string());
+        let response = render_redirect_page(&cache_data, Platform::Windows).unwrap();
 
-        let hash_input = format!(
-            "{}:{}:{}:{}:{}",
-            ios_deep_link, ios_fallback, android_deep_link, android_fallback, default_fallback
-        );
+        let body = to_bytes(response.into_body(), 10240).await.unwrap();
+        let html = String::from_utf8_lossy(&body);
 
-        assert!(hash_input.contains("app://ios"));
+        assert!(html.contains(r#"data-desktop-deeplink="myapp://open""#));
+        assert!(html.contains(r#"data-windows-fallback="https://example.com/windows""#));
+        assert!(html.contains(r#"data-macos-fallback="""#));
+        assert!(html.contains(r#"data-platform="windows""#));
+    }
+
+    #[tokio::test]
+    async fn test_render_redirect_page_passes_server_platform() {
+        use axum::body::to_bytes;
+
+        let cache_data = create_test_url_cache_data();
+        let response = render_redirect_page(&cache_data, Platform::Android).unwrap();
+
+        let body = to_bytes(response.into_body(), 10240).await.unwrap();
+        let html = String::from_utf8_lossy(&body);
+
+        assert!(html.contains(r#"data-platform="android""#));
+    }
+
+    // ============ render_in_app_page 테스트 ============
+
+    async fn render_in_app_html(
+        mode: InAppBrowserMode,
+        browser: InAppBrowser,
+        platform: Platform,
+    ) -> String {
+        use axum::body::to_bytes;
+
+        let mut cache_data = create_test_url_cache_data();
+        cache_data.in_app_browser_mode = mode;
+        let response = render_in_app_page(&cache_data, "AbXy", None, browser, platform).unwrap();
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+
+        let body = to_bytes(response.into_body(), 10240).await.unwrap();
+        String::from_utf8_lossy(&body).into_owned()
+    }
+
+    #[test]
+    fn test_shows_in_app_page() {
+        let mut cache_data = create_test_url_cache_data();
+        assert!(shows_in_app_page(&cache_data));
+
+        cache_data.in_app_browser_mode = InAppBrowserMode::Prompt;
+        assert!(shows_in_app_page(&cache_data));
+
+        cache_data.in_app_browser_mode = InAppBrowserMode::Off;
+        assert!(!shows_in_app_page(&cache_data));
+
+        cache_data.in_app_browser_mode = InAppBrowserMode::Escape;
+        cache_data.redirect_mode = RedirectMode::Http302;
+        assert!(!shows_in_app_page(&cache_data));
+    }
+
+    #[tokio::test]
+    async fn test_render_in_app_page_escapes_kakaotalk() {
+        let html = render_in_app_html(
+            InAppBrowserMode::Escape,
+            InAppBrowser::KakaoTalk,
+            Platform::Ios,
+        )
+        .await;
+
+        assert!(html.contains("KakaoTalk"));
+        assert!(html.contains("kakaotalk://web/openExternal?url="));
+        assert!(html.contains(r#"data-auto-escape="true""#));
+        assert!(html.contains("AbXy?stayInApp=1"));
+    }
+
+    #[tokio::test]
+    async fn test_render_in_app_page_prompt_does_not_auto_escape() {
+        let html = render_in_app_html(
+            InAppBrowserMode::Prompt,
+            InAppBrowser::Instagram,
+            Platform::Android,
+        )
+        .await;
+
+        assert!(html.contains("intent:"));
+        assert!(html.contains("package=com.android.chrome"));
+        assert!(html.contains(r#"data-auto-escape="false""#));
+    }
+
+    #[tokio::test]
+    async fn test_render_in_app_page_without_escape_shows_instructions() {
+        let html = render_in_app_html(
+            InAppBrowserMode::Escape,
+            InAppBrowser::Instagram,
+            Platform::Ios,
+        )
+        .await;
+
+        assert!(html.contains(r#"data-escape-url="""#));
+        assert!(html.contains(r#"data-auto-escape="false""#));
+        assert!(html.contains("menu and choose"));
+        assert!(html.contains("AbXy?stayInApp=1"));
+    }
+
+    // ============ render_disabled_page 테스트 ============
+
+    #[tokio::test]
+    async fn test_render_disabled_page_is_gone() {
+        use axum::body::to_bytes;
+
+        let response = render_disabled_page().unwrap();
+        assert_eq!(response.status(), StatusCode::GONE);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+
+        let body = to_bytes(response.into_body(), 10240).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("This link has been disabled"));
+    }
+
+    // ============ CreateShortUrlRequest 해시 생성 로직 테스트 ============
+
+    #[test]
+    fn test_hash_input_format() {
+        // 핸들러에서 사용하는 해시 입력 형식 테스트
+        let ios_deep_link = "app://ios";
+        let ios_fallback = "https://apps.apple.com";
+        let android_deep_link = "app://android";
+        let android_fallback = "https://play.google.com";
+        let default_fallback = "https://example.com";
+
+        let hash_input = format!(
+            "{ios_deep_link}:{ios_fallback}:{android_deep_link}:{android_fallback}:{default_fallback}"
+        );
+
+        assert!(hash_input.contains("app://ios"));
         assert!(hash_input.contains("https://example.com"));
         // 4개의 구분자 (5개 필드를 구분)
         // URL에 포함된 콜론도 포함되므로, 최소 4개의 구분자가 있는지 확인
@@ -575,6 +2528,783 @@ mod tests {
         assert_eq!(hash_input, "::::https://example.com");
     }
 
+    #[test]
+    fn test_compute_hashed_value_matches_legacy_format() {
+        // 기존 저장된 해시와 호환되어야 함
+        let legacy = format!(
+            "{:032x}",
+            xxh3_128("app://ios:::app://android:https://example.com".as_bytes())
+        );
+        let hashed = compute_hashed_value(
+            &["app://ios", "", "", "app://android", "https://example.com"],
+            &link_settings_hash_extras(
+                RedirectMode::Interstitial,
+                InAppBrowserMode::Escape,
+                None,
+                None,
+                None,
+                None,
+            ),
+        );
+        assert_eq!(hashed, legacy);
+        assert_eq!(hashed.len(), 32);
+    }
+
+    #[test]
+    fn test_compute_hashed_value_different_inputs() {
+        let hash1 = compute_hashed_value(&["", "", "", "", "https://a.com"], &[]);
+        let hash2 = compute_hashed_value(&["", "", "", "", "https://b.com"], &[]);
+        assert_ne!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_compute_hashed_value_extras_change_hash() {
+        let fields = ["", "", "", "", "https://a.com"];
+        let hash = |mode, expires_at, max_clicks| {
+            compute_hashed_value(
+                &fields,
+                &link_settings_hash_extras(
+                    mode,
+                    InAppBrowserMode::default(),
+                    expires_at,
+                    max_clicks,
+                    None,
+                    None,
+                ),
+            )
+        };
+        let expires_at = "2030-01-01T00:00:00Z".parse().ok();
+
+        let default_settings = hash(RedirectMode::default(), None, None);
+        assert_eq!(default_settings, compute_hashed_value(&fields, &[]));
+        assert_ne!(default_settings, hash(RedirectMode::Http302, None, None));
+        assert_ne!(
+            hash(RedirectMode::Http302, None, None),
+            hash(RedirectMode::Http301, None, None)
+        );
+        assert_ne!(
+            default_settings,
+            hash(RedirectMode::default(), expires_at, None)
+        );
+        assert_ne!(
+            default_settings,
+            hash(RedirectMode::default(), None, Some(10))
+        );
+        assert_ne!(
+            hash(RedirectMode::default(), None, Some(10)),
+            hash(RedirectMode::default(), None, Some(20))
+        );
+    }
+
+    #[test]
+    fn test_compute_hashed_value_alias_changes_hash() {
+        let fields = ["", "", "", "", "https://a.com"];
+        let hash = |alias| {
+            compute_hashed_value(
+                &fields,
+                &link_settings_hash_extras(
+                    RedirectMode::default(),
+                    InAppBrowserMode::default(),
+                    None,
+                    None,
+                    alias,
+                    None,
+                ),
+            )
+        };
+
+        assert_eq!(hash(None), compute_hashed_value(&fields, &[]));
+        assert_ne!(hash(None), hash(Some("promo")));
+        assert_ne!(hash(Some("promo")), hash(Some("promo2")));
+    }
+
+    #[test]
+    fn test_compute_hashed_value_android_app_link_changes_hash() {
+        let fields = ["", "", "", "", "https://a.com"];
+        let hash = |package, scheme, path| {
+            compute_hashed_value(&fields, &android_app_hash_extras(package, scheme, path))
+        };
+
+        assert_eq!(hash(None, None, None), compute_hashed_value(&fields, &[]));
+        assert_ne!(
+            hash(None, None, None),
+            hash(Some("com.example.app"), Some("myapp"), None)
+        );
+        assert_ne!(
+            hash(Some("com.example.app"), Some("myapp"), Some("a")),
+            hash(Some("com.example.app"), Some("myapp"), Some("b"))
+        );
+    }
+
+    #[test]
+    fn test_compute_hashed_value_desktop_targets_change_hash() {
+        let fields = ["", "", "", "", "https://a.com"];
+        let hash = |deep_link, macos, windows, linux| {
+            compute_hashed_value(
+                &fields,
+                &desktop_hash_extras(deep_link, macos, windows, linux),
+            )
+        };
+
+        assert_eq!(
+            hash(None, None, None, None),
+            compute_hashed_value(&fields, &[])
+        );
+        assert_ne!(
+            hash(None, None, None, None),
+            hash(Some("myapp://open"), None, None, None)
+        );
+        // 같은 URL이라도 OS가 다르면 다른 해시
+        assert_ne!(
+            hash(None, Some("https://a.com/mac"), None, None),
+            hash(None, None, Some("https://a.com/mac"), None)
+        );
+        assert_ne!(
+            hash(None, None, Some("https://a.com/dl"), None),
+            hash(None, None, None, Some("https://a.com/dl"))
+        );
+    }
+
+    #[test]
+    fn test_compute_hashed_value_in_app_browser_mode_changes_hash() {
+        let fields = ["", "", "", "", "https://a.com"];
+        let hash = |in_app_browser_mode| {
+            compute_hashed_value(
+                &fields,
+                &link_settings_hash_extras(
+                    RedirectMode::default(),
+                    in_app_browser_mode,
+                    None,
+                    None,
+                    None,
+                    None,
+                ),
+            )
+        };
+
+        assert_eq!(
+            hash(InAppBrowserMode::Escape),
+            compute_hashed_value(&fields, &[])
+        );
+        assert_ne!(
+            hash(InAppBrowserMode::Escape),
+            hash(InAppBrowserMode::Prompt)
+        );
+        assert_ne!(hash(InAppBrowserMode::Prompt), hash(InAppBrowserMode::Off));
+    }
+
+    // ============ url_cache_keys 테스트 ============
+
+    fn create_test_url(alias: Option<&str>) -> Url {
+        Url {
+            id: 100,
+            random_key: "AbXy".to_string(),
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: "https://example.com".to_string(),
+            hashed_value: "hash".to_string(),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            is_active: true,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: alias.map(str::to_string),
+            owner: "user".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_url_cache_keys_without_alias() {
+        let url = create_test_url(None);
+        let keys = url_cache_keys(&url);
+
+        assert_eq!(keys, vec![format!("urls:{}", merge_short_key("AbXy", 100))]);
+    }
+
+    #[test]
+    fn test_url_cache_keys_with_alias() {
+        let url = create_test_url(Some("promo"));
+        let keys = url_cache_keys(&url);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0], format!("urls:{}", merge_short_key("AbXy", 100)));
+        assert_eq!(keys[1], "urls:alias:promo");
+    }
+
+    // ============ build_new_url 테스트 ============
+
+    fn create_test_request(default_fallback_url: Option<&str>) -> CreateShortUrlRequest {
+        CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: default_fallback_url.map(str::to_string),
+            webhook_url: None,
+            og_title: Some(String::new()),
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::default(),
+            in_app_browser_mode: InAppBrowserMode::default(),
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_new_url_success() {
+        let mut req = create_test_request(Some("https://example.com"));
+        req.alias = Some("Promo".to_string());
+
+        let new_url = build_new_url(
+            "user".to_string(),
+            req,
+            Utc::now(),
+            &DomainBlocklist::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(new_url.owner, "user");
+        assert_eq!(new_url.random_key.len(), 4);
+        assert_eq!(new_url.default_fallback_url, "https://example.com");
+        assert_eq!(new_url.alias.as_deref(), Some("promo"));
+        // 빈 문자열은 None으로 저장됨
+        assert!(new_url.og_title.is_none());
+        assert!(new_url.is_active);
+        assert!(new_url.password_hash.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_build_new_url_hashes_password() {
+        let now = Utc::now();
+        let mut req = create_test_request(Some("https://example.com"));
+        req.password = Some("s3cret".to_string());
+
+        let protected = build_new_url(
+            "user".to_string(),
+            req.clone(),
+            now,
+            &DomainBlocklist::default(),
+        )
+        .await
+        .unwrap();
+        let password_hash = protected.password_hash.as_deref().unwrap();
+        assert_ne!(password_hash, "s3cret");
+        assert!(verify_password("s3cret", password_hash));
+
+        // 솔트 때문에 같은 비밀번호라도 중복으로 간주되지 않음
+        let again = build_new_url("user".to_string(), req, now, &DomainBlocklist::default())
+            .await
+            .unwrap();
+        assert_ne!(protected.hashed_value, again.hashed_value);
+    }
+
+    #[tokio::test]
+    async fn test_build_new_url_same_input_same_hash() {
+        let now = Utc::now();
+        let a = build_new_url(
+            "user".to_string(),
+            create_test_request(Some("https://a.com")),
+            now,
+            &DomainBlocklist::default(),
+        )
+        .await
+        .unwrap();
+        let b = build_new_url(
+            "user".to_string(),
+            create_test_request(Some("https://a.com")),
+            now,
+            &DomainBlocklist::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(a.hashed_value, b.hashed_value);
+    }
+
+    #[tokio::test]
+    async fn test_build_new_url_validation_errors() {
+        let now = Utc::now();
+
+        let missing = build_new_url(
+            "user".to_string(),
+            create_test_request(None),
+            now,
+            &DomainBlocklist::default(),
+        )
+        .await;
+        assert!(matches!(missing, Err(AppError::Validation(_))));
+
+        let invalid = build_new_url(
+            "user".to_string(),
+            create_test_request(Some("nope")),
+            now,
+            &DomainBlocklist::default(),
+        )
+        .await;
+        assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+        let mut past = create_test_request(Some("https://example.com"));
+        past.expires_at = Some(now - chrono::Duration::seconds(1));
+        assert!(matches!(
+            build_new_url("user".to_string(), past, now, &DomainBlocklist::default()).await,
+            Err(AppError::Validation(_))
+        ));
+
+        let mut reserved = create_test_request(Some("https://example.com"));
+        reserved.alias = Some("batch".to_string());
+        assert!(matches!(
+            build_new_url(
+                "user".to_string(),
+                reserved,
+                now,
+                &DomainBlocklist::default()
+            )
+            .await,
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_build_new_url_rejects_unsafe_destinations() {
+        let now = Utc::now();
+        let mut blocklist = DomainBlocklist::default();
+        blocklist.extend_hosts("0.0.0.0 phish.example");
+        let build = |req| build_new_url("user".to_string(), req, now, &blocklist);
+        let message = |result: AppResult<NewUrl>| match result {
+            Err(AppError::Validation(message)) => message,
+            other => panic!("expected a validation error, got {other:?}"),
+        };
+
+        let mut script = create_test_request(Some("https://example.com"));
+        script.ios_deep_link = Some("javascript:alert(1)".to_string());
+        assert_eq!(
+            message(build(script).await),
+            "iosDeepLink must not use the 'javascript' scheme"
+        );
+
+        let mut app_fallback = create_test_request(Some("https://example.com"));
+        app_fallback.android_fallback_url = Some("myapp://open".to_string());
+        assert_eq!(
+            message(build(app_fallback).await),
+            "androidFallbackUrl must be an http or https URL"
+        );
+
+        let blocked = create_test_request(Some("https://www.phish.example/login"));
+        assert_eq!(
+            message(build(blocked).await),
+            "defaultFallbackUrl points to a blocked domain (phish.example)"
+        );
+
+        let mut deep_link = create_test_request(Some("https://example.com"));
+        deep_link.ios_deep_link = Some("myapp://product/42".to_string());
+        assert!(build(deep_link).await.is_ok());
+    }
+
+    // ============ HTTP 리디렉션 모드 테스트 ============
+
+    #[test]
+    fn test_http_redirect_status() {
+        assert_eq!(http_redirect_status(RedirectMode::Interstitial), None);
+        assert_eq!(
+            http_redirect_status(RedirectMode::Http302),
+            Some(StatusCode::FOUND)
+        );
+        assert_eq!(
+            http_redirect_status(RedirectMode::Http301),
+            Some(StatusCode::MOVED_PERMANENTLY)
+        );
+        assert_eq!(
+            http_redirect_status(RedirectMode::Http307),
+            Some(StatusCode::TEMPORARY_REDIRECT)
+        );
+    }
+
+    #[test]
+    fn test_http_redirect_sets_location() {
+        let response = http_redirect(StatusCode::FOUND, "https://example.com/path?q=1").unwrap();
+
+        assert_eq!(response.status(), StatusCode::FOUND);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "https://example.com/path?q=1"
+        );
+    }
+
+    #[test]
+    fn test_http_redirect_invalid_location() {
+        // 헤더에 허용되지 않는 문자(개행)는 에러
+        let result = http_redirect(StatusCode::FOUND, "https://example.com/\r\nSet-Cookie: a=b");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_per_visit_redirect_status_is_never_permanent() {
+        assert_eq!(
+            per_visit_redirect_status(RedirectMode::Http307),
+            StatusCode::TEMPORARY_REDIRECT
+        );
+        for mode in [
+            RedirectMode::Interstitial,
+            RedirectMode::Http302,
+            RedirectMode::Http301,
+        ] {
+            assert_eq!(per_visit_redirect_status(mode), StatusCode::FOUND);
+        }
+    }
+
+    #[test]
+    fn test_redirect_response_cache_headers() {
+        let mut url_data = create_test_url_cache_data();
+        url_data.redirect_mode = RedirectMode::Http301;
+
+        let response = redirect_response(&url_data, Platform::Ios, None, false).unwrap();
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(response.headers()[header::VARY], "User-Agent");
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            PERMANENT_REDIRECT_CACHE_CONTROL
+        );
+
+        for mode in [RedirectMode::Http302, RedirectMode::Http307] {
+            url_data.redirect_mode = mode;
+            let response = redirect_response(&url_data, Platform::Ios, None, false).unwrap();
+            assert_eq!(response.headers()[header::VARY], "User-Agent");
+            assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+        }
+
+        // 인터스티셜 페이지는 리디렉션 캐시 헤더를 붙이지 않음
+        url_data.redirect_mode = RedirectMode::Interstitial;
+        let response = redirect_response(&url_data, Platform::Ios, None, false).unwrap();
+        assert!(response.headers().get(header::VARY).is_none());
+    }
+
+    #[test]
+    fn test_redirect_response_vary_with_variants_and_rules() {
+        let mut url_data = split_url_cache_data();
+        url_data.redirect_mode = RedirectMode::Http302;
+        let response = redirect_response(&url_data, Platform::Other, None, true).unwrap();
+        assert_eq!(response.headers()[header::VARY], "User-Agent, Cookie");
+
+        url_data.routing_rules = serde_json::from_str(
+            r#"[{"conditions": {"languages": ["ko"]}, "destination": "https://a.com/ko"}]"#,
+        )
+        .unwrap();
+        let response =
+            redirect_response(&url_data, Platform::Other, Some("https://a.com/ko"), true).unwrap();
+        assert_eq!(
+            response.headers()[header::VARY],
+            "User-Agent, Cookie, Accept-Language"
+        );
+
+        url_data.variants = UrlVariants::default();
+        let response = redirect_response(&url_data, Platform::Other, None, false).unwrap();
+        assert_eq!(
+            response.headers()[header::VARY],
+            "User-Agent, Accept-Language"
+        );
+    }
+
+    #[test]
+    fn test_redirect_response_expiring_link_is_never_permanent() {
+        let mut url_data = create_test_url_cache_data();
+        url_data.redirect_mode = RedirectMode::Http301;
+        url_data.expires_at = Some(Utc::now() + chrono::Duration::days(1));
+
+        let response = redirect_response(&url_data, Platform::Other, None, false).unwrap();
+        assert_eq!(response.status(), StatusCode::FOUND);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+
+        url_data.expires_at = None;
+        url_data.max_clicks = Some(1);
+        let response = redirect_response(&url_data, Platform::Other, None, false).unwrap();
+        assert_eq!(response.status(), StatusCode::FOUND);
+    }
+
+    // ============ matching_rule_destination 테스트 ============
+
+    #[test]
+    fn test_matching_rule_destination() {
+        let mut url_data = create_test_url_cache_data();
+        url_data.routing_rules = serde_json::from_str(
+            r#"[
+                {"conditions": {"query": {"src": "ad"}}, "destination": "https://a.com/ad"},
+                {"conditions": {"languages": ["ko"]}, "destination": "https://a.com/ko"}
+            ]"#,
+        )
+        .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT_LANGUAGE, "ko-KR,en;q=0.5".parse().unwrap());
+
+        let destination = |headers: &HeaderMap, query| {
+            let query = parse_query(query);
+            matching_rule_destination(&url_data, headers, None, Platform::Ios, &query)
+                .map(str::to_string)
+        };
+        assert_eq!(
+            destination(&headers, Some("src=ad")),
+            Some("https://a.com/ad".to_string())
+        );
+        assert_eq!(
+            destination(&headers, Some("src=mail")),
+            Some("https://a.com/ko".to_string())
+        );
+        assert_eq!(destination(&HeaderMap::new(), None), None);
+    }
+
+    #[test]
+    fn test_matching_rule_destination_without_rules() {
+        let url_data = create_test_url_cache_data();
+        assert_eq!(
+            matching_rule_destination(&url_data, &HeaderMap::new(), Some("KR"), Platform::Ios, &[]),
+            None
+        );
+    }
+
+    // ============ 쿼리 파라미터 병합 테스트 ============
+
+    fn visit_query() -> Vec<(String, String)> {
+        parse_query(Some("ref=news&utm_source=visit&stayInApp=1"))
+    }
+
+    #[test]
+    fn test_merge_visit_query_adds_utm_params() {
+        let mut url_data = create_test_url_cache_data();
+        url_data.utm_params = UtmParams {
+            source: Some("newsletter".to_string()),
+            campaign: Some("spring sale".to_string()),
+            ..UtmParams::default()
+        };
+
+        let destination = merge_visit_query(
+            &mut url_data,
+            Some("https://a.com/rule".to_string()),
+            &visit_query(),
+        );
+
+        // 패스스루가 꺼져 있으면 방문 쿼리는 전달되지 않음
+        assert_eq!(
+            destination.as_deref(),
+            Some("https://a.com/rule?utm_source=newsletter&utm_campaign=spring+sale")
+        );
+        assert_eq!(
+            url_data.fallback_url_for(Platform::Windows),
+            "https://example.com?utm_source=newsletter&utm_campaign=spring+sale"
+        );
+    }
+
+    #[test]
+    fn test_merge_visit_query_passthrough_overrides_utm_params() {
+        let mut url_data = create_test_url_cache_data();
+        url_data.query_passthrough = true;
+        url_data.utm_params = UtmParams {
+            source: Some("newsletter".to_string()),
+            medium: Some("email".to_string()),
+            ..UtmParams::default()
+        };
+
+        let destination = merge_visit_query(&mut url_data, None, &visit_query());
+
+        assert!(destination.is_none());
+        assert_eq!(
+            url_data.fallback_url_for(Platform::Windows),
+            "https://example.com?ref=news&utm_source=visit&utm_medium=email"
+        );
+    }
+
+    #[test]
+    fn test_merge_visit_query_without_params_keeps_destinations() {
+        let mut url_data = create_test_url_cache_data();
+        let expected = url_data.clone();
+
+        let destination = merge_visit_query(&mut url_data, None, &visit_query());
+
+        assert!(destination.is_none());
+        assert_eq!(url_data.default_fallback_url, expected.default_fallback_url);
+        assert_eq!(url_data.ios_deep_link, expected.ios_deep_link);
+    }
+
+    // ============ 경로 전달 테스트 ============
+
+    #[test]
+    fn test_forward_visit_path() {
+        let mut url_data = create_test_url_cache_data();
+        url_data.default_fallback_url = "https://example.com/shop?ref=a".to_string();
+
+        let destination = forward_visit_path(
+            &mut url_data,
+            Some("https://a.com/rule/".to_string()),
+            Some("products/42"),
+        );
+
+        assert_eq!(
+            destination.as_deref(),
+            Some("https://a.com/rule/products/42")
+        );
+        assert_eq!(
+            url_data.ios_deep_link.as_deref(),
+            Some("app://ios/path/products/42")
+        );
+        assert_eq!(
+            url_data.default_fallback_url,
+            "https://example.com/shop/products/42?ref=a"
+        );
+    }
+
+    #[test]
+    fn test_forward_visit_path_without_suffix_keeps_destinations() {
+        let mut url_data = create_test_url_cache_data();
+        let expected = url_data.clone();
+
+        let destination =
+            forward_visit_path(&mut url_data, Some("https://a.com/rule".to_string()), None);
+
+        assert_eq!(destination.as_deref(), Some("https://a.com/rule"));
+        assert_eq!(url_data.ios_deep_link, expected.ios_deep_link);
+        assert_eq!(url_data.default_fallback_url, expected.default_fallback_url);
+    }
+
+    #[test]
+    fn test_short_path() {
+        assert_eq!(short_path("AbXy", Some("products/42")), "AbXy/products/42");
+        assert_eq!(short_path("AbXy", None), "AbXy");
+    }
+
+    #[test]
+    fn test_with_query() {
+        assert_eq!(with_query("/AbXy".to_string(), Some("a=1")), "/AbXy?a=1");
+        assert_eq!(with_query("/AbXy".to_string(), Some("")), "/AbXy");
+        assert_eq!(with_query("/AbXy".to_string(), None), "/AbXy");
+    }
+
+    // ============ A/B 변형 테스트 ============
+
+    fn split_url_cache_data() -> UrlCacheData {
+        let mut url_data = create_test_url_cache_data();
+        url_data.variants = serde_json::from_str(
+            r#"[
+                {"name": "a", "destination": "https://a.com", "weight": 50},
+                {"name": "b", "destination": "https://b.com", "weight": 50}
+            ]"#,
+        )
+        .unwrap();
+        url_data
+    }
+
+    #[test]
+    fn test_assign_variant_issues_visitor_cookie() {
+        let url_data = split_url_cache_data();
+        let (variant, jar) = assign_variant(&url_data, CookieJar::new());
+
+        let cookie = jar.get(VISITOR_COOKIE_NAME).unwrap();
+        let visitor_id = visitor_id(Some(cookie)).unwrap();
+        assert_eq!(
+            variant.as_ref(),
+            url_data.variants.assign(url_data.id, &visitor_id)
+        );
+    }
+
+    #[test]
+    fn test_assign_variant_is_sticky() {
+        let url_data = split_url_cache_data();
+        let jar = CookieJar::new().add(visitor_cookie(new_visitor_id()));
+
+        let (first, jar) = assign_variant(&url_data, jar);
+        for _ in 0..5 {
+            let (variant, _) = assign_variant(&url_data, jar.clone());
+            assert_eq!(variant, first);
+        }
+    }
+
+    #[test]
+    fn test_assign_variant_without_variants() {
+        let (variant, jar) = assign_variant(&create_test_url_cache_data(), CookieJar::new());
+        assert!(variant.is_none());
+        assert!(jar.get(VISITOR_COOKIE_NAME).is_none());
+    }
+
+    #[test]
+    fn test_redirect_response_with_variant_is_temporary() {
+        let mut url_data = split_url_cache_data();
+        url_data.redirect_mode = RedirectMode::Http301;
+
+        let response = redirect_response(&url_data, Platform::Other, None, false).unwrap();
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+
+        url_data.default_fallback_url = "https://b.com".to_string();
+        let response = redirect_response(&url_data, Platform::Other, None, true).unwrap();
+        assert_eq!(response.status(), StatusCode::FOUND);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "https://b.com"
+        );
+    }
+
+    // ============ merge_patch_field 테스트 ============
+
+    #[test]
+    fn test_merge_patch_field_absent_keeps_current() {
+        let current = Some("https://current.com".to_string());
+        assert_eq!(merge_patch_field(None, current.clone()), current);
+    }
+
+    #[test]
+    fn test_merge_patch_field_null_clears() {
+        let current = Some("https://current.com".to_string());
+        assert_eq!(merge_patch_field(Some(None), current), None);
+    }
+
+    #[test]
+    fn test_merge_patch_field_empty_string_clears() {
+        let current = Some("https://current.com".to_string());
+        assert_eq!(merge_patch_field(Some(Some(String::new())), current), None);
+    }
+
+    #[test]
+    fn test_merge_patch_field_value_replaces() {
+        let patched = merge_patch_field(Some(Some("https://new.com".to_string())), None);
+        assert_eq!(patched, Some("https://new.com".to_string()));
+    }
+
     #[test]
     fn test_xxhash_deterministic() {
         let input = "test_input_for_hash";
@@ -596,6 +3326,224 @@ mod tests {
         assert_eq!(hash.len(), 32); // 128비트 = 32 hex chars
     }
 
+    // ============ deferred_link_ttl 테스트 ============
+
+    #[test]
+    fn test_deferred_link_ttl_is_bounded() {
+        let ttl = deferred_link_ttl();
+        assert!(ttl >= chrono::Duration::seconds(1));
+        assert!(ttl <= chrono::Duration::seconds(MAX_DEFERRED_LINK_TTL_SECS));
+    }
+
+    // ============ association_file_response 테스트 ============
+
+    #[tokio::test]
+    async fn test_association_file_response_headers() {
+        use axum::body::to_bytes;
+
+        let response = association_file_response(Some(r#"{"applinks":{}}"#)).unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            ASSOCIATION_FILE_CACHE_CONTROL
+        );
+        let body = to_bytes(response.into_body(), 1024).await.unwrap();
+        assert_eq!(&body[..], br#"{"applinks":{}}"#);
+    }
+
+    #[test]
+    fn test_association_file_response_not_registered() {
+        assert!(matches!(
+            association_file_response(None),
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    // ============ 방문 기록 테스트 ============
+
+    // 아래 테스트는 마이그레이션이 적용된 `DB_*` PostgreSQL과 `REDIS_*` Redis가
+    // 필요 (`cargo test -- --ignored`)
+
+    /// 전역 풀은 다른 테스트의 런타임에 묶일 수 있으므로 테스트마다 새로 연결
+    async fn connected_state() -> (AppState, tokio::task::JoinHandle<()>) {
+        use crate::config::{database_url, redis_url, AppLinks};
+        use crate::models::ClickRecorder;
+
+        let db = sqlx::PgPool::connect(&database_url())
+            .await
+            .expect("database");
+        let cache = deadpool_redis::Config::from_url(redis_url())
+            .create_pool(Some(deadpool_redis::Runtime::Tokio1))
+            .expect("redis");
+        let (clicks, writer) =
+            ClickRecorder::spawn(db.clone(), 100, std::time::Duration::from_millis(100), 100);
+        let state = AppState::new(
+            db,
+            cache,
+            clicks,
+            AppLinks::default(),
+            DomainBlocklist::default(),
+        );
+        (state, writer)
+    }
+
+    /// 테스트 링크를 만들고 ID와 단축 키를 반환
+    async fn insert_test_url(
+        db: &sqlx::PgPool,
+        max_clicks: Option<i64>,
+        webhook_url: Option<&str>,
+    ) -> (i64, String) {
+        let random_key = gen_rand_str(4);
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO urls \
+             (random_key, default_fallback_url, hashed_value, max_clicks, webhook_url) \
+             VALUES ($1, 'https://example.com', $2, $3, $4) RETURNING id",
+        )
+        .bind(&random_key)
+        .bind(format!("visit-test-{}", gen_rand_str(16)))
+        .bind(max_clicks)
+        .bind(webhook_url)
+        .fetch_one(db)
+        .await
+        .unwrap();
+        #[allow(clippy::cast_sign_loss)]
+        let short_key = merge_short_key(&random_key, id as u64);
+        (id, short_key)
+    }
+
+    async fn visit(state: &AppState, short_key: &str, user_agent: &str) -> StatusCode {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let request = Request::get(format!("/{short_key}"))
+            .header(header::USER_AGENT, user_agent)
+            .body(Body::empty())
+            .unwrap();
+        crate::api::create_routes(state.clone())
+            .oneshot(request)
+            .await
+            .unwrap()
+            .status()
+    }
+
+    async fn delete_test_url(db: &sqlx::PgPool, id: i64) {
+        sqlx::query("DELETE FROM urls WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await
+            .unwrap();
+    }
+
+    const BROWSER_UA: &str =
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15";
+
+    #[tokio::test]
+    #[ignore = "requires PostgreSQL and Redis"]
+    async fn test_bot_visit_does_not_spend_click_limit() {
+        let (state, _writer) = connected_state().await;
+        let (id, short_key) = insert_test_url(&state.db, Some(1), None).await;
+
+        // 링크 미리보기 봇은 클릭 수를 소모하지 않음
+        let bot = "Slackbot-LinkExpanding 1.0 (+https://api.slack.com/robots)";
+        assert_eq!(visit(&state, &short_key, bot).await, StatusCode::OK);
+        assert_eq!(visit(&state, &short_key, BROWSER_UA).await, StatusCode::OK);
+        assert_eq!(
+            visit(&state, &short_key, BROWSER_UA).await,
+            StatusCode::GONE
+        );
+
+        delete_test_url(&state.db, id).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires PostgreSQL and Redis"]
+    async fn test_click_webhook_survives_dropped_click() {
+        let (state, writer) = connected_state().await;
+        let (id, short_key) =
+            insert_test_url(&state.db, None, Some("https://webhook.example.com/hook")).await;
+
+        // 클릭 기록기가 멈춰 클릭이 버려져도 웹훅 이벤트는 응답 전에 기록됨
+        writer.abort();
+        let _ = writer.await;
+        assert_eq!(visit(&state, &short_key, BROWSER_UA).await, StatusCode::OK);
+
+        let queued: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM webhook_deliveries \
+             WHERE url_id = $1 AND payload->>'type' = 'link.clicked'",
+        )
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(queued, 1);
+
+        delete_test_url(&state.db, id).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires PostgreSQL and Redis"]
+    async fn test_deferred_match_skips_expired_link() {
+        use axum::body::Body;
+        use axum::extract::ConnectInfo;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        use crate::models::{DeferredClick, DeferredLinkRepository};
+        use crate::utils::BrowserFamily;
+
+        let (state, _writer) = connected_state().await;
+        let (id, short_key) = insert_test_url(&state.db, None, None).await;
+        sqlx::query("UPDATE urls SET ios_deep_link = 'myapp://a' WHERE id = $1")
+            .bind(id)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let peer: SocketAddr = format!("198.18.{}.7:4000", id % 256).parse().unwrap();
+        let record = || async {
+            let click = DeferredClick {
+                short_key: short_key.clone(),
+                ip_prefix: truncate_ip(peer.ip()),
+                platform: Platform::Ios,
+                clicked_at: Utc::now(),
+                browser: BrowserFamily::Safari,
+            };
+            DeferredLinkRepository::record(&state.cache, &click, chrono::Duration::minutes(5))
+                .await
+                .unwrap();
+        };
+        let claim = || async {
+            let mut request = Request::post("/v1/deferred/match")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"platform": "ios"}"#))
+                .unwrap();
+            request.extensions_mut().insert(ConnectInfo(peer));
+            crate::api::create_routes(state.clone())
+                .oneshot(request)
+                .await
+                .unwrap()
+                .status()
+        };
+
+        record().await;
+        assert_eq!(claim().await, StatusCode::OK);
+
+        // 만료된 링크는 410이 아니라 일치하는 클릭이 없는 것으로 응답
+        record().await;
+        sqlx::query("UPDATE urls SET expires_at = NOW() - INTERVAL '1 minute' WHERE id = $1")
+            .bind(id)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        evict_url_cache(&state, &[format!("urls:{short_key}")]).await;
+        assert_eq!(claim().await, StatusCode::NOT_FOUND);
+
+        delete_test_url(&state.db, id).await;
+    }
+
     // ============ Health Check 핸들러 테스트 ============
 
     #[tokio::test]
@@ -2,16 +2,21 @@
 //!
 //! Provides authentication and other request processing middleware.
 
-use axum::{body::Body, extract::Request, http::header, middleware::Next, response::Response};
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::Response,
+};
 use axum_extra::extract::CookieJar;
 
 use crate::error::AppError;
-use crate::utils::{parse_token, Claims};
+use crate::utils::{parse_token, Claims, Scope};
 
 /// Extension type for storing authenticated user claims.
 /// Can be extracted in handlers via axum's Extension extractor.
 #[derive(Clone)]
-#[allow(dead_code)]
 pub struct AuthUser(pub Claims);
 
 /// JWT Authentication Middleware.
@@ -53,6 +58,39 @@ pub async fn jwt_auth(
     }
 }
 
+/// Scope Authorization Middleware.
+///
+/// Runs after [`jwt_auth`] and only lets requests through whose token
+/// grants the scope given as the middleware state:
+///
+/// ```ignore
+/// .route_layer(middleware::from_fn_with_state(Scope::Admin, require_scope))
+/// ```
+///
+/// # Error Responses
+///
+/// - 403 Forbidden: When the token lacks the scope
+pub async fn require_scope(
+    State(scope): State<Scope>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    check_scope(request.extensions().get::<AuthUser>(), scope)?;
+    Ok(next.run(request).await)
+}
+
+/// Checks that the authenticated user's token grants a scope.
+fn check_scope(auth_user: Option<&AuthUser>, scope: Scope) -> Result<(), AppError> {
+    if auth_user.is_some_and(|AuthUser(claims)| claims.has_scope(scope)) {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(format!(
+            "Missing required scope: {}",
+            scope.as_str()
+        )))
+    }
+}
+
 /// Extracts the JWT token from the request.
 ///
 /// First checks the Authorization header for a Bearer token,
@@ -231,6 +269,46 @@ mod tests {
         assert_eq!(token, Some("unicode_test_token_123".to_string()));
     }
 
+    // ============ check_scope 함수 테스트 ============
+
+    fn auth_user(scopes: &[Scope]) -> AuthUser {
+        AuthUser(Claims {
+            sub: "test_user".to_string(),
+            exp: 9_999_999_999,
+            iat: 1_000_000_000,
+            scopes: scopes.to_vec(),
+        })
+    }
+
+    #[test]
+    fn test_check_scope_allows_granted_scope() {
+        let user = auth_user(Scope::GUEST);
+        assert!(check_scope(Some(&user), Scope::UrlsCreate).is_ok());
+        assert!(check_scope(Some(&user), Scope::UrlsManage).is_ok());
+
+        // admin은 모든 라우트에 접근 가능
+        let admin = auth_user(&[Scope::Admin]);
+        assert!(check_scope(Some(&admin), Scope::UrlsCreate).is_ok());
+        assert!(check_scope(Some(&admin), Scope::Admin).is_ok());
+    }
+
+    #[test]
+    fn test_check_scope_rejects_missing_scope() {
+        let guest = auth_user(Scope::GUEST);
+        match check_scope(Some(&guest), Scope::Admin) {
+            Err(AppError::Forbidden(message)) => {
+                assert_eq!(message, "Missing required scope: admin");
+            }
+            other => panic!("expected Forbidden, got {other:?}"),
+        }
+
+        let creator = auth_user(&[Scope::UrlsCreate]);
+        assert!(check_scope(Some(&creator), Scope::UrlsManage).is_err());
+        assert!(check_scope(Some(&auth_user(&[])), Scope::UrlsCreate).is_err());
+        // jwt_auth를 거치지 않은 요청
+        assert!(check_scope(None, Scope::UrlsCreate).is_err());
+    }
+
     // ============ AuthUser 구조체 테스트 ============
 
     #[test]
@@ -239,11 +317,12 @@ mod tests {
 
         let claims = Claims {
             sub: "test_user".to_string(),
-            exp: 9999999999,
-            iat: 1000000000,
+            exp: 9_999_999_999,
+            iat: 1_000_000_000,
+            scopes: Vec::new(),
         };
 
-        let auth_user = AuthUser(claims.clone());
+        let auth_user = AuthUser(claims);
         let cloned = auth_user.clone();
 
         assert_eq!(auth_user.0.sub, cloned.0.sub);
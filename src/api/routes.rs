@@ -3,17 +3,24 @@
 //! Defines all HTTP routes and their middleware stack.
 
 use axum::{
+    http::StatusCode,
     middleware,
-    routing::{get, post},
+    routing::{any, get, post},
     Router,
 };
 
 use crate::api::handlers::{
-    create_short_url_handler, health_handler, index_handler, readiness_handler,
-    redirect_to_original_handler,
+    apple_app_site_association_handler, asset_links_handler, batch_create_short_urls_handler,
+    create_short_url_handler, deferred_match_handler, delete_short_url_handler,
+    disable_url_handler, get_short_url_handler, get_short_url_qr_handler,
+    get_short_url_stats_handler, health_handler, index_handler, list_all_urls_handler,
+    list_reports_handler, list_short_urls_handler, purge_handler, readiness_handler,
+    reassign_owner_handler, redirect_to_original_handler, report_url_handler,
+    unlock_short_url_handler, update_short_url_handler,
 };
-use crate::api::middlewares::jwt_auth;
+use crate::api::middlewares::{jwt_auth, require_scope};
 use crate::api::state::AppState;
+use crate::utils::Scope;
 
 /// Creates and configures all application routes.
 ///
@@ -23,26 +30,102 @@ use crate::api::state::AppState;
 /// - `GET /health` - Liveness probe
 /// - `GET /ready` - Readiness probe
 ///
+/// ## App Link Association Routes
+/// - `GET /.well-known/apple-app-site-association` - iOS Universal Links association file
+/// - `GET /.well-known/assetlinks.json` - Android App Links statement list
+///
 /// ## Template Routes
 /// - `GET /` - Main page
 /// - `GET /:short_key` - Redirect to original URL
+/// - `POST /:short_key` - Submit the password of a protected URL
+/// - `GET /:short_key/*rest` - Redirect with the rest of the path appended (path forwarding links)
+/// - `POST /:short_key/*rest` - Submit the password of a protected path forwarding URL
+/// - `POST /report/:short_key` - Report an abusive link
+///
+/// ## API Routes (v1, JWT authentication with the listed scope)
+/// - `POST /v1/urls` - Create short URL (`urls:create`)
+/// - `POST /v1/urls/batch` - Create up to 1000 short URLs at once (`urls:create`)
+/// - `GET /v1/urls` - List short URLs with cursor pagination (`urls:manage`)
+/// - `GET /v1/urls/:short_key` - Get short URL details (`urls:manage`)
+/// - `PATCH /v1/urls/:short_key` - Update short URL (`urls:manage`)
+/// - `DELETE /v1/urls/:short_key` - Soft-delete short URL (`urls:manage`)
+/// - `GET /v1/urls/:short_key/stats` - Get click statistics (`urls:manage`)
+/// - `GET /v1/urls/:short_key/qr` - Render a PNG/SVG QR code (`urls:manage`)
+/// - `POST /v1/deferred/match` - Claim the deep link of the click before an app install (no auth)
 ///
-/// ## API Routes (v1)
-/// - `POST /v1/urls` - Create short URL (requires JWT authentication)
+/// ## Admin Routes (v1, `admin` scope)
+/// - `GET /v1/admin/urls` - List the links of all owners with cursor pagination
+/// - `GET /v1/admin/reports` - List abuse reports with cursor pagination
+/// - `POST /v1/admin/urls/:short_key/disable` - Disable a link and resolve its reports
+/// - `POST /v1/admin/maintenance/purge` - Purge old deleted links and webhook deliveries
+/// - `POST /v1/admin/maintenance/reassign-owner` - Move every link of one owner to another
 pub fn create_routes(state: AppState) -> Router {
+    // Scoped routes, authenticated by the JWT layer of the v1 routes
+    let create_routes = Router::new()
+        .route("/urls", post(create_short_url_handler))
+        .route("/urls/batch", post(batch_create_short_urls_handler))
+        .route_layer(middleware::from_fn_with_state(
+            Scope::UrlsCreate,
+            require_scope,
+        ));
+
+    let admin_routes = Router::new()
+        .route("/admin/urls", get(list_all_urls_handler))
+        .route("/admin/reports", get(list_reports_handler))
+        .route("/admin/urls/{short_key}/disable", post(disable_url_handler))
+        .route("/admin/maintenance/purge", post(purge_handler))
+        .route(
+            "/admin/maintenance/reassign-owner",
+            post(reassign_owner_handler),
+        )
+        .route_layer(middleware::from_fn_with_state(Scope::Admin, require_scope));
+
     // API v1 routes with JWT authentication
     let v1_routes = Router::new()
-        .route("/urls", post(create_short_url_handler))
-        .route_layer(middleware::from_fn(jwt_auth));
+        .route("/urls", get(list_short_urls_handler))
+        .route(
+            "/urls/{short_key}",
+            get(get_short_url_handler)
+                .patch(update_short_url_handler)
+                .delete(delete_short_url_handler),
+        )
+        .route("/urls/{short_key}/stats", get(get_short_url_stats_handler))
+        .route("/urls/{short_key}/qr", get(get_short_url_qr_handler))
+        .route_layer(middleware::from_fn_with_state(
+            Scope::UrlsManage,
+            require_scope,
+        ))
+        .merge(create_routes)
+        .merge(admin_routes)
+        .route_layer(middleware::from_fn(jwt_auth))
+        // Called by freshly installed apps, before any sign-in
+        .route("/deferred/match", post(deferred_match_handler))
+        // Keeps unknown API paths from reaching the path forwarding route
+        .route("/{*rest}", any(|| async { StatusCode::NOT_FOUND }));
 
     // Main router
     Router::new()
         // Health check routes (no auth required)
         .route("/health", get(health_handler))
         .route("/ready", get(readiness_handler))
+        // App link associations (registered before the short key catch-all)
+        .route(
+            "/.well-known/apple-app-site-association",
+            get(apple_app_site_association_handler),
+        )
+        .route("/.well-known/assetlinks.json", get(asset_links_handler))
         // Template routes
         .route("/", get(index_handler))
-        .route("/{short_key}", get(redirect_to_original_handler))
+        .route(
+            "/{short_key}",
+            get(redirect_to_original_handler).post(unlock_short_url_handler),
+        )
+        .route(
+            "/{short_key}/{*rest}",
+            get(redirect_to_original_handler).post(unlock_short_url_handler),
+        )
+        // Abuse reports (no auth required)
+        .route("/report/{short_key}", post(report_url_handler))
         // API routes
         .nest("/v1", v1_routes)
         // Shared state
@@ -2,10 +2,17 @@
 //!
 //! Contains DTOs for API request validation and response serialization.
 
-use serde::{Deserialize, Serialize};
-use validator::Validate;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use validator::{Validate, ValidationError};
 
 use crate::error::AppError;
+use crate::models::{
+    ClickBucketCount, ClickStats, InAppBrowserMode, PlatformClickCount, RedirectMode, Report,
+    ReportReason, ReportStatus, RoutingRules, StatsBucket, Url, UrlStatusFilter, UrlVariants,
+    UtmParams, VariantClickCount,
+};
+use crate::utils::{merge_short_key, Platform, QrErrorCorrection, QrFormat, QrOptions};
 
 /// Short URL creation request structure.
 ///
@@ -33,6 +40,63 @@ pub struct CreateShortUrlRequest {
     #[serde(default)]
     pub android_fallback_url: Option<String>,
 
+    /// Android app package name, e.g. `com.example.app` (optional, requires `android_scheme`)
+    #[validate(length(max = 255, message = "androidPackage must be at most 255 characters"))]
+    #[serde(default)]
+    pub android_package: Option<String>,
+
+    /// URI scheme handled by the Android app (optional, requires `android_package`)
+    #[validate(length(max = 64, message = "androidScheme must be at most 64 characters"))]
+    #[serde(default)]
+    pub android_scheme: Option<String>,
+
+    /// Host and path opened in the Android app (optional)
+    #[validate(length(max = 2048, message = "androidPath must be at most 2048 characters"))]
+    #[serde(default)]
+    pub android_path: Option<String>,
+
+    /// Desktop app deep link URL, tried on macOS, Windows and Linux (optional)
+    #[validate(url(message = "Invalid desktop deep link URL"))]
+    #[serde(default)]
+    pub desktop_deep_link: Option<String>,
+
+    /// URL to redirect on macOS when the desktop app is not installed (optional)
+    #[validate(url(message = "Invalid macOS fallback URL"))]
+    #[serde(default)]
+    pub macos_fallback_url: Option<String>,
+
+    /// URL to redirect on Windows when the desktop app is not installed (optional)
+    #[validate(url(message = "Invalid Windows fallback URL"))]
+    #[serde(default)]
+    pub windows_fallback_url: Option<String>,
+
+    /// URL to redirect on Linux when the desktop app is not installed (optional)
+    #[validate(url(message = "Invalid Linux fallback URL"))]
+    #[serde(default)]
+    pub linux_fallback_url: Option<String>,
+
+    /// Ordered routing rules, the first match wins (optional, at most 20)
+    #[serde(default)]
+    pub routing_rules: RoutingRules,
+
+    /// Weighted A/B destinations whose weights sum to 100 (optional)
+    #[validate(custom(function = "validate_variants"))]
+    #[serde(default)]
+    pub variants: UrlVariants,
+
+    /// UTM parameters merged into every destination (optional)
+    #[serde(default)]
+    pub utm_params: UtmParams,
+
+    /// Whether to forward the short URL's query parameters (optional, default `false`)
+    #[serde(default)]
+    pub query_passthrough: bool,
+
+    /// Whether to append a path after the short key to the destinations
+    /// (optional, default `false`)
+    #[serde(default)]
+    pub allow_path_forwarding: bool,
+
     /// Default redirect URL (required)
     #[validate(
         required(message = "Default fallback URL is required"),
@@ -59,6 +123,32 @@ pub struct CreateShortUrlRequest {
     #[validate(url(message = "Invalid OG image URL"))]
     #[serde(default)]
     pub og_image_url: Option<String>,
+
+    /// Redirect mode (optional, default `interstitial`)
+    #[serde(default)]
+    pub redirect_mode: RedirectMode,
+
+    /// Handling of in-app browser visits (optional, default `escape`)
+    #[serde(default)]
+    pub in_app_browser_mode: InAppBrowserMode,
+
+    /// Expiration time (optional, RFC 3339, must be in the future)
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Maximum number of redirects (optional, at least 1)
+    #[validate(range(min = 1, message = "maxClicks must be at least 1"))]
+    #[serde(default)]
+    pub max_clicks: Option<i64>,
+
+    /// Vanity alias (optional, see [`normalize_alias`])
+    #[serde(default)]
+    pub alias: Option<String>,
+
+    /// Password visitors must enter before being redirected (optional)
+    #[validate(length(min = 4, max = 128, message = "password must be 4-128 characters"))]
+    #[serde(default)]
+    pub password: Option<String>,
 }
 
 /// Response for short URL creation.
@@ -67,6 +157,8 @@ pub struct CreateShortUrlResponse {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub short_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
 }
 
 impl CreateShortUrlResponse {
@@ -75,6 +167,7 @@ impl CreateShortUrlResponse {
         Self {
             message: "URL created successfully".to_string(),
             short_key: Some(short_key),
+            alias: None,
         }
     }
 
@@ -83,627 +176,2615 @@ impl CreateShortUrlResponse {
         Self {
             message: "URL already exists".to_string(),
             short_key: Some(short_key),
+            alias: None,
         }
     }
+
+    /// Sets the vanity alias of the URL.
+    #[must_use]
+    pub fn with_alias(mut self, alias: Option<String>) -> Self {
+        self.alias = alias;
+        self
+    }
 }
 
-/// Validates a short URL key.
-///
-/// # Validation Rules
-///
-/// - Must be at least 5 characters long (2 prefix + 1 ID char + 2 suffix)
-/// - Must contain only alphanumeric characters (a-z, A-Z, 0-9)
-pub fn validate_short_key(short_key: &str) -> Result<(), AppError> {
-    use crate::utils::short_key::SHORT_KEY_MIN_LEN;
+/// Maximum number of URLs created by one batch request.
+pub const MAX_BATCH_CREATE_ITEMS: usize = 1000;
+
+/// Outcome of one item of a batch creation request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    /// A new URL was created.
+    Created,
+    /// An identical URL of the caller already existed.
+    Existing,
+    /// The item was rejected.
+    Error,
+}
 
-    if short_key.len() < SHORT_KEY_MIN_LEN {
-        return Err(AppError::BadRequest(format!(
-            "short_key must be at least {SHORT_KEY_MIN_LEN} characters long"
-        )));
+/// Result of one item of a batch creation request.
+#[derive(Debug, Serialize)]
+pub struct BatchCreateItemResult {
+    /// Position of the item in the request array
+    pub index: usize,
+    pub status: BatchItemStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BatchCreateItemResult {
+    /// Creates the result of a newly created URL.
+    pub const fn created(index: usize, short_key: String, alias: Option<String>) -> Self {
+        Self {
+            index,
+            status: BatchItemStatus::Created,
+            short_key: Some(short_key),
+            alias,
+            error: None,
+        }
     }
 
-    if !short_key.chars().all(|c| c.is_ascii_alphanumeric()) {
-        return Err(AppError::BadRequest(
-            "short_key must contain only English letters and numbers".to_string(),
-        ));
+    /// Creates the result of an item that matched an existing URL.
+    pub const fn existing(index: usize, short_key: String, alias: Option<String>) -> Self {
+        Self {
+            index,
+            status: BatchItemStatus::Existing,
+            short_key: Some(short_key),
+            alias,
+            error: None,
+        }
     }
 
-    Ok(())
+    /// Creates the result of a rejected item.
+    pub const fn error(index: usize, message: String) -> Self {
+        Self {
+            index,
+            status: BatchItemStatus::Error,
+            short_key: None,
+            alias: None,
+            error: Some(message),
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use validator::Validate;
+/// Response for batch short URL creation.
+#[derive(Debug, Serialize)]
+pub struct BatchCreateShortUrlResponse {
+    pub created: usize,
+    pub existing: usize,
+    pub failed: usize,
+    /// One result per request item, in request order
+    pub results: Vec<BatchCreateItemResult>,
+}
 
-    // ============ validate_short_key 테스트 ============
+impl BatchCreateShortUrlResponse {
+    /// Creates a response from per-item results, sorting them by index.
+    pub fn new(mut results: Vec<BatchCreateItemResult>) -> Self {
+        results.sort_by_key(|result| result.index);
+        let count = |status| {
+            results
+                .iter()
+                .filter(|result| result.status == status)
+                .count()
+        };
 
-    #[test]
-    fn test_validate_short_key_valid_minimum() {
-        // 최소 5자: prefix(2) + id(1) + suffix(2)
-        assert!(validate_short_key("ab1xy").is_ok());
+        Self {
+            created: count(BatchItemStatus::Created),
+            existing: count(BatchItemStatus::Existing),
+            failed: count(BatchItemStatus::Error),
+            results,
+        }
     }
+}
 
-    #[test]
-    fn test_validate_short_key_valid_mixed() {
-        assert!(validate_short_key("ABC123").is_ok());
-        assert!(validate_short_key("a1B2c3D4").is_ok());
-    }
+/// Password form posted from the password prompt page.
+#[derive(Debug, Deserialize)]
+pub struct UnlockUrlForm {
+    pub password: String,
+}
 
-    #[test]
-    fn test_validate_short_key_valid_numbers_only() {
-        assert!(validate_short_key("12345").is_ok());
-        assert!(validate_short_key("00000").is_ok());
-    }
+/// Path parameters of the short URL redirect and unlock routes.
+#[derive(Debug, Deserialize)]
+pub struct RedirectPath {
+    /// Short key or vanity alias
+    pub short_key: String,
+    /// Path after the short key (`/{short_key}/{*rest}`), forwarded to the destinations
+    #[serde(default)]
+    pub rest: Option<String>,
+}
 
-    #[test]
-    fn test_validate_short_key_valid_long() {
-        assert!(validate_short_key("abcdefghijklmnopqrstuvwxyz").is_ok());
-    }
+/// Query parameters of the short URL redirect.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedirectQuery {
+    /// Set by the "open in browser" page to continue inside the in-app browser
+    #[serde(default)]
+    pub stay_in_app: Option<String>,
+}
 
-    #[test]
-    fn test_validate_short_key_exactly_five_chars() {
-        assert!(validate_short_key("ab1cd").is_ok());
-        assert!(validate_short_key("12345").is_ok());
-        assert!(validate_short_key("a1B2c").is_ok());
-    }
+impl RedirectQuery {
+    /// Query parameter set by the "open in browser" page.
+    pub const STAY_IN_APP_PARAM: &'static str = "stayInApp";
 
-    #[test]
-    fn test_validate_short_key_too_short_empty() {
-        let result = validate_short_key("");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// Query string that continues a redirect inside the in-app browser.
+    pub const STAY_IN_APP: &'static str = "stayInApp=1";
+}
 
-    #[test]
-    fn test_validate_short_key_too_short_one() {
-        let result = validate_short_key("a");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+/// Short URL update request structure.
+///
+/// Every field is optional: an absent field keeps its current value,
+/// and an explicit `null` clears an optional field.
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::option_option)]
+pub struct UpdateShortUrlRequest {
+    /// iOS app deep link URL
+    #[validate(url(message = "Invalid iOS deep link URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub ios_deep_link: Option<Option<String>>,
 
-    #[test]
-    fn test_validate_short_key_too_short_four() {
-        let result = validate_short_key("abcd");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// URL to redirect when iOS app is not installed
+    #[validate(url(message = "Invalid iOS fallback URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub ios_fallback_url: Option<Option<String>>,
 
-    #[test]
-    fn test_validate_short_key_invalid_hyphen() {
-        let result = validate_short_key("abc-def");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// Android app deep link URL
+    #[validate(url(message = "Invalid Android deep link URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub android_deep_link: Option<Option<String>>,
 
-    #[test]
-    fn test_validate_short_key_invalid_underscore() {
-        let result = validate_short_key("abc_def");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// URL to redirect when Android app is not installed
+    #[validate(url(message = "Invalid Android fallback URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub android_fallback_url: Option<Option<String>>,
+
+    /// Android app package name
+    #[validate(length(max = 255, message = "androidPackage must be at most 255 characters"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub android_package: Option<Option<String>>,
+
+    /// URI scheme handled by the Android app
+    #[validate(length(max = 64, message = "androidScheme must be at most 64 characters"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub android_scheme: Option<Option<String>>,
+
+    /// Host and path opened in the Android app
+    #[validate(length(max = 2048, message = "androidPath must be at most 2048 characters"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub android_path: Option<Option<String>>,
+
+    /// Desktop app deep link URL
+    #[validate(url(message = "Invalid desktop deep link URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub desktop_deep_link: Option<Option<String>>,
+
+    /// URL to redirect on macOS when the desktop app is not installed
+    #[validate(url(message = "Invalid macOS fallback URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub macos_fallback_url: Option<Option<String>>,
+
+    /// URL to redirect on Windows when the desktop app is not installed
+    #[validate(url(message = "Invalid Windows fallback URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub windows_fallback_url: Option<Option<String>>,
+
+    /// URL to redirect on Linux when the desktop app is not installed
+    #[validate(url(message = "Invalid Linux fallback URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub linux_fallback_url: Option<Option<String>>,
+
+    /// Ordered routing rules, replacing the current ones (`[]` removes them)
+    #[serde(default)]
+    pub routing_rules: Option<RoutingRules>,
 
-    #[test]
-    fn test_validate_short_key_invalid_space() {
-        let result = validate_short_key("abc def");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// Weighted A/B destinations, replacing the current ones (`[]` removes them)
+    #[validate(custom(function = "validate_variants"))]
+    #[serde(default)]
+    pub variants: Option<UrlVariants>,
 
-    #[test]
-    fn test_validate_short_key_invalid_special_chars() {
-        let invalid_chars = ["abc!", "ab@c", "a#bc", "ab$c", "ab%c", "ab^c"];
-        for key in invalid_chars {
-            let result = validate_short_key(key);
-            assert!(
-                matches!(result, Err(AppError::BadRequest(_))),
-                "Expected error for: {key}"
-            );
-        }
-    }
+    /// UTM parameters, replacing the current ones (`{}` removes them)
+    #[serde(default)]
+    pub utm_params: Option<UtmParams>,
 
-    #[test]
-    fn test_validate_short_key_invalid_unicode() {
-        let result = validate_short_key("abc한글");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// Whether to forward the short URL's query parameters
+    #[serde(default)]
+    pub query_passthrough: Option<bool>,
 
-    #[test]
-    fn test_validate_short_key_invalid_emoji() {
-        let result = validate_short_key("abc🚀");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
-    }
+    /// Whether to append a path after the short key to the destinations
+    #[serde(default)]
+    pub allow_path_forwarding: Option<bool>,
 
-    // ============ CreateShortUrlResponse 테스트 ============
+    /// Default redirect URL (cannot be cleared)
+    #[validate(url(message = "Invalid default fallback URL"))]
+    #[serde(default)]
+    pub default_fallback_url: Option<String>,
 
-    #[test]
-    fn test_create_short_url_response_created() {
-        let response = CreateShortUrlResponse::created("AbC123".to_string());
-        assert_eq!(response.message, "URL created successfully");
-        assert_eq!(response.short_key, Some("AbC123".to_string()));
-    }
+    /// Webhook URL
+    #[validate(url(message = "Invalid webhook URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub webhook_url: Option<Option<String>>,
 
-    #[test]
-    fn test_create_short_url_response_already_exists_with_key() {
-        let response = CreateShortUrlResponse::already_exists_with_key("existing123".to_string());
-        assert_eq!(response.message, "URL already exists");
-        assert_eq!(response.short_key, Some("existing123".to_string()));
-    }
+    /// Open Graph title (max 255 characters)
+    #[validate(length(max = 255, message = "OG title must be at most 255 characters"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub og_title: Option<Option<String>>,
 
-    #[test]
-    fn test_create_short_url_response_serialize_created() {
-        let response = CreateShortUrlResponse::created("test123".to_string());
-        let json = serde_json::to_string(&response).unwrap();
-        assert!(json.contains("test123"));
-        assert!(json.contains("URL created successfully"));
-    }
+    /// Open Graph description (max 500 characters)
+    #[validate(length(max = 500, message = "OG description must be at most 500 characters"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub og_description: Option<Option<String>>,
 
-    #[test]
-    fn test_create_short_url_response_serialize_already_exists() {
-        let response = CreateShortUrlResponse::already_exists_with_key("abc123".to_string());
-        let json = serde_json::to_string(&response).unwrap();
-        assert!(json.contains("URL already exists"));
-        // Now includes the existing short_key
-        assert!(json.contains("short_key"));
-        assert!(json.contains("abc123"));
-    }
+    /// Open Graph image URL
+    #[validate(url(message = "Invalid OG image URL"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub og_image_url: Option<Option<String>>,
 
-    // ============ CreateShortUrlRequest 테스트 ============
+    /// Activation status (inactive URLs are not redirected)
+    #[serde(default)]
+    pub is_active: Option<bool>,
 
-    #[test]
-    fn test_create_short_url_request_deserialize_minimal() {
-        let json = r#"{"defaultFallbackUrl": "https://example.com"}"#;
-        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(
-            req.default_fallback_url,
-            Some("https://example.com".to_string())
-        );
-        assert!(req.ios_deep_link.is_none());
-        assert!(req.android_deep_link.is_none());
-    }
+    /// Redirect mode
+    #[serde(default)]
+    pub redirect_mode: Option<RedirectMode>,
 
-    #[test]
-    fn test_create_short_url_request_deserialize_full() {
-        let json = r#"{
-            "defaultFallbackUrl": "https://example.com",
-            "iosDeepLink": "app://ios",
-            "iosFallbackUrl": "https://apps.apple.com",
-            "androidDeepLink": "app://android",
-            "androidFallbackUrl": "https://play.google.com",
-            "webhookUrl": "https://webhook.example.com",
-            "ogTitle": "Test Title",
-            "ogDescription": "Test Description",
-            "ogImageUrl": "https://example.com/image.png"
-        }"#;
-        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(
-            req.default_fallback_url,
-            Some("https://example.com".to_string())
-        );
-        assert_eq!(req.ios_deep_link, Some("app://ios".to_string()));
-        assert_eq!(req.og_title, Some("Test Title".to_string()));
-    }
+    /// Handling of in-app browser visits
+    #[serde(default)]
+    pub in_app_browser_mode: Option<InAppBrowserMode>,
 
-    #[test]
-    fn test_create_short_url_request_validate_valid() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
+    /// Expiration time (RFC 3339, a past time expires the link immediately)
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub expires_at: Option<Option<DateTime<Utc>>>,
+
+    /// Maximum number of redirects (at least 1)
+    #[validate(range(min = 1, message = "maxClicks must be at least 1"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub max_clicks: Option<Option<i64>>,
+
+    /// Vanity alias (see [`normalize_alias`])
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub alias: Option<Option<String>>,
+
+    /// Link password (`null` removes the protection)
+    #[validate(length(min = 4, max = 128, message = "password must be 4-128 characters"))]
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    pub password: Option<Option<String>>,
+}
+
+/// Deserializes a present field (including `null`) as `Some`,
+/// so that an absent field can be told apart from an explicit `null`.
+#[allow(clippy::option_option)]
+fn deserialize_patch_field<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Response describing a single short URL.
+#[derive(Debug, Serialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct UrlResponse {
+    pub short_key: String,
+    pub ios_deep_link: Option<String>,
+    pub ios_fallback_url: Option<String>,
+    pub android_deep_link: Option<String>,
+    pub android_fallback_url: Option<String>,
+    pub android_package: Option<String>,
+    pub android_scheme: Option<String>,
+    pub android_path: Option<String>,
+    pub desktop_deep_link: Option<String>,
+    pub macos_fallback_url: Option<String>,
+    pub windows_fallback_url: Option<String>,
+    pub linux_fallback_url: Option<String>,
+    pub routing_rules: RoutingRules,
+    pub variants: UrlVariants,
+    pub utm_params: UtmParams,
+    pub query_passthrough: bool,
+    pub allow_path_forwarding: bool,
+    pub default_fallback_url: String,
+    pub webhook_url: Option<String>,
+    /// Key for verifying the `X-Webhook-Signature` header of webhook requests
+    pub webhook_secret: String,
+    pub og_title: Option<String>,
+    pub og_description: Option<String>,
+    pub og_image_url: Option<String>,
+    pub is_active: bool,
+    pub redirect_mode: RedirectMode,
+    pub in_app_browser_mode: InAppBrowserMode,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_clicks: Option<i64>,
+    /// Redirects counted against `max_clicks`
+    pub click_count: i64,
+    pub alias: Option<String>,
+    /// Whether visitors must enter a password
+    pub has_password: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<Url> for UrlResponse {
+    fn from(url: Url) -> Self {
+        #[allow(clippy::cast_sign_loss)]
+        let short_key = merge_short_key(&url.random_key, url.id as u64);
+
+        Self {
+            short_key,
+            ios_deep_link: url.ios_deep_link,
+            ios_fallback_url: url.ios_fallback_url,
+            android_deep_link: url.android_deep_link,
+            android_fallback_url: url.android_fallback_url,
+            android_package: url.android_package,
+            android_scheme: url.android_scheme,
+            android_path: url.android_path,
+            desktop_deep_link: url.desktop_deep_link,
+            macos_fallback_url: url.macos_fallback_url,
+            windows_fallback_url: url.windows_fallback_url,
+            linux_fallback_url: url.linux_fallback_url,
+            routing_rules: url.routing_rules,
+            variants: url.variants,
+            utm_params: url.utm_params,
+            query_passthrough: url.query_passthrough,
+            allow_path_forwarding: url.allow_path_forwarding,
+            default_fallback_url: url.default_fallback_url,
+            webhook_url: url.webhook_url,
+            webhook_secret: url.webhook_secret,
+            og_title: url.og_title,
+            og_description: url.og_description,
+            og_image_url: url.og_image_url,
+            is_active: url.is_active,
+            redirect_mode: url.redirect_mode,
+            in_app_browser_mode: url.in_app_browser_mode,
+            expires_at: url.expires_at,
+            max_clicks: url.max_clicks,
+            click_count: url.click_count,
+            alias: url.alias,
+            has_password: url.password_hash.is_some(),
+            created_at: url.created_at,
+            updated_at: url.updated_at,
+            deleted_at: url.deleted_at,
+        }
+    }
+}
+
+/// Query parameters for listing short URLs.
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ListUrlsQuery {
+    /// Last ID of the previous page (`next_cursor` of the previous response)
+    #[serde(default)]
+    pub cursor: Option<i64>,
+
+    /// Page size (1-100, default 20)
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    #[serde(default)]
+    pub limit: Option<i64>,
+
+    /// Status filter (live, active, inactive, deleted, all)
+    #[serde(default)]
+    pub status: UrlStatusFilter,
+
+    /// Only URLs created at or after this time (RFC 3339)
+    #[serde(default)]
+    pub created_from: Option<DateTime<Utc>>,
+
+    /// Only URLs created before this time (RFC 3339)
+    #[serde(default)]
+    pub created_to: Option<DateTime<Utc>>,
+
+    /// Case-insensitive substring match on the default fallback URL or OG title
+    #[validate(length(max = 200, message = "q must be at most 200 characters"))]
+    #[serde(default)]
+    pub q: Option<String>,
+}
+
+impl ListUrlsQuery {
+    /// Default page size.
+    pub const DEFAULT_LIMIT: i64 = 20;
+}
+
+/// Response for listing short URLs.
+#[derive(Debug, Serialize)]
+pub struct ListUrlsResponse {
+    pub items: Vec<UrlResponse>,
+    /// Cursor for the next page, absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<i64>,
+}
+
+/// Query parameters for URL click statistics.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlStatsQuery {
+    /// Bucket granularity (hour, day)
+    #[serde(default)]
+    pub bucket: StatsBucket,
+
+    /// Start of the range, inclusive (RFC 3339)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// End of the range, exclusive (RFC 3339, default now)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl UrlStatsQuery {
+    /// Maximum number of buckets a single request may span.
+    pub const MAX_BUCKETS: i32 = 1000;
+
+    /// Number of buckets covered when `from` is omitted.
+    const DEFAULT_BUCKETS: i32 = 30;
+
+    /// Resolves the requested range against `now`.
+    ///
+    /// Defaults to the last 30 buckets ending at `to`.
+    pub fn resolve_range(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<(DateTime<Utc>, DateTime<Utc>), AppError> {
+        let to = self.to.unwrap_or(now);
+        let from = self
+            .from
+            .unwrap_or_else(|| to - self.bucket.duration() * Self::DEFAULT_BUCKETS);
+
+        if from >= to {
+            return Err(AppError::Validation(
+                "from must be earlier than to".to_string(),
+            ));
+        }
+        if to - from > self.bucket.duration() * Self::MAX_BUCKETS {
+            return Err(AppError::Validation(format!(
+                "range must not exceed {} {} buckets",
+                Self::MAX_BUCKETS,
+                self.bucket.as_str()
+            )));
+        }
+
+        Ok((from, to))
+    }
+}
+
+/// Query parameters for QR code rendering.
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCodeQuery {
+    /// Image format (png, svg)
+    #[serde(default)]
+    pub format: QrFormat,
+
+    /// Image width and height in pixels (64-2048, default 256)
+    #[validate(range(min = 64, max = 2048, message = "size must be between 64 and 2048"))]
+    #[serde(default)]
+    pub size: Option<u32>,
+
+    /// Error-correction level (L, M, Q, H)
+    #[serde(default)]
+    pub error_correction: QrErrorCorrection,
+
+    /// Quiet zone in modules (0-16, default 4)
+    #[validate(range(max = 16, message = "margin must be at most 16"))]
+    #[serde(default)]
+    pub margin: Option<u32>,
+}
+
+impl QrCodeQuery {
+    /// Default image size in pixels.
+    pub const DEFAULT_SIZE: u32 = 256;
+
+    /// Default quiet zone in modules, as recommended by the QR specification.
+    pub const DEFAULT_MARGIN: u32 = 4;
+
+    /// Resolves the rendering options, applying defaults.
+    pub fn options(&self) -> QrOptions {
+        QrOptions {
+            format: self.format,
+            size: self.size.unwrap_or(Self::DEFAULT_SIZE),
+            error_correction: self.error_correction,
+            margin: self.margin.unwrap_or(Self::DEFAULT_MARGIN),
+        }
+    }
+}
+
+/// Response for URL click statistics.
+#[derive(Debug, Serialize)]
+pub struct UrlStatsResponse {
+    pub short_key: String,
+    /// All-time click count
+    pub total_clicks: i64,
+    /// Click count within the range
+    pub range_clicks: i64,
+    pub bucket: StatsBucket,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Click counts within the range by platform
+    pub platforms: Vec<PlatformClickCount>,
+    /// Click counts within the range by A/B variant
+    pub variants: Vec<VariantClickCount>,
+    /// Non-empty buckets within the range, oldest first
+    pub buckets: Vec<ClickBucketCount>,
+}
+
+impl UrlStatsResponse {
+    /// Builds the response from aggregated statistics.
+    pub fn new(
+        short_key: String,
+        bucket: StatsBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        stats: ClickStats,
+    ) -> Self {
+        Self {
+            short_key,
+            total_clicks: stats.total_clicks,
+            range_clicks: stats.range_clicks,
+            bucket,
+            from,
+            to,
+            platforms: stats.platforms,
+            variants: stats.variants,
+            buckets: stats.buckets,
+        }
+    }
+}
+
+/// Deferred deep link match request sent by a freshly installed app.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeferredMatchRequest {
+    /// Platform of the app (`ios` or `android`)
+    pub platform: Platform,
+    /// `User-Agent` of the device's browser or webview, compared with the
+    /// browser family of the click (defaults to the request's `User-Agent`)
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+/// Response for a matched deferred deep link.
+#[derive(Debug, Serialize)]
+pub struct DeferredMatchResponse {
+    /// Short key or alias the user opened before installing the app
+    pub short_key: String,
+    pub short_url: String,
+    /// Deep link of the link for the app's platform, if still set
+    pub deep_link: Option<String>,
+    pub clicked_at: DateTime<Utc>,
+    /// Match confidence between 0 and 1
+    pub confidence: f64,
+}
+
+/// Abuse report submitted by a visitor.
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportUrlRequest {
+    /// Why the link is reported (phishing, malware, spam, other)
+    pub reason: ReportReason,
+
+    /// Description of the problem (optional, max 1000 characters)
+    #[validate(length(max = 1000, message = "details must be at most 1000 characters"))]
+    #[serde(default)]
+    pub details: Option<String>,
+
+    /// Contact address of the reporter (optional)
+    #[validate(
+        email(message = "Invalid email address"),
+        length(max = 255, message = "email must be at most 255 characters")
+    )]
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Response for a received abuse report.
+#[derive(Debug, Serialize)]
+pub struct ReportUrlResponse {
+    pub message: String,
+}
+
+impl ReportUrlResponse {
+    /// Response for a report queued for review.
+    pub fn received() -> Self {
+        Self {
+            message: "Report received".to_string(),
+        }
+    }
+}
+
+/// Query parameters for listing abuse reports.
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ListReportsQuery {
+    /// Last ID of the previous page (`next_cursor` of the previous response)
+    #[serde(default)]
+    pub cursor: Option<i64>,
+
+    /// Page size (1-100, default 20)
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    #[serde(default)]
+    pub limit: Option<i64>,
+
+    /// Status filter (open, resolved; all when omitted)
+    #[serde(default)]
+    pub status: Option<ReportStatus>,
+}
+
+impl ListReportsQuery {
+    /// Default page size.
+    pub const DEFAULT_LIMIT: i64 = 20;
+}
+
+/// Response describing a single abuse report.
+#[derive(Debug, Serialize)]
+pub struct ReportResponse {
+    pub id: i64,
+    /// Short key of the reported link
+    pub short_key: String,
+    pub alias: Option<String>,
+    pub default_fallback_url: String,
+    /// Whether the reported link still redirects
+    pub is_active: bool,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub reporter_email: Option<String>,
+    /// Reporter IP truncated to its network prefix
+    pub ip_prefix: Option<String>,
+    pub status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl From<Report> for ReportResponse {
+    fn from(report: Report) -> Self {
+        #[allow(clippy::cast_sign_loss)]
+        let short_key = merge_short_key(&report.random_key, report.url_id as u64);
+
+        Self {
+            id: report.id,
+            short_key,
+            alias: report.alias,
+            default_fallback_url: report.default_fallback_url,
+            is_active: report.is_active,
+            reason: report.reason,
+            details: report.details,
+            reporter_email: report.reporter_email,
+            ip_prefix: report.ip_prefix,
+            status: report.status,
+            created_at: report.created_at,
+            resolved_at: report.resolved_at,
+        }
+    }
+}
+
+/// Response for listing abuse reports.
+#[derive(Debug, Serialize)]
+pub struct ListReportsResponse {
+    pub items: Vec<ReportResponse>,
+    /// Cursor for the next page, absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<i64>,
+}
+
+/// Response for a link disabled by an admin.
+#[derive(Debug, Serialize)]
+pub struct DisableUrlResponse {
+    pub short_key: String,
+    pub is_active: bool,
+    /// Number of open reports of the link resolved by the takedown
+    pub resolved_reports: u64,
+}
+
+/// Response describing a link of any owner, for admins.
+#[derive(Debug, Serialize)]
+pub struct AdminUrlResponse {
+    /// Subject of the token that created the link
+    pub owner: String,
+    #[serde(flatten)]
+    pub url: UrlResponse,
+}
+
+impl From<Url> for AdminUrlResponse {
+    fn from(url: Url) -> Self {
+        Self {
+            owner: url.owner.clone(),
+            url: UrlResponse::from(url),
+        }
+    }
+}
+
+/// Response for listing the links of all owners.
+#[derive(Debug, Serialize)]
+pub struct ListAdminUrlsResponse {
+    pub items: Vec<AdminUrlResponse>,
+    /// Cursor for the next page, absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<i64>,
+}
+
+/// Request body for purging old data.
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeRequest {
+    /// Age in days of the data to purge (1-3650, default 30)
+    #[validate(range(
+        min = 1,
+        max = 3650,
+        message = "olderThanDays must be between 1 and 3650"
+    ))]
+    #[serde(default)]
+    pub older_than_days: Option<i64>,
+}
+
+impl PurgeRequest {
+    /// Default age of purged data in days.
+    pub const DEFAULT_OLDER_THAN_DAYS: i64 = 30;
+}
+
+/// Response for a purge run.
+#[derive(Debug, Serialize)]
+pub struct PurgeResponse {
+    /// Soft-deleted links permanently deleted (with their clicks and reports)
+    pub purged_urls: u64,
+    /// Delivered and dead-lettered webhook deliveries deleted
+    pub purged_webhook_deliveries: u64,
+}
+
+/// Request body for moving every link of one owner to another.
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ReassignOwnerRequest {
+    /// Current owner (JWT `sub`), e.g. `guest` for links created before owners existed
+    #[validate(length(min = 1, max = 255, message = "from must be 1-255 characters"))]
+    pub from: String,
+
+    /// New owner (JWT `sub`)
+    #[validate(length(min = 1, max = 255, message = "to must be 1-255 characters"))]
+    pub to: String,
+}
+
+/// Response for an owner reassignment.
+#[derive(Debug, Serialize)]
+pub struct ReassignOwnerResponse {
+    /// Links moved to the new owner
+    pub reassigned_urls: u64,
+    /// Links left with the old owner because the new owner already has a
+    /// live link with the same destinations
+    pub remaining_urls: i64,
+}
+
+/// Aliases that would shadow application routes.
+///
+/// `batch` would shadow `/v1/urls/batch` in the management API, and
+/// `report` the abuse report endpoint.
+pub const RESERVED_ALIASES: &[&str] = &[
+    "health", "ready", "v1", "api", "admin", "static", "batch", "report",
+];
+
+/// Minimum length of a vanity alias.
+pub const ALIAS_MIN_LEN: usize = 3;
+
+/// Maximum length of a vanity alias.
+pub const ALIAS_MAX_LEN: usize = 64;
+
+/// Validates a vanity alias and returns its normalized (lowercase) form.
+///
+/// # Validation Rules
+///
+/// - Must be 3-64 characters long
+/// - Must contain only English letters, numbers, `-` and `_`
+/// - Must start and end with a letter or number
+/// - Must not be a reserved word (e.g. `health`, `ready`, `v1`)
+pub fn normalize_alias(alias: &str) -> Result<String, AppError> {
+    let alias = alias.to_ascii_lowercase();
+
+    if !(ALIAS_MIN_LEN..=ALIAS_MAX_LEN).contains(&alias.len()) {
+        return Err(AppError::Validation(format!(
+            "alias must be between {ALIAS_MIN_LEN} and {ALIAS_MAX_LEN} characters long"
+        )));
+    }
+
+    if !alias
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(AppError::Validation(
+            "alias must contain only English letters, numbers, '-' and '_'".to_string(),
+        ));
+    }
+
+    let is_alphanumeric_edge = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    if !is_alphanumeric_edge(alias.chars().next()) || !is_alphanumeric_edge(alias.chars().last()) {
+        return Err(AppError::Validation(
+            "alias must start and end with a letter or number".to_string(),
+        ));
+    }
+
+    if RESERVED_ALIASES.contains(&alias.as_str()) {
+        return Err(AppError::Validation(format!("alias '{alias}' is reserved")));
+    }
+
+    Ok(alias)
+}
+
+/// Validates the Android app link fields of a URL.
+///
+/// # Validation Rules
+///
+/// - `android_package` and `android_scheme` must be set together
+/// - `android_path` requires them
+/// - The package must be a Java package name with at least two segments
+///   (`com.example.app`)
+/// - The scheme must be an RFC 3986 scheme (a letter, then letters,
+///   numbers, `+`, `-` or `.`)
+/// - The path must not contain whitespace or `#`, which ends the intent URI
+pub fn validate_android_app_link(
+    package: Option<&str>,
+    scheme: Option<&str>,
+    path: Option<&str>,
+) -> Result<(), AppError> {
+    let (Some(package), Some(scheme)) = (package, scheme) else {
+        if package.is_some() || scheme.is_some() || path.is_some() {
+            return Err(AppError::Validation(
+                "androidPackage and androidScheme must be set together".to_string(),
+            ));
+        }
+        return Ok(());
+    };
+
+    let is_package_segment = |segment: &str| {
+        segment.starts_with(|c: char| c.is_ascii_alphabetic())
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    if package.split('.').count() < 2 || !package.split('.').all(is_package_segment) {
+        return Err(AppError::Validation(
+            "androidPackage must be a package name such as 'com.example.app'".to_string(),
+        ));
+    }
+
+    if !scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        || !scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    {
+        return Err(AppError::Validation(
+            "androidScheme must be a URI scheme such as 'myapp'".to_string(),
+        ));
+    }
+
+    if path.is_some_and(|path| path.chars().any(|c| c == '#' || c.is_whitespace())) {
+        return Err(AppError::Validation(
+            "androidPath must not contain whitespace or '#'".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the A/B variants of a request (see [`UrlVariants::check`]).
+fn validate_variants(variants: &UrlVariants) -> Result<(), ValidationError> {
+    variants
+        .check()
+        .map_err(|message| ValidationError::new("variants").with_message(message.into()))
+}
+
+/// Validates a short URL key.
+///
+/// # Validation Rules
+///
+/// - Must be at least 5 characters long (2 prefix + 1 ID char + 2 suffix)
+/// - Must contain only alphanumeric characters (a-z, A-Z, 0-9)
+pub fn validate_short_key(short_key: &str) -> Result<(), AppError> {
+    use crate::utils::short_key::SHORT_KEY_MIN_LEN;
+
+    if short_key.len() < SHORT_KEY_MIN_LEN {
+        return Err(AppError::BadRequest(format!(
+            "short_key must be at least {SHORT_KEY_MIN_LEN} characters long"
+        )));
+    }
+
+    if !short_key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(AppError::BadRequest(
+            "short_key must contain only English letters and numbers".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ValidationErrorExt;
+    use validator::Validate;
+
+    // ============ validate_short_key 테스트 ============
+
+    #[test]
+    fn test_validate_short_key_valid_minimum() {
+        // 최소 5자: prefix(2) + id(1) + suffix(2)
+        assert!(validate_short_key("ab1xy").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_valid_mixed() {
+        assert!(validate_short_key("ABC123").is_ok());
+        assert!(validate_short_key("a1B2c3D4").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_valid_numbers_only() {
+        assert!(validate_short_key("12345").is_ok());
+        assert!(validate_short_key("00000").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_valid_long() {
+        assert!(validate_short_key("abcdefghijklmnopqrstuvwxyz").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_exactly_five_chars() {
+        assert!(validate_short_key("ab1cd").is_ok());
+        assert!(validate_short_key("12345").is_ok());
+        assert!(validate_short_key("a1B2c").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_too_short_empty() {
+        let result = validate_short_key("");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_too_short_one() {
+        let result = validate_short_key("a");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_too_short_four() {
+        let result = validate_short_key("abcd");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_hyphen() {
+        let result = validate_short_key("abc-def");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_underscore() {
+        let result = validate_short_key("abc_def");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_space() {
+        let result = validate_short_key("abc def");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_special_chars() {
+        let invalid_chars = ["abc!", "ab@c", "a#bc", "ab$c", "ab%c", "ab^c"];
+        for key in invalid_chars {
+            let result = validate_short_key(key);
+            assert!(
+                matches!(result, Err(AppError::BadRequest(_))),
+                "Expected error for: {key}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_unicode() {
+        let result = validate_short_key("abc한글");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_emoji() {
+        let result = validate_short_key("abc🚀");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    // ============ CreateShortUrlResponse 테스트 ============
+
+    #[test]
+    fn test_create_short_url_response_created() {
+        let response = CreateShortUrlResponse::created("AbC123".to_string());
+        assert_eq!(response.message, "URL created successfully");
+        assert_eq!(response.short_key, Some("AbC123".to_string()));
+    }
+
+    #[test]
+    fn test_create_short_url_response_already_exists_with_key() {
+        let response = CreateShortUrlResponse::already_exists_with_key("existing123".to_string());
+        assert_eq!(response.message, "URL already exists");
+        assert_eq!(response.short_key, Some("existing123".to_string()));
+    }
+
+    #[test]
+    fn test_create_short_url_response_serialize_created() {
+        let response = CreateShortUrlResponse::created("test123".to_string());
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("test123"));
+        assert!(json.contains("URL created successfully"));
+    }
+
+    #[test]
+    fn test_create_short_url_response_serialize_already_exists() {
+        let response = CreateShortUrlResponse::already_exists_with_key("abc123".to_string());
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("URL already exists"));
+        // Now includes the existing short_key
+        assert!(json.contains("short_key"));
+        assert!(json.contains("abc123"));
+    }
+
+    // ============ CreateShortUrlRequest 테스트 ============
+
+    #[test]
+    fn test_create_short_url_request_deserialize_minimal() {
+        let json = r#"{"defaultFallbackUrl": "https://example.com"}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.default_fallback_url,
+            Some("https://example.com".to_string())
+        );
+        assert!(req.ios_deep_link.is_none());
+        assert!(req.android_deep_link.is_none());
+    }
+
+    #[test]
+    fn test_create_short_url_request_deserialize_full() {
+        let json = r#"{
+            "defaultFallbackUrl": "https://example.com",
+            "iosDeepLink": "app://ios",
+            "iosFallbackUrl": "https://apps.apple.com",
+            "androidDeepLink": "app://android",
+            "androidFallbackUrl": "https://play.google.com",
+            "webhookUrl": "https://webhook.example.com",
+            "ogTitle": "Test Title",
+            "ogDescription": "Test Description",
+            "ogImageUrl": "https://example.com/image.png"
+        }"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.default_fallback_url,
+            Some("https://example.com".to_string())
+        );
+        assert_eq!(req.ios_deep_link, Some("app://ios".to_string()));
+        assert_eq!(req.og_title, Some("Test Title".to_string()));
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_valid() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_missing_default_url() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: None,
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_invalid_url_format() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("not-a-valid-url".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_og_title_too_long() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: Some("a".repeat(256)), // 255자 초과
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_og_title_max_length() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: Some("a".repeat(255)), // 정확히 255자
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_og_description_too_long() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: Some("a".repeat(501)), // 500자 초과
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_with_all_optional_urls() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: Some("https://ios.example.com".to_string()),
+            ios_fallback_url: Some("https://apps.apple.com".to_string()),
+            android_deep_link: Some("https://android.example.com".to_string()),
+            android_fallback_url: Some("https://play.google.com".to_string()),
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: Some("https://webhook.example.com".to_string()),
+            og_title: Some("Title".to_string()),
+            og_description: Some("Description".to_string()),
+            og_image_url: Some("https://example.com/image.png".to_string()),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_short_url_request_clone() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: Some("https://ios.example.com".to_string()),
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        let cloned = req.clone();
+        assert_eq!(req.default_fallback_url, cloned.default_fallback_url);
+        assert_eq!(req.ios_deep_link, cloned.ios_deep_link);
+    }
+
+    #[test]
+    fn test_create_short_url_request_debug() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        let debug_str = format!("{req:?}");
+        assert!(debug_str.contains("CreateShortUrlRequest"));
+    }
+
+    // ============ 추가 validate_short_key 테스트 ============
+
+    #[test]
+    fn test_validate_short_key_max_length() {
+        // 매우 긴 short_key도 유효 (Base62로 인코딩된 큰 숫자)
+        let long_key = "a".repeat(100);
+        assert!(validate_short_key(&long_key).is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_all_digits() {
+        assert!(validate_short_key("123456789").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_all_lowercase() {
+        assert!(validate_short_key("abcdefghij").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_all_uppercase() {
+        assert!(validate_short_key("ABCDEFGHIJ").is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_base62_chars() {
+        // Base62에 사용되는 모든 문자 테스트
+        assert!(validate_short_key(
+            "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_dot() {
+        let result = validate_short_key("abc.def");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_slash() {
+        let result = validate_short_key("abc/def");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_invalid_backslash() {
+        let result = validate_short_key("abc\\def");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_short_key_error_message_too_short() {
+        let result = validate_short_key("abcd");
+        match result {
+            Err(AppError::BadRequest(msg)) => {
+                assert!(msg.contains("at least 5 characters"));
+            }
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_validate_short_key_error_message_invalid_chars() {
+        let result = validate_short_key("abc-def");
+        match result {
+            Err(AppError::BadRequest(msg)) => {
+                assert!(msg.contains("English letters and numbers"));
+            }
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    // ============ 추가 CreateShortUrlRequest 유효성 검사 테스트 ============
+
+    #[test]
+    fn test_create_short_url_request_validate_invalid_ios_deep_link() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: Some("not-a-url".to_string()),
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_invalid_android_deep_link() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: Some("invalid-url".to_string()),
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_invalid_webhook_url() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: Some("not-a-webhook-url".to_string()),
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_invalid_og_image_url() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: Some("not-an-image-url".to_string()),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_og_description_max_length() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: Some("a".repeat(500)), // 정확히 500자
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_empty_strings_ok() {
+        // 빈 문자열은 유효성 검사에서 URL 형식으로 체크되지 않음
+        let req = CreateShortUrlRequest {
+            ios_deep_link: Some(String::new()),
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: None,
+            og_title: None,
+            og_description: None,
+            og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        // 빈 문자열은 URL 형식이 아니므로 실패할 수 있음
+        // validator의 url 검사는 빈 문자열을 어떻게 처리하는지에 따라 다름
+        let _ = req.validate(); // 결과에 관계없이 패닉하지 않으면 OK
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_https_urls() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: Some("https://ios.example.com".to_string()),
+            ios_fallback_url: Some("https://apps.apple.com/app".to_string()),
+            android_deep_link: Some("https://android.example.com".to_string()),
+            android_fallback_url: Some("https://play.google.com/app".to_string()),
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("https://example.com".to_string()),
+            webhook_url: Some("https://webhook.example.com/hook".to_string()),
+            og_title: Some("Title".to_string()),
+            og_description: Some("Description".to_string()),
+            og_image_url: Some("https://example.com/image.png".to_string()),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_short_url_request_validate_http_urls() {
+        let req = CreateShortUrlRequest {
+            ios_deep_link: None,
+            ios_fallback_url: None,
+            android_deep_link: None,
+            android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: Some("http://example.com".to_string()),
             webhook_url: None,
             og_title: None,
             og_description: None,
             og_image_url: None,
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_short_url_request_variants() {
+        let parse = |variants: &str| {
+            let json = format!(
+                r#"{{"defaultFallbackUrl": "https://example.com", "variants": {variants}}}"#
+            );
+            serde_json::from_str::<CreateShortUrlRequest>(&json).unwrap()
         };
+
+        let req = parse(
+            r#"[
+                {"name": "a", "destination": "https://example.com/a", "weight": 70},
+                {"name": "b", "destination": "https://example.com/b", "weight": 30}
+            ]"#,
+        );
+        assert!(req.validate().is_ok());
+        assert_eq!(req.variants.0[1].weight, 30);
+
+        let req = parse(
+            r#"[
+                {"name": "a", "destination": "https://example.com/a", "weight": 70},
+                {"name": "b", "destination": "https://example.com/b", "weight": 20}
+            ]"#,
+        );
+        let error = req.validate().unwrap_err().to_validation_error();
+        assert!(
+            matches!(error, AppError::Validation(message) if message == "Variant weights must sum to 100")
+        );
+    }
+
+    // ============ CreateShortUrlResponse 추가 테스트 ============
+
+    #[test]
+    fn test_create_short_url_response_debug() {
+        let response = CreateShortUrlResponse::created("test123".to_string());
+        let debug_str = format!("{response:?}");
+        assert!(debug_str.contains("CreateShortUrlResponse"));
+    }
+
+    #[test]
+    fn test_create_short_url_response_empty_short_key() {
+        let response = CreateShortUrlResponse::created(String::new());
+        assert_eq!(response.short_key, Some(String::new()));
+    }
+
+    #[test]
+    fn test_create_short_url_response_long_short_key() {
+        let long_key = "a".repeat(100);
+        let response = CreateShortUrlResponse::created(long_key.clone());
+        assert_eq!(response.short_key, Some(long_key));
+    }
+
+    #[test]
+    fn test_create_short_url_response_serialize_json_structure() {
+        let response = CreateShortUrlResponse::created("test123".to_string());
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert!(json.is_object());
+        assert!(json.get("message").is_some());
+        assert!(json.get("short_key").is_some());
+    }
+
+    // ============ BatchCreateShortUrlResponse 테스트 ============
+
+    #[test]
+    fn test_batch_create_response_counts_and_sorts() {
+        let response = BatchCreateShortUrlResponse::new(vec![
+            BatchCreateItemResult::created(2, "Ab3D7Xy".to_string(), None),
+            BatchCreateItemResult::error(0, "Invalid default fallback URL".to_string()),
+            BatchCreateItemResult::existing(1, "Xy9Z2Ab".to_string(), Some("promo".to_string())),
+        ]);
+
+        assert_eq!(response.created, 1);
+        assert_eq!(response.existing, 1);
+        assert_eq!(response.failed, 1);
+        let indexes: Vec<usize> = response.results.iter().map(|r| r.index).collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_batch_create_item_result_serialize() {
+        let created = serde_json::to_value(BatchCreateItemResult::created(
+            0,
+            "Ab3D7Xy".to_string(),
+            None,
+        ))
+        .unwrap();
+        assert_eq!(created["status"], "created");
+        assert_eq!(created["short_key"], "Ab3D7Xy");
+        assert!(created.get("alias").is_none());
+        assert!(created.get("error").is_none());
+
+        let error = serde_json::to_value(BatchCreateItemResult::error(
+            3,
+            "Alias is already in use".to_string(),
+        ))
+        .unwrap();
+        assert_eq!(error["index"], 3);
+        assert_eq!(error["status"], "error");
+        assert_eq!(error["error"], "Alias is already in use");
+        assert!(error.get("short_key").is_none());
+    }
+
+    // ============ UpdateShortUrlRequest 테스트 ============
+
+    #[test]
+    fn test_update_request_absent_fields_are_none() {
+        let req: UpdateShortUrlRequest = serde_json::from_str("{}").unwrap();
+        assert!(req.ios_deep_link.is_none());
+        assert!(req.default_fallback_url.is_none());
+        assert!(req.is_active.is_none());
+    }
+
+    #[test]
+    fn test_update_request_null_clears_field() {
+        let json = r#"{"iosDeepLink": null, "ogTitle": "New Title"}"#;
+        let req: UpdateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.ios_deep_link, Some(None));
+        assert_eq!(req.og_title, Some(Some("New Title".to_string())));
+        assert!(req.android_deep_link.is_none());
+    }
+
+    #[test]
+    fn test_update_request_is_active() {
+        let json = r#"{"isActive": false}"#;
+        let req: UpdateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.is_active, Some(false));
         assert!(req.validate().is_ok());
     }
 
     #[test]
-    fn test_create_short_url_request_validate_missing_default_url() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: None,
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
+    fn test_update_request_validate_invalid_url() {
+        let req = UpdateShortUrlRequest {
+            ios_fallback_url: Some(Some("not-a-url".to_string())),
+            password: None,
+            ..Default::default()
         };
         assert!(req.validate().is_err());
     }
 
     #[test]
-    fn test_create_short_url_request_validate_invalid_url_format() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("not-a-valid-url".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
-        };
+    fn test_update_request_validates_variants() {
+        let json =
+            r#"{"variants": [{"name": "a", "destination": "https://a.com", "weight": 100}]}"#;
+        let req: UpdateShortUrlRequest = serde_json::from_str(json).unwrap();
         assert!(req.validate().is_err());
+
+        // 빈 목록은 A/B 분할 해제
+        let req: UpdateShortUrlRequest = serde_json::from_str(r#"{"variants": []}"#).unwrap();
+        assert!(req.validate().is_ok());
+        assert_eq!(req.variants, Some(UrlVariants::default()));
     }
 
     #[test]
-    fn test_create_short_url_request_validate_og_title_too_long() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: Some("a".repeat(256)), // 255자 초과
-            og_description: None,
-            og_image_url: None,
-        };
-        assert!(req.validate().is_err());
+    fn test_utm_params_and_query_passthrough_requests() {
+        let req: CreateShortUrlRequest = serde_json::from_str(
+            r#"{
+                "defaultFallbackUrl": "https://example.com",
+                "utmParams": {"source": "newsletter", "campaign": "spring"},
+                "queryPassthrough": true
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(req.utm_params.source.as_deref(), Some("newsletter"));
+        assert!(req.utm_params.medium.is_none());
+        assert!(req.query_passthrough);
+
+        let req: CreateShortUrlRequest =
+            serde_json::from_str(r#"{"defaultFallbackUrl": "https://example.com"}"#).unwrap();
+        assert!(req.utm_params.is_empty());
+        assert!(!req.query_passthrough);
+
+        // 빈 객체는 UTM 파라미터 삭제
+        let req: UpdateShortUrlRequest = serde_json::from_str(r#"{"utmParams": {}}"#).unwrap();
+        assert_eq!(req.utm_params, Some(UtmParams::default()));
+        assert!(req.query_passthrough.is_none());
     }
 
     #[test]
-    fn test_create_short_url_request_validate_og_title_max_length() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: Some("a".repeat(255)), // 정확히 255자
-            og_description: None,
-            og_image_url: None,
+    fn test_allow_path_forwarding_requests() {
+        let req: CreateShortUrlRequest = serde_json::from_str(
+            r#"{"defaultFallbackUrl": "https://example.com", "allowPathForwarding": true}"#,
+        )
+        .unwrap();
+        assert!(req.allow_path_forwarding);
+
+        let req: UpdateShortUrlRequest =
+            serde_json::from_str(r#"{"allowPathForwarding": false}"#).unwrap();
+        assert_eq!(req.allow_path_forwarding, Some(false));
+        let req: UpdateShortUrlRequest = serde_json::from_str("{}").unwrap();
+        assert!(req.allow_path_forwarding.is_none());
+    }
+
+    #[test]
+    fn test_update_request_validate_cleared_field_ok() {
+        let req = UpdateShortUrlRequest {
+            ios_fallback_url: Some(None),
+            webhook_url: Some(None),
+            password: None,
+            ..Default::default()
         };
         assert!(req.validate().is_ok());
     }
 
     #[test]
-    fn test_create_short_url_request_validate_og_description_too_long() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: Some("a".repeat(501)), // 500자 초과
-            og_image_url: None,
+    fn test_update_request_validate_og_title_too_long() {
+        let req = UpdateShortUrlRequest {
+            og_title: Some(Some("a".repeat(256))),
+            password: None,
+            ..Default::default()
         };
         assert!(req.validate().is_err());
     }
 
     #[test]
-    fn test_create_short_url_request_validate_with_all_optional_urls() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: Some("https://ios.example.com".to_string()),
-            ios_fallback_url: Some("https://apps.apple.com".to_string()),
-            android_deep_link: Some("https://android.example.com".to_string()),
-            android_fallback_url: Some("https://play.google.com".to_string()),
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: Some("https://webhook.example.com".to_string()),
-            og_title: Some("Title".to_string()),
-            og_description: Some("Description".to_string()),
-            og_image_url: Some("https://example.com/image.png".to_string()),
-        };
+    fn test_update_request_redirect_mode() {
+        let req: UpdateShortUrlRequest =
+            serde_json::from_str(r#"{"redirectMode": "http_301"}"#).unwrap();
+        assert_eq!(req.redirect_mode, Some(RedirectMode::Http301));
+
+        let req: UpdateShortUrlRequest = serde_json::from_str("{}").unwrap();
+        assert!(req.redirect_mode.is_none());
+    }
+
+    #[test]
+    fn test_in_app_browser_mode_request_fields() {
+        let json = r#"{"defaultFallbackUrl": "https://example.com", "inAppBrowserMode": "prompt"}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.in_app_browser_mode, InAppBrowserMode::Prompt);
+
+        let json = r#"{"defaultFallbackUrl": "https://example.com"}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.in_app_browser_mode, InAppBrowserMode::Escape);
+
+        let req: UpdateShortUrlRequest =
+            serde_json::from_str(r#"{"inAppBrowserMode": "off"}"#).unwrap();
+        assert_eq!(req.in_app_browser_mode, Some(InAppBrowserMode::Off));
+    }
+
+    #[test]
+    fn test_create_request_redirect_mode() {
+        let json = r#"{"defaultFallbackUrl": "https://example.com", "redirectMode": "http_307"}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.redirect_mode, RedirectMode::Http307);
+
+        let json = r#"{"defaultFallbackUrl": "https://example.com"}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.redirect_mode, RedirectMode::Interstitial);
+
+        let json = r#"{"defaultFallbackUrl": "https://example.com", "redirectMode": "js"}"#;
+        assert!(serde_json::from_str::<CreateShortUrlRequest>(json).is_err());
+    }
+
+    #[test]
+    fn test_update_request_expiration_fields() {
+        let req: UpdateShortUrlRequest =
+            serde_json::from_str(r#"{"expiresAt": null, "maxClicks": 100}"#).unwrap();
+        assert_eq!(req.expires_at, Some(None));
+        assert_eq!(req.max_clicks, Some(Some(100)));
+        assert!(req.validate().is_ok());
+
+        let req: UpdateShortUrlRequest = serde_json::from_str(r#"{"maxClicks": 0}"#).unwrap();
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_request_expiration_fields() {
+        let json = r#"{
+            "defaultFallbackUrl": "https://example.com",
+            "expiresAt": "2030-01-01T00:00:00Z",
+            "maxClicks": 500
+        }"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert!(req.expires_at.is_some());
+        assert_eq!(req.max_clicks, Some(500));
         assert!(req.validate().is_ok());
+
+        let json = r#"{"defaultFallbackUrl": "https://example.com", "maxClicks": 0}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert!(req.validate().is_err());
     }
 
+    // ============ UrlResponse 테스트 ============
+
     #[test]
-    fn test_create_short_url_request_clone() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: Some("https://ios.example.com".to_string()),
+    fn test_url_response_from_url() {
+        let now = Utc::now();
+        let url = Url {
+            id: 12345,
+            random_key: "PrSf".to_string(),
+            ios_deep_link: Some("app://ios".to_string()),
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
+            default_fallback_url: "https://example.com".to_string(),
+            hashed_value: "hash".to_string(),
             webhook_url: None,
-            og_title: None,
+            og_title: Some("Title".to_string()),
             og_description: None,
             og_image_url: None,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
         };
-        let cloned = req.clone();
-        assert_eq!(req.default_fallback_url, cloned.default_fallback_url);
-        assert_eq!(req.ios_deep_link, cloned.ios_deep_link);
+
+        let admin_json = serde_json::to_value(AdminUrlResponse::from(url.clone())).unwrap();
+        assert_eq!(admin_json["owner"], "guest");
+        assert_eq!(admin_json["short_key"], merge_short_key("PrSf", 12345));
+
+        let response = UrlResponse::from(url);
+        assert_eq!(response.short_key, merge_short_key("PrSf", 12345));
+        assert_eq!(response.ios_deep_link, Some("app://ios".to_string()));
+        assert_eq!(response.default_fallback_url, "https://example.com");
+        assert!(response.is_active);
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("hashed_value").is_none());
+        assert!(json.get("short_key").is_some());
     }
 
+    // ============ ListUrlsQuery 테스트 ============
+
     #[test]
-    fn test_create_short_url_request_debug() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
+    fn test_list_urls_query_defaults() {
+        let query: ListUrlsQuery = serde_json::from_str("{}").unwrap();
+        assert!(query.cursor.is_none());
+        assert!(query.limit.is_none());
+        assert_eq!(query.status, UrlStatusFilter::Live);
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn test_list_urls_query_full() {
+        let json = r#"{
+            "cursor": 120,
+            "limit": 50,
+            "status": "inactive",
+            "createdFrom": "2024-12-01T00:00:00Z",
+            "createdTo": "2025-01-01T00:00:00Z",
+            "q": "example"
+        }"#;
+        let query: ListUrlsQuery = serde_json::from_str(json).unwrap();
+        assert_eq!(query.cursor, Some(120));
+        assert_eq!(query.limit, Some(50));
+        assert_eq!(query.status, UrlStatusFilter::Inactive);
+        assert!(query.created_from.unwrap() < query.created_to.unwrap());
+        assert_eq!(query.q, Some("example".to_string()));
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn test_list_urls_query_limit_out_of_range() {
+        let zero = ListUrlsQuery {
+            limit: Some(0),
+            ..Default::default()
+        };
+        assert!(zero.validate().is_err());
+
+        let too_many = ListUrlsQuery {
+            limit: Some(101),
+            ..Default::default()
+        };
+        assert!(too_many.validate().is_err());
+    }
+
+    #[test]
+    fn test_list_urls_query_search_too_long() {
+        let query = ListUrlsQuery {
+            q: Some("a".repeat(201)),
+            ..Default::default()
+        };
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_list_urls_response_omits_cursor_on_last_page() {
+        let response = ListUrlsResponse {
+            items: vec![],
+            next_cursor: None,
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("items").unwrap().is_array());
+        assert!(json.get("next_cursor").is_none());
+    }
+
+    // ============ Deserialization 엣지 케이스 ============
+
+    #[test]
+    fn test_create_short_url_request_deserialize_empty_json() {
+        let json = "{}";
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert!(req.default_fallback_url.is_none());
+        assert!(req.ios_deep_link.is_none());
+    }
+
+    #[test]
+    fn test_create_short_url_request_deserialize_null_values() {
+        let json = r#"{"defaultFallbackUrl": null, "iosDeepLink": null}"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert!(req.default_fallback_url.is_none());
+        assert!(req.ios_deep_link.is_none());
+    }
+
+    #[test]
+    fn test_create_short_url_request_deserialize_extra_fields() {
+        // 알 수 없는 필드가 있어도 무시
+        let json = r#"{
+            "defaultFallbackUrl": "https://example.com",
+            "unknownField": "should be ignored"
+        }"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.default_fallback_url,
+            Some("https://example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_create_short_url_request_deserialize_unicode() {
+        let json = r#"{
+            "defaultFallbackUrl": "https://example.com",
+            "ogTitle": "한글 제목 🚀",
+            "ogDescription": "日本語説明"
+        }"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.og_title, Some("한글 제목 🚀".to_string()));
+        assert_eq!(req.og_description, Some("日本語説明".to_string()));
+    }
+
+    // ============ UrlStatsQuery 테스트 ============
+
+    fn stats_now() -> DateTime<Utc> {
+        "2025-01-31T12:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_url_stats_query_defaults() {
+        let query: UrlStatsQuery = serde_json::from_str("{}").unwrap();
+        assert_eq!(query.bucket, StatsBucket::Day);
+
+        let (from, to) = query.resolve_range(stats_now()).unwrap();
+        assert_eq!(to, stats_now());
+        assert_eq!(to - from, chrono::Duration::days(30));
+    }
+
+    #[test]
+    fn test_url_stats_query_hour_default_range() {
+        let query: UrlStatsQuery = serde_json::from_str(r#"{"bucket": "hour"}"#).unwrap();
+
+        let (from, to) = query.resolve_range(stats_now()).unwrap();
+        assert_eq!(to - from, chrono::Duration::hours(30));
+    }
+
+    #[test]
+    fn test_url_stats_query_explicit_range() {
+        let json = r#"{"from": "2025-01-01T00:00:00Z", "to": "2025-01-08T00:00:00Z"}"#;
+        let query: UrlStatsQuery = serde_json::from_str(json).unwrap();
+
+        let (from, to) = query.resolve_range(stats_now()).unwrap();
+        assert_eq!(from, query.from.unwrap());
+        assert_eq!(to, query.to.unwrap());
+    }
+
+    #[test]
+    fn test_url_stats_query_inverted_range() {
+        let json = r#"{"from": "2025-01-08T00:00:00Z", "to": "2025-01-01T00:00:00Z"}"#;
+        let query: UrlStatsQuery = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(
+            query.resolve_range(stats_now()),
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_url_stats_query_range_too_large() {
+        let query = UrlStatsQuery {
+            bucket: StatsBucket::Hour,
+            from: Some("2024-01-01T00:00:00Z".parse().unwrap()),
+            to: None,
+        };
+
+        assert!(matches!(
+            query.resolve_range(stats_now()),
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_url_stats_query_invalid_bucket() {
+        assert!(serde_json::from_str::<UrlStatsQuery>(r#"{"bucket": "week"}"#).is_err());
+    }
+
+    #[test]
+    fn test_url_stats_response_serialize() {
+        let stats = ClickStats {
+            total_clicks: 10,
+            range_clicks: 4,
+            platforms: vec![PlatformClickCount {
+                platform: "ios".to_string(),
+                clicks: 4,
+            }],
+            variants: vec![VariantClickCount {
+                variant: "b".to_string(),
+                clicks: 3,
+            }],
+            buckets: vec![ClickBucketCount {
+                bucket_start: "2025-01-30T00:00:00Z".parse().unwrap(),
+                clicks: 4,
+            }],
         };
-        let debug_str = format!("{req:?}");
-        assert!(debug_str.contains("CreateShortUrlRequest"));
+        let response = UrlStatsResponse::new(
+            "Ab3D7Xy".to_string(),
+            StatsBucket::Day,
+            "2025-01-01T00:00:00Z".parse().unwrap(),
+            stats_now(),
+            stats,
+        );
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["total_clicks"], 10);
+        assert_eq!(json["range_clicks"], 4);
+        assert_eq!(json["bucket"], "day");
+        assert_eq!(json["platforms"][0]["platform"], "ios");
+        assert_eq!(json["variants"][0]["variant"], "b");
+        assert_eq!(json["buckets"][0]["clicks"], 4);
     }
 
-    // ============ 추가 validate_short_key 테스트 ============
+    // ============ QrCodeQuery 테스트 ============
 
     #[test]
-    fn test_validate_short_key_max_length() {
-        // 매우 긴 short_key도 유효 (Base62로 인코딩된 큰 숫자)
-        let long_key = "a".repeat(100);
-        assert!(validate_short_key(&long_key).is_ok());
+    fn test_qr_code_query_defaults() {
+        let query: QrCodeQuery = serde_json::from_str("{}").unwrap();
+        let options = query.options();
+
+        assert!(query.validate().is_ok());
+        assert_eq!(options.format, QrFormat::Png);
+        assert_eq!(options.size, QrCodeQuery::DEFAULT_SIZE);
+        assert_eq!(options.error_correction, QrErrorCorrection::Medium);
+        assert_eq!(options.margin, QrCodeQuery::DEFAULT_MARGIN);
     }
 
     #[test]
-    fn test_validate_short_key_all_digits() {
-        assert!(validate_short_key("123456789").is_ok());
+    fn test_qr_code_query_custom() {
+        let query: QrCodeQuery = serde_json::from_str(
+            r#"{"format": "svg", "size": 1024, "errorCorrection": "H", "margin": 0}"#,
+        )
+        .unwrap();
+        let options = query.options();
+
+        assert!(query.validate().is_ok());
+        assert_eq!(options.format, QrFormat::Svg);
+        assert_eq!(options.size, 1024);
+        assert_eq!(options.error_correction, QrErrorCorrection::High);
+        assert_eq!(options.margin, 0);
     }
 
     #[test]
-    fn test_validate_short_key_all_lowercase() {
-        assert!(validate_short_key("abcdefghij").is_ok());
+    fn test_qr_code_query_out_of_range() {
+        let too_small = QrCodeQuery {
+            size: Some(32),
+            ..Default::default()
+        };
+        assert!(too_small.validate().is_err());
+
+        let too_large = QrCodeQuery {
+            size: Some(4096),
+            ..Default::default()
+        };
+        assert!(too_large.validate().is_err());
+
+        let wide_margin = QrCodeQuery {
+            margin: Some(17),
+            ..Default::default()
+        };
+        assert!(wide_margin.validate().is_err());
     }
 
     #[test]
-    fn test_validate_short_key_all_uppercase() {
-        assert!(validate_short_key("ABCDEFGHIJ").is_ok());
+    fn test_qr_code_query_invalid_format() {
+        assert!(serde_json::from_str::<QrCodeQuery>(r#"{"format": "gif"}"#).is_err());
     }
 
+    // ============ validate_android_app_link 테스트 ============
+
     #[test]
-    fn test_validate_short_key_base62_chars() {
-        // Base62에 사용되는 모든 문자 테스트
-        assert!(validate_short_key(
-            "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+    fn test_validate_android_app_link_valid() {
+        assert!(validate_android_app_link(None, None, None).is_ok());
+        assert!(validate_android_app_link(Some("com.example.app"), Some("myapp"), None).is_ok());
+        assert!(validate_android_app_link(
+            Some("com.example.my_app2"),
+            Some("my-app+v2.beta"),
+            Some("product/42?ref=short")
         )
         .is_ok());
     }
 
     #[test]
-    fn test_validate_short_key_invalid_dot() {
-        let result = validate_short_key("abc.def");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    fn test_validate_android_app_link_requires_package_and_scheme() {
+        assert!(validate_android_app_link(Some("com.example.app"), None, None).is_err());
+        assert!(validate_android_app_link(None, Some("myapp"), None).is_err());
+        assert!(validate_android_app_link(None, None, Some("product/42")).is_err());
     }
 
     #[test]
-    fn test_validate_short_key_invalid_slash() {
-        let result = validate_short_key("abc/def");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    fn test_validate_android_app_link_invalid_package() {
+        for package in [
+            "example",
+            "com..app",
+            "com.1example",
+            "com.example-app",
+            ".com.app",
+        ] {
+            assert!(
+                validate_android_app_link(Some(package), Some("myapp"), None).is_err(),
+                "{package}"
+            );
+        }
     }
 
     #[test]
-    fn test_validate_short_key_invalid_backslash() {
-        let result = validate_short_key("abc\\def");
-        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    fn test_validate_android_app_link_invalid_scheme() {
+        for scheme in ["1app", "my app", "my_app", "myapp:"] {
+            assert!(
+                validate_android_app_link(Some("com.example.app"), Some(scheme), None).is_err(),
+                "{scheme}"
+            );
+        }
     }
 
     #[test]
-    fn test_validate_short_key_error_message_too_short() {
-        let result = validate_short_key("abcd");
-        match result {
-            Err(AppError::BadRequest(msg)) => {
-                assert!(msg.contains("at least 5 characters"));
-            }
-            _ => panic!("Expected BadRequest error"),
+    fn test_validate_android_app_link_invalid_path() {
+        for path in ["home#top", "a b", "line\nbreak"] {
+            assert!(
+                validate_android_app_link(Some("com.example.app"), Some("myapp"), Some(path))
+                    .is_err(),
+                "{path}"
+            );
         }
     }
 
     #[test]
-    fn test_validate_short_key_error_message_invalid_chars() {
-        let result = validate_short_key("abc-def");
-        match result {
-            Err(AppError::BadRequest(msg)) => {
-                assert!(msg.contains("English letters and numbers"));
-            }
-            _ => panic!("Expected BadRequest error"),
-        }
+    fn test_android_app_link_request_fields() {
+        let json = r#"{
+            "defaultFallbackUrl": "https://example.com",
+            "androidPackage": "com.example.app",
+            "androidScheme": "myapp",
+            "androidPath": "product/42"
+        }"#;
+        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.android_package.as_deref(), Some("com.example.app"));
+        assert_eq!(req.android_scheme.as_deref(), Some("myapp"));
+        assert_eq!(req.android_path.as_deref(), Some("product/42"));
+
+        let req: UpdateShortUrlRequest =
+            serde_json::from_str(r#"{"androidPackage": null}"#).unwrap();
+        assert_eq!(req.android_package, Some(None));
+        assert!(req.android_scheme.is_none());
     }
 
-    // ============ 추가 CreateShortUrlRequest 유효성 검사 테스트 ============
+    // ============ DeferredMatchRequest 테스트 ============
 
     #[test]
-    fn test_create_short_url_request_validate_invalid_ios_deep_link() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: Some("not-a-url".to_string()),
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
-        };
-        assert!(req.validate().is_err());
+    fn test_deferred_match_request_platform() {
+        let req: DeferredMatchRequest = serde_json::from_str(r#"{"platform": "ios"}"#).unwrap();
+        assert_eq!(req.platform, Platform::Ios);
+
+        let req: DeferredMatchRequest = serde_json::from_str(r#"{"platform": "android"}"#).unwrap();
+        assert_eq!(req.platform, Platform::Android);
+
+        assert!(
+            serde_json::from_str::<DeferredMatchRequest>(r#"{"platform": "symbian"}"#).is_err()
+        );
+        assert!(serde_json::from_str::<DeferredMatchRequest>("{}").is_err());
     }
 
     #[test]
-    fn test_create_short_url_request_validate_invalid_android_deep_link() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: Some("invalid-url".to_string()),
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
-        };
-        assert!(req.validate().is_err());
+    fn test_deferred_match_request_user_agent() {
+        let req: DeferredMatchRequest = serde_json::from_str(r#"{"platform": "ios"}"#).unwrap();
+        assert!(req.user_agent.is_none());
+
+        let req: DeferredMatchRequest =
+            serde_json::from_str(r#"{"platform": "ios", "userAgent": "Mozilla/5.0"}"#).unwrap();
+        assert_eq!(req.user_agent.as_deref(), Some("Mozilla/5.0"));
     }
 
+    // ============ ReportUrlRequest 테스트 ============
+
     #[test]
-    fn test_create_short_url_request_validate_invalid_webhook_url() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: Some("not-a-webhook-url".to_string()),
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
-        };
+    fn test_report_url_request_validation() {
+        let req: ReportUrlRequest = serde_json::from_str(
+            r#"{"reason": "phishing", "details": "Fake login page", "email": "me@example.com"}"#,
+        )
+        .unwrap();
+        assert_eq!(req.reason, ReportReason::Phishing);
+        assert!(req.validate().is_ok());
+
+        let req: ReportUrlRequest = serde_json::from_str(r#"{"reason": "spam"}"#).unwrap();
+        assert!(req.validate().is_ok());
+
+        let req: ReportUrlRequest =
+            serde_json::from_str(r#"{"reason": "other", "email": "not-an-email"}"#).unwrap();
         assert!(req.validate().is_err());
-    }
 
-    #[test]
-    fn test_create_short_url_request_validate_invalid_og_image_url() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: Some("not-an-image-url".to_string()),
+        let req = ReportUrlRequest {
+            reason: ReportReason::Other,
+            details: Some("a".repeat(1001)),
+            email: None,
         };
         assert!(req.validate().is_err());
-    }
 
-    #[test]
-    fn test_create_short_url_request_validate_og_description_max_length() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: Some("a".repeat(500)), // 정확히 500자
-            og_image_url: None,
-        };
-        assert!(req.validate().is_ok());
+        assert!(serde_json::from_str::<ReportUrlRequest>(r#"{"reason": "scam"}"#).is_err());
+        assert!(serde_json::from_str::<ReportUrlRequest>("{}").is_err());
     }
 
+    // ============ ReportResponse 테스트 ============
+
     #[test]
-    fn test_create_short_url_request_validate_empty_strings_ok() {
-        // 빈 문자열은 유효성 검사에서 URL 형식으로 체크되지 않음
-        let req = CreateShortUrlRequest {
-            ios_deep_link: Some(String::new()),
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
+    fn test_report_response_from_report() {
+        let report = Report {
+            id: 7,
+            url_id: 100,
+            reason: ReportReason::Malware,
+            details: None,
+            reporter_email: None,
+            ip_prefix: Some("203.0.113.0/24".to_string()),
+            status: ReportStatus::Open,
+            created_at: Utc::now(),
+            resolved_at: None,
+            random_key: "AbXy".to_string(),
+            alias: None,
+            default_fallback_url: "https://example.com".to_string(),
+            is_active: true,
         };
-        // 빈 문자열은 URL 형식이 아니므로 실패할 수 있음
-        // validator의 url 검사는 빈 문자열을 어떻게 처리하는지에 따라 다름
-        let _ = req.validate(); // 결과에 관계없이 패닉하지 않으면 OK
+
+        let json = serde_json::to_value(ReportResponse::from(report)).unwrap();
+        assert_eq!(json["short_key"], merge_short_key("AbXy", 100));
+        assert_eq!(json["reason"], "malware");
+        assert_eq!(json["status"], "open");
+        assert!(json["resolved_at"].is_null());
     }
 
+    // ============ PurgeRequest 테스트 ============
+
     #[test]
-    fn test_create_short_url_request_validate_https_urls() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: Some("https://ios.example.com".to_string()),
-            ios_fallback_url: Some("https://apps.apple.com/app".to_string()),
-            android_deep_link: Some("https://android.example.com".to_string()),
-            android_fallback_url: Some("https://play.google.com/app".to_string()),
-            default_fallback_url: Some("https://example.com".to_string()),
-            webhook_url: Some("https://webhook.example.com/hook".to_string()),
-            og_title: Some("Title".to_string()),
-            og_description: Some("Description".to_string()),
-            og_image_url: Some("https://example.com/image.png".to_string()),
-        };
+    fn test_purge_request_validation() {
+        let req: PurgeRequest = serde_json::from_str("{}").unwrap();
+        assert!(req.older_than_days.is_none());
         assert!(req.validate().is_ok());
-    }
 
-    #[test]
-    fn test_create_short_url_request_validate_http_urls() {
-        let req = CreateShortUrlRequest {
-            ios_deep_link: None,
-            ios_fallback_url: None,
-            android_deep_link: None,
-            android_fallback_url: None,
-            default_fallback_url: Some("http://example.com".to_string()),
-            webhook_url: None,
-            og_title: None,
-            og_description: None,
-            og_image_url: None,
-        };
+        let req: PurgeRequest = serde_json::from_str(r#"{"olderThanDays": 90}"#).unwrap();
+        assert_eq!(req.older_than_days, Some(90));
         assert!(req.validate().is_ok());
+
+        for days in [0, 3651] {
+            let req = PurgeRequest {
+                older_than_days: Some(days),
+            };
+            assert!(req.validate().is_err());
+        }
     }
 
-    // ============ CreateShortUrlResponse 추가 테스트 ============
+    // ============ ReassignOwnerRequest 테스트 ============
 
     #[test]
-    fn test_create_short_url_response_debug() {
-        let response = CreateShortUrlResponse::created("test123".to_string());
-        let debug_str = format!("{response:?}");
-        assert!(debug_str.contains("CreateShortUrlResponse"));
+    fn test_reassign_owner_request_validation() {
+        let req: ReassignOwnerRequest =
+            serde_json::from_str(r#"{"from": "guest", "to": "ops@example.com"}"#).unwrap();
+        assert!(req.validate().is_ok());
+
+        let req = ReassignOwnerRequest {
+            from: "guest".to_string(),
+            to: String::new(),
+        };
+        assert!(req.validate().is_err());
+        assert!(serde_json::from_str::<ReassignOwnerRequest>(r#"{"from": "guest"}"#).is_err());
     }
 
+    // ============ normalize_alias 테스트 ============
+
     #[test]
-    fn test_create_short_url_response_empty_short_key() {
-        let response = CreateShortUrlResponse::created(String::new());
-        assert_eq!(response.short_key, Some(String::new()));
+    fn test_normalize_alias_valid() {
+        assert_eq!(normalize_alias("spring-sale").unwrap(), "spring-sale");
+        assert_eq!(normalize_alias("promo_2025").unwrap(), "promo_2025");
+        assert_eq!(normalize_alias("abc").unwrap(), "abc");
     }
 
     #[test]
-    fn test_create_short_url_response_long_short_key() {
-        let long_key = "a".repeat(100);
-        let response = CreateShortUrlResponse::created(long_key.clone());
-        assert_eq!(response.short_key, Some(long_key));
+    fn test_normalize_alias_lowercases() {
+        assert_eq!(normalize_alias("Spring-Sale").unwrap(), "spring-sale");
     }
 
     #[test]
-    fn test_create_short_url_response_serialize_json_structure() {
-        let response = CreateShortUrlResponse::created("test123".to_string());
-        let json = serde_json::to_value(&response).unwrap();
-
-        assert!(json.is_object());
-        assert!(json.get("message").is_some());
-        assert!(json.get("short_key").is_some());
+    fn test_normalize_alias_length() {
+        assert!(matches!(
+            normalize_alias("ab"),
+            Err(AppError::Validation(_))
+        ));
+        assert!(normalize_alias(&"a".repeat(ALIAS_MAX_LEN)).is_ok());
+        assert!(normalize_alias(&"a".repeat(ALIAS_MAX_LEN + 1)).is_err());
     }
 
-    // ============ Deserialization 엣지 케이스 ============
-
     #[test]
-    fn test_create_short_url_request_deserialize_empty_json() {
-        let json = "{}";
-        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
-        assert!(req.default_fallback_url.is_none());
-        assert!(req.ios_deep_link.is_none());
+    fn test_normalize_alias_invalid_characters() {
+        assert!(normalize_alias("spring sale").is_err());
+        assert!(normalize_alias("spring/sale").is_err());
+        assert!(normalize_alias("spring.sale").is_err());
+        assert!(normalize_alias("봄세일").is_err());
     }
 
     #[test]
-    fn test_create_short_url_request_deserialize_null_values() {
-        let json = r#"{"defaultFallbackUrl": null, "iosDeepLink": null}"#;
-        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
-        assert!(req.default_fallback_url.is_none());
-        assert!(req.ios_deep_link.is_none());
+    fn test_normalize_alias_edges_must_be_alphanumeric() {
+        assert!(normalize_alias("-spring").is_err());
+        assert!(normalize_alias("spring-").is_err());
+        assert!(normalize_alias("_spring").is_err());
     }
 
     #[test]
-    fn test_create_short_url_request_deserialize_extra_fields() {
-        // 알 수 없는 필드가 있어도 무시
-        let json = r#"{
-            "defaultFallbackUrl": "https://example.com",
-            "unknownField": "should be ignored"
-        }"#;
-        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(
-            req.default_fallback_url,
-            Some("https://example.com".to_string())
-        );
+    fn test_normalize_alias_reserved() {
+        for reserved in RESERVED_ALIASES {
+            assert!(
+                normalize_alias(reserved).is_err(),
+                "{reserved} should be rejected"
+            );
+        }
+        assert!(normalize_alias("HEALTH").is_err());
+        assert!(normalize_alias("ready").is_err());
     }
 
     #[test]
-    fn test_create_short_url_request_deserialize_unicode() {
-        let json = r#"{
-            "defaultFallbackUrl": "https://example.com",
-            "ogTitle": "한글 제목 🚀",
-            "ogDescription": "日本語説明"
-        }"#;
-        let req: CreateShortUrlRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.og_title, Some("한글 제목 🚀".to_string()));
-        assert_eq!(req.og_description, Some("日本語説明".to_string()));
+    fn test_create_short_url_response_with_alias() {
+        let response =
+            CreateShortUrlResponse::created("Ab3D7Xy".to_string()).with_alias(Some("sale".into()));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["alias"], "sale");
+
+        let response = CreateShortUrlResponse::created("Ab3D7Xy".to_string());
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("alias").is_none());
     }
 }
@@ -2,26 +2,49 @@
 //!
 //! Contains shared state for database and cache connections.
 
+use std::sync::Arc;
+
 use deadpool_redis::Pool as RedisPool;
 use sqlx::PgPool;
 
+use crate::config::{AppLinks, DomainBlocklist};
+use crate::models::ClickRecorder;
+
 /// Shared application state.
 ///
 /// This struct holds references to shared resources like database
-/// and cache connections that handlers need access to.
+/// and cache connections and background workers that handlers need access to.
 #[derive(Clone)]
 pub struct AppState {
     /// `PostgreSQL` connection pool
     pub db: PgPool,
     /// Redis connection pool
     pub cache: RedisPool,
+    /// Queue of the batched click writer
+    pub clicks: ClickRecorder,
+    /// Association files of the registered apps
+    pub app_links: Arc<AppLinks>,
+    /// Domains links must not point to
+    pub blocklist: Arc<DomainBlocklist>,
 }
 
 impl AppState {
     /// Creates a new `AppState` instance.
     #[must_use]
-    pub const fn new(db: PgPool, cache: RedisPool) -> Self {
-        Self { db, cache }
+    pub fn new(
+        db: PgPool,
+        cache: RedisPool,
+        clicks: ClickRecorder,
+        app_links: AppLinks,
+        blocklist: DomainBlocklist,
+    ) -> Self {
+        Self {
+            db,
+            cache,
+            clicks,
+            app_links: Arc::new(app_links),
+            blocklist: Arc::new(blocklist),
+        }
     }
 }
 
@@ -0,0 +1,351 @@
+//! App link association configuration module.
+//!
+//! Loads the apps allowed to open short URLs directly (iOS Universal Links
+//! and Android App Links) and renders the association files served from
+//! `/.well-known/`.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::config::env::APP_CONFIG;
+use crate::error::{AppError, AppResult};
+
+/// Path pattern prefix that excludes matching paths in the legacy format.
+const EXCLUDE_PREFIX: &str = "NOT ";
+
+/// iOS app allowed to open short URLs as Universal Links.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IosAppRegistration {
+    /// Apple Developer team ID (10 characters)
+    pub team_id: String,
+    /// App bundle ID (e.g. `com.example.app`)
+    pub bundle_id: String,
+    /// Path patterns handled by the app, first match wins (`NOT ` excludes)
+    #[serde(default = "default_ios_paths")]
+    pub paths: Vec<String>,
+}
+
+/// Android app allowed to open short URLs as App Links.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AndroidAppRegistration {
+    /// Application ID (e.g. `com.example.app`)
+    pub package_name: String,
+    /// SHA-256 fingerprints of the signing certificates (`AB:CD:...`)
+    pub sha256_cert_fingerprints: Vec<String>,
+}
+
+/// App registrations read from the `APP_LINKS_FILE` JSON file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppLinksConfig {
+    #[serde(default)]
+    pub ios: Vec<IosAppRegistration>,
+    #[serde(default)]
+    pub android: Vec<AndroidAppRegistration>,
+}
+
+fn default_ios_paths() -> Vec<String> {
+    vec!["/*".to_string()]
+}
+
+/// Pre-rendered association files, `None` when no app of the platform is registered.
+#[derive(Debug, Clone, Default)]
+pub struct AppLinks {
+    /// Body of `/.well-known/apple-app-site-association`
+    pub apple_app_site_association: Option<String>,
+    /// Body of `/.well-known/assetlinks.json`
+    pub asset_links: Option<String>,
+}
+
+impl AppLinksConfig {
+    /// Parses and validates the JSON configuration.
+    pub fn from_json(json: &str) -> AppResult<Self> {
+        let config: Self = serde_json::from_str(json)
+            .map_err(|e| AppError::Internal(format!("Invalid app links config: {e}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validates the registrations.
+    ///
+    /// # Validation Rules
+    ///
+    /// - Team IDs are 10 uppercase letters or numbers
+    /// - Bundle IDs and package names are non-empty
+    /// - iOS apps have at least one path pattern
+    /// - Android apps have at least one fingerprint, each 32 colon-separated hex bytes
+    fn validate(&self) -> AppResult<()> {
+        let invalid = |message: String| Err(AppError::Internal(message));
+
+        for app in &self.ios {
+            if app.team_id.len() != 10
+                || !app
+                    .team_id
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            {
+                return invalid(format!("Invalid Apple team ID: {}", app.team_id));
+            }
+            if app.bundle_id.is_empty() {
+                return invalid("iOS app bundle ID is required".to_string());
+            }
+            if app.paths.is_empty() {
+                return invalid(format!("No paths configured for {}", app.bundle_id));
+            }
+        }
+
+        for app in &self.android {
+            if app.package_name.is_empty() {
+                return invalid("Android app package name is required".to_string());
+            }
+            if app.sha256_cert_fingerprints.is_empty() {
+                return invalid(format!(
+                    "No certificate fingerprints configured for {}",
+                    app.package_name
+                ));
+            }
+            if let Some(fingerprint) = app
+                .sha256_cert_fingerprints
+                .iter()
+                .find(|fingerprint| !is_sha256_fingerprint(fingerprint))
+            {
+                return invalid(format!("Invalid SHA-256 fingerprint: {fingerprint}"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders the `apple-app-site-association` document.
+    ///
+    /// Each app is listed in both the current (`appIDs` + `components`) and
+    /// the legacy (`appID` + `paths`) format, so that iOS 12 and older
+    /// devices read the same rules.
+    pub fn apple_app_site_association(&self) -> Value {
+        let details: Vec<Value> = self
+            .ios
+            .iter()
+            .map(|app| {
+                let app_id = format!("{}.{}", app.team_id, app.bundle_id);
+                let components: Vec<Value> = app
+                    .paths
+                    .iter()
+                    .map(|path| {
+                        path.strip_prefix(EXCLUDE_PREFIX).map_or_else(
+                            || json!({ "/": path }),
+                            |excluded| json!({ "/": excluded, "exclude": true }),
+                        )
+                    })
+                    .collect();
+
+                json!({
+                    "appIDs": [app_id],
+                    "components": components,
+                    "appID": app_id,
+                    "paths": app.paths,
+                })
+            })
+            .collect();
+
+        json!({ "applinks": { "apps": [], "details": details } })
+    }
+
+    /// Renders the Digital Asset Links statement list (`assetlinks.json`).
+    pub fn asset_links(&self) -> Value {
+        let statements: Vec<Value> = self
+            .android
+            .iter()
+            .map(|app| {
+                json!({
+                    "relation": ["delegate_permission/common.handle_all_urls"],
+                    "target": {
+                        "namespace": "android_app",
+                        "package_name": app.package_name,
+                        "sha256_cert_fingerprints": app
+                            .sha256_cert_fingerprints
+                            .iter()
+                            .map(|fingerprint| fingerprint.to_ascii_uppercase())
+                            .collect::<Vec<_>>(),
+                    },
+                })
+            })
+            .collect();
+
+        Value::Array(statements)
+    }
+}
+
+impl From<&AppLinksConfig> for AppLinks {
+    fn from(config: &AppLinksConfig) -> Self {
+        Self {
+            apple_app_site_association: (!config.ios.is_empty())
+                .then(|| config.apple_app_site_association().to_string()),
+            asset_links: (!config.android.is_empty()).then(|| config.asset_links().to_string()),
+        }
+    }
+}
+
+/// Returns whether a value is a SHA-256 fingerprint (`AB:CD:...`, 32 bytes).
+fn is_sha256_fingerprint(value: &str) -> bool {
+    let bytes: Vec<&str> = value.split(':').collect();
+    bytes.len() == 32
+        && bytes
+            .iter()
+            .all(|byte| byte.len() == 2 && byte.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Loads the association files from the `APP_LINKS_FILE` configuration.
+///
+/// Without a configured file no app is registered and both files are absent.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or holds an invalid configuration
+pub fn load_app_links() -> AppResult<AppLinks> {
+    let path = &APP_CONFIG.app_links_file;
+    if path.is_empty() {
+        return Ok(AppLinks::default());
+    }
+
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| AppError::Internal(format!("Failed to read {path}: {e}")))?;
+    let config = AppLinksConfig::from_json(&json)?;
+
+    tracing::info!(
+        ios_apps = config.ios.len(),
+        android_apps = config.android.len(),
+        "App link associations loaded"
+    );
+    Ok(AppLinks::from(&config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FINGERPRINT: &str = "14:6D:E9:83:C5:73:06:50:D8:EE:B9:95:2F:34:FC:64:\
+                               16:A0:83:42:E6:1D:BE:A8:8A:04:96:B2:3F:CF:44:E5";
+
+    fn config_json() -> String {
+        format!(
+            r#"{{
+                "ios": [{{
+                    "teamId": "ABCDE12345",
+                    "bundleId": "com.example.app",
+                    "paths": ["NOT /v1/*", "/*"]
+                }}],
+                "android": [{{
+                    "packageName": "com.example.app",
+                    "sha256CertFingerprints": ["{}"]
+                }}]
+            }}"#,
+            FINGERPRINT.to_ascii_lowercase()
+        )
+    }
+
+    // ============ AppLinksConfig 파싱 테스트 ============
+
+    #[test]
+    fn test_app_links_config_from_json() {
+        let config = AppLinksConfig::from_json(&config_json()).unwrap();
+
+        assert_eq!(config.ios.len(), 1);
+        assert_eq!(config.ios[0].paths, ["NOT /v1/*", "/*"]);
+        assert_eq!(config.android[0].package_name, "com.example.app");
+    }
+
+    #[test]
+    fn test_app_links_config_default_paths() {
+        let config = AppLinksConfig::from_json(
+            r#"{"ios": [{"teamId": "ABCDE12345", "bundleId": "com.example.app"}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.ios[0].paths, ["/*"]);
+        assert!(config.android.is_empty());
+    }
+
+    #[test]
+    fn test_app_links_config_rejects_invalid_team_id() {
+        for team_id in ["ABCDE1234", "abcde12345", "ABCDE-2345"] {
+            let json = format!(r#"{{"ios": [{{"teamId": "{team_id}", "bundleId": "a.b"}}]}}"#);
+            assert!(AppLinksConfig::from_json(&json).is_err(), "{team_id}");
+        }
+    }
+
+    #[test]
+    fn test_app_links_config_rejects_invalid_fingerprint() {
+        for fingerprints in [r"[]", r#"["14:6D:E9"]"#, r#"["not-a-fingerprint"]"#] {
+            let json = format!(
+                r#"{{"android": [{{"packageName": "a.b", "sha256CertFingerprints": {fingerprints}}}]}}"#
+            );
+            assert!(AppLinksConfig::from_json(&json).is_err(), "{fingerprints}");
+        }
+    }
+
+    #[test]
+    fn test_app_links_config_rejects_malformed_json() {
+        assert!(AppLinksConfig::from_json("{").is_err());
+        assert!(AppLinksConfig::from_json(r#"{"ios": [{"teamId": "ABCDE12345"}]}"#).is_err());
+    }
+
+    // ============ 연결 파일 렌더링 테스트 ============
+
+    #[test]
+    fn test_apple_app_site_association() {
+        let config = AppLinksConfig::from_json(&config_json()).unwrap();
+        let aasa = config.apple_app_site_association();
+
+        assert_eq!(
+            aasa,
+            json!({
+                "applinks": {
+                    "apps": [],
+                    "details": [{
+                        "appIDs": ["ABCDE12345.com.example.app"],
+                        "components": [
+                            { "/": "/v1/*", "exclude": true },
+                            { "/": "/*" }
+                        ],
+                        "appID": "ABCDE12345.com.example.app",
+                        "paths": ["NOT /v1/*", "/*"]
+                    }]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_asset_links_uppercases_fingerprints() {
+        let config = AppLinksConfig::from_json(&config_json()).unwrap();
+
+        assert_eq!(
+            config.asset_links(),
+            json!([{
+                "relation": ["delegate_permission/common.handle_all_urls"],
+                "target": {
+                    "namespace": "android_app",
+                    "package_name": "com.example.app",
+                    "sha256_cert_fingerprints": [FINGERPRINT]
+                }
+            }])
+        );
+    }
+
+    #[test]
+    fn test_app_links_omits_unregistered_platforms() {
+        let links = AppLinks::from(&AppLinksConfig::default());
+        assert!(links.apple_app_site_association.is_none());
+        assert!(links.asset_links.is_none());
+
+        let config = AppLinksConfig::from_json(
+            r#"{"ios": [{"teamId": "ABCDE12345", "bundleId": "com.example.app"}]}"#,
+        )
+        .unwrap();
+        let links = AppLinks::from(&config);
+        assert!(links.apple_app_site_association.is_some());
+        assert!(links.asset_links.is_none());
+    }
+}
@@ -0,0 +1,174 @@
+//! Domain blocklist configuration module.
+//!
+//! Loads the domains links must not point to, from the `BLOCKED_DOMAINS`
+//! setting and the hosts-format `BLOCKLIST_FILE`.
+
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use crate::config::env::APP_CONFIG;
+use crate::error::{AppError, AppResult};
+
+/// Domains links must not point to.
+///
+/// A listed domain also blocks its subdomains: `example.com` blocks
+/// `www.example.com`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainBlocklist {
+    domains: HashSet<String>,
+}
+
+impl DomainBlocklist {
+    /// Adds domains, lowercased and without a trailing dot.
+    ///
+    /// Names without a dot (`localhost`, `broadcasthost`, ...) and IP
+    /// addresses are skipped, as hosts files list them for the local machine.
+    pub fn extend<'a>(&mut self, domains: impl IntoIterator<Item = &'a str>) {
+        self.domains.extend(
+            domains
+                .into_iter()
+                .map(|domain| domain.trim().trim_end_matches('.').to_ascii_lowercase())
+                .filter(|domain| {
+                    domain.contains('.')
+                        && domain != "localhost.localdomain"
+                        && domain.parse::<IpAddr>().is_err()
+                }),
+        );
+    }
+
+    /// Adds the domains of a hosts-format list.
+    ///
+    /// Each line maps an address to host names (`0.0.0.0 ads.example.com`);
+    /// lines holding only domains are accepted as well. Text after `#` is
+    /// a comment.
+    pub fn extend_hosts(&mut self, hosts: &str) {
+        for line in hosts.lines() {
+            let line = line.split_once('#').map_or(line, |(entry, _)| entry);
+            let mut names = line.split_whitespace().peekable();
+            if names
+                .peek()
+                .is_some_and(|name| name.parse::<IpAddr>().is_ok())
+            {
+                names.next();
+            }
+            self.extend(names);
+        }
+    }
+
+    /// Returns the number of listed domains.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.domains.len()
+    }
+
+    /// Returns whether no domain is listed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Returns the listed domain blocking a host, if any.
+    #[must_use]
+    pub fn blocked_by(&self, host: &str) -> Option<&str> {
+        if self.domains.is_empty() {
+            return None;
+        }
+
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        let mut domain = host.as_str();
+        loop {
+            if let Some(blocked) = self.domains.get(domain) {
+                return Some(blocked);
+            }
+            domain = domain.split_once('.')?.1;
+        }
+    }
+}
+
+/// Loads the blocklist from the `BLOCKED_DOMAINS` and `BLOCKLIST_FILE` configuration.
+///
+/// Without either setting no domain is blocked.
+///
+/// # Errors
+///
+/// Returns an error if the configured file cannot be read
+pub fn load_blocklist() -> AppResult<DomainBlocklist> {
+    let mut blocklist = DomainBlocklist::default();
+    blocklist.extend(APP_CONFIG.blocked_domains.split(','));
+
+    let path = &APP_CONFIG.blocklist_file;
+    if !path.is_empty() {
+        let hosts = std::fs::read_to_string(path)
+            .map_err(|e| AppError::Internal(format!("Failed to read {path}: {e}")))?;
+        blocklist.extend_hosts(&hosts);
+    }
+
+    if !blocklist.is_empty() {
+        tracing::info!(domains = blocklist.len(), "Domain blocklist loaded");
+    }
+    Ok(blocklist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocklist(hosts: &str) -> DomainBlocklist {
+        let mut blocklist = DomainBlocklist::default();
+        blocklist.extend_hosts(hosts);
+        blocklist
+    }
+
+    // ============ hosts 파싱 테스트 ============
+
+    #[test]
+    fn test_extend_hosts_parses_entries() {
+        let blocklist = blocklist(
+            "# Blocklist\n\
+             127.0.0.1 localhost\n\
+             ::1 localhost ip6-localhost\n\
+             0.0.0.0 0.0.0.0\n\
+             0.0.0.0 Phish.Example.com tracker.example.net # ads\n\
+             \n\
+             malware.example.org.\n",
+        );
+
+        assert_eq!(blocklist.len(), 3);
+        assert!(blocklist.blocked_by("phish.example.com").is_some());
+        assert!(blocklist.blocked_by("tracker.example.net").is_some());
+        assert!(blocklist.blocked_by("malware.example.org").is_some());
+        assert!(blocklist.blocked_by("localhost").is_none());
+    }
+
+    #[test]
+    fn test_extend_skips_blank_and_local_names() {
+        let mut blocklist = DomainBlocklist::default();
+        blocklist.extend(" evil.example , ,localhost,localhost.localdomain".split(','));
+
+        assert_eq!(blocklist.len(), 1);
+        assert!(blocklist.blocked_by("evil.example").is_some());
+    }
+
+    // ============ 도메인 매칭 테스트 ============
+
+    #[test]
+    fn test_blocked_by_matches_subdomains() {
+        let blocklist = blocklist("0.0.0.0 example.com");
+
+        assert_eq!(blocklist.blocked_by("example.com"), Some("example.com"));
+        assert_eq!(
+            blocklist.blocked_by("WWW.Example.COM."),
+            Some("example.com")
+        );
+        assert!(blocklist.blocked_by("notexample.com").is_none());
+        assert!(blocklist.blocked_by("example.com.evil").is_none());
+        assert!(blocklist.blocked_by("com").is_none());
+    }
+
+    #[test]
+    fn test_empty_blocklist_blocks_nothing() {
+        let blocklist = DomainBlocklist::default();
+        assert!(blocklist.is_empty());
+        assert!(blocklist.blocked_by("example.com").is_none());
+    }
+}
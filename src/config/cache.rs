@@ -27,17 +27,7 @@ pub async fn init_cache() -> AppResult<Pool> {
         return Ok(pool.clone());
     }
 
-    let host = get_env("REDIS_HOST", Some("localhost"));
-    let port = get_env("REDIS_PORT", Some("6379"));
-    let password = get_env("REDIS_PASSWORD", None);
-
-    let redis_url = if password.is_empty() {
-        format!("redis://{host}:{port}")
-    } else {
-        format!("redis://:{password}@{host}:{port}")
-    };
-
-    let mut cfg = Config::from_url(redis_url);
+    let mut cfg = Config::from_url(redis_url());
     cfg.pool = Some(PoolConfig {
         max_size: APP_CONFIG.redis_max_connections,
         ..PoolConfig::default()
@@ -62,6 +52,19 @@ pub async fn init_cache() -> AppResult<Pool> {
     Ok(pool)
 }
 
+/// Returns the connection URL built from the `REDIS_*` environment variables.
+pub fn redis_url() -> String {
+    let host = get_env("REDIS_HOST", Some("localhost"));
+    let port = get_env("REDIS_PORT", Some("6379"));
+    let password = get_env("REDIS_PASSWORD", None);
+
+    if password.is_empty() {
+        format!("redis://{host}:{port}")
+    } else {
+        format!("redis://:{password}@{host}:{port}")
+    }
+}
+
 /// Closes the Redis connection pool.
 ///
 /// Note: The pool handles cleanup automatically when dropped.
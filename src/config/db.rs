@@ -22,14 +22,6 @@ pub async fn init_db() -> AppResult<PgPool> {
         return Ok(pool.clone());
     }
 
-    let host = get_env("DB_HOST", Some("localhost"));
-    let port = get_env("DB_PORT", Some("5432"));
-    let user = get_env("DB_USER", Some("postgres"));
-    let password = get_env("DB_PASSWORD", Some("postgres"));
-    let dbname = get_env("DB_NAME", Some("postgres"));
-
-    let database_url = format!("postgres://{user}:{password}@{host}:{port}/{dbname}");
-
     let pool = PgPoolOptions::new()
         .max_connections(APP_CONFIG.db_max_connections)
         .min_connections(APP_CONFIG.db_min_connections)
@@ -40,7 +32,7 @@ pub async fn init_db() -> AppResult<PgPool> {
         .test_before_acquire(false)
         // Log slow connection acquisitions
         .acquire_slow_threshold(Duration::from_millis(500))
-        .connect(&database_url)
+        .connect(&database_url())
         .await?;
 
     DB_POOL.set(pool.clone()).ok();
@@ -53,6 +45,17 @@ pub async fn init_db() -> AppResult<PgPool> {
     Ok(pool)
 }
 
+/// Returns the connection URL built from the `DB_*` environment variables.
+pub fn database_url() -> String {
+    let host = get_env("DB_HOST", Some("localhost"));
+    let port = get_env("DB_PORT", Some("5432"));
+    let user = get_env("DB_USER", Some("postgres"));
+    let password = get_env("DB_PASSWORD", Some("postgres"));
+    let dbname = get_env("DB_NAME", Some("postgres"));
+
+    format!("postgres://{user}:{password}@{host}:{port}/{dbname}")
+}
+
 /// Closes the database connection pool.
 pub async fn close_db() {
     if let Some(pool) = DB_POOL.get() {
@@ -42,6 +42,8 @@ pub fn get_env_parsed<T: std::str::FromStr>(key: &str, default: T) -> T {
 pub struct AppConfig {
     // Server settings
     pub server_port: String,
+    /// Public origin of short URLs, without a trailing slash (e.g. `https://s.example.com`)
+    pub public_base_url: String,
 
     // Environment
     pub is_production: bool,
@@ -71,6 +73,36 @@ pub struct AppConfig {
     // Webhook settings
     pub webhook_timeout_secs: u64,
     pub webhook_max_concurrent: usize,
+    pub webhook_max_attempts: u32,
+    pub webhook_retry_base_secs: u64,
+    pub webhook_retry_max_secs: u64,
+    pub webhook_poll_interval_ms: u64,
+    pub webhook_batch_size: usize,
+    pub webhook_expiry_sweep_secs: u64,
+
+    // App link associations
+    /// JSON file with the apps served in `/.well-known/` (empty = none)
+    pub app_links_file: String,
+
+    // URL safety
+    /// Comma-separated domains links must not point to
+    pub blocked_domains: String,
+    /// Hosts-format file of further blocked domains (empty = none)
+    pub blocklist_file: String,
+
+    // Reverse proxies
+    /// Comma-separated addresses or CIDR ranges of the proxies whose
+    /// `X-Forwarded-For` / `X-Real-IP` headers are trusted (empty = none)
+    pub trusted_proxies: String,
+
+    // Deferred deep linking
+    /// How long click fingerprints can be claimed by an app install
+    pub deferred_link_ttl_secs: u64,
+
+    // Click analytics settings
+    pub click_batch_size: usize,
+    pub click_flush_interval_ms: u64,
+    pub click_queue_capacity: usize,
 
     // Migration
     pub run_migrations: bool,
@@ -81,9 +113,17 @@ impl AppConfig {
     pub fn from_env() -> Self {
         let rust_env = get_env("RUST_ENV", Some("development"));
         let is_production = rust_env == "production" || rust_env == "prod";
+        let server_port = get_env("SERVER_PORT", Some("3000"));
+        let public_base_url = get_env(
+            "PUBLIC_BASE_URL",
+            Some(&format!("http://localhost:{server_port}")),
+        )
+        .trim_end_matches('/')
+        .to_string();
 
         Self {
-            server_port: get_env("SERVER_PORT", Some("3000")),
+            server_port,
+            public_base_url,
 
             is_production,
 
@@ -106,6 +146,25 @@ impl AppConfig {
 
             webhook_timeout_secs: get_env_parsed("WEBHOOK_TIMEOUT_SECS", 10),
             webhook_max_concurrent: get_env_parsed("WEBHOOK_MAX_CONCURRENT", 100),
+            webhook_max_attempts: get_env_parsed("WEBHOOK_MAX_ATTEMPTS", 8),
+            webhook_retry_base_secs: get_env_parsed("WEBHOOK_RETRY_BASE_SECS", 30),
+            webhook_retry_max_secs: get_env_parsed("WEBHOOK_RETRY_MAX_SECS", 3600),
+            webhook_poll_interval_ms: get_env_parsed("WEBHOOK_POLL_INTERVAL_MS", 1000),
+            webhook_batch_size: get_env_parsed("WEBHOOK_BATCH_SIZE", 100),
+            webhook_expiry_sweep_secs: get_env_parsed("WEBHOOK_EXPIRY_SWEEP_SECS", 60),
+
+            app_links_file: get_env("APP_LINKS_FILE", None),
+
+            blocked_domains: get_env("BLOCKED_DOMAINS", None),
+            blocklist_file: get_env("BLOCKLIST_FILE", None),
+
+            trusted_proxies: get_env("TRUSTED_PROXIES", None),
+
+            deferred_link_ttl_secs: get_env_parsed("DEFERRED_LINK_TTL_SECS", 3600),
+
+            click_batch_size: get_env_parsed("CLICK_BATCH_SIZE", 500),
+            click_flush_interval_ms: get_env_parsed("CLICK_FLUSH_INTERVAL_MS", 1000),
+            click_queue_capacity: get_env_parsed("CLICK_QUEUE_CAPACITY", 10000),
 
             run_migrations: get_env("RUN_MIGRATIONS", Some("true")) == "true",
         }
@@ -241,6 +300,32 @@ mod tests {
         assert!(config.webhook_max_concurrent > 0);
     }
 
+    #[test]
+    fn test_app_config_webhook_retry_settings() {
+        let config = AppConfig::from_env();
+        assert!(config.webhook_max_attempts > 0);
+        assert!(config.webhook_retry_base_secs > 0);
+        assert!(config.webhook_retry_max_secs >= config.webhook_retry_base_secs);
+        assert!(config.webhook_poll_interval_ms > 0);
+        assert!(config.webhook_batch_size > 0);
+        assert!(config.webhook_expiry_sweep_secs > 0);
+    }
+
+    #[test]
+    fn test_app_config_click_settings_positive() {
+        let config = AppConfig::from_env();
+        assert!(config.click_batch_size > 0);
+        assert!(config.click_flush_interval_ms > 0);
+        assert!(config.click_queue_capacity > 0);
+    }
+
+    #[test]
+    fn test_app_config_public_base_url_has_no_trailing_slash() {
+        let config = AppConfig::from_env();
+        assert!(config.public_base_url.starts_with("http"));
+        assert!(!config.public_base_url.ends_with('/'));
+    }
+
     // ============ APP_CONFIG 전역 인스턴스 테스트 ============
 
     #[test]
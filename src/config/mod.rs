@@ -1,11 +1,16 @@
 //! Configuration module.
 //!
-//! Contains environment configuration, database, and cache pool initialization.
+//! Contains environment configuration, database, and cache pool initialization,
+//! the app link associations and the domain blocklist.
 
+pub mod app_links;
+pub mod blocklist;
 pub mod cache;
 pub mod db;
 pub mod env;
 
+pub use app_links::*;
+pub use blocklist::*;
 pub use cache::*;
 pub use db::*;
 pub use env::*;
@@ -25,10 +25,22 @@ pub enum AppError {
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
+    /// Forbidden error (403)
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     /// Not found error (404)
     #[error("Not found: {0}")]
     NotFound(String),
 
+    /// Conflict error (409)
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// Gone error (410)
+    #[error("Gone: {0}")]
+    Gone(String),
+
     /// Validation error (400)
     #[error("Validation error: {0}")]
     Validation(String),
@@ -72,7 +84,10 @@ impl IntoResponse for AppError {
         let (status, error_message) = match &self {
             Self::BadRequest(msg) | Self::Validation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
             Self::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg.clone()),
+            Self::Forbidden(msg) => (StatusCode::FORBIDDEN, msg.clone()),
             Self::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            Self::Conflict(msg) => (StatusCode::CONFLICT, msg.clone()),
+            Self::Gone(msg) => (StatusCode::GONE, msg.clone()),
             Self::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
             Self::Database(e) => {
                 tracing::error!("Database error: {e:?}");
@@ -178,12 +193,30 @@ mod tests {
         assert_eq!(error.to_string(), "Unauthorized: 인증 실패");
     }
 
+    #[test]
+    fn test_app_error_forbidden_display() {
+        let error = AppError::Forbidden("권한 없음".to_string());
+        assert_eq!(error.to_string(), "Forbidden: 권한 없음");
+    }
+
     #[test]
     fn test_app_error_not_found_display() {
         let error = AppError::NotFound("리소스를 찾을 수 없음".to_string());
         assert_eq!(error.to_string(), "Not found: 리소스를 찾을 수 없음");
     }
 
+    #[test]
+    fn test_app_error_conflict_display() {
+        let error = AppError::Conflict("이미 존재함".to_string());
+        assert_eq!(error.to_string(), "Conflict: 이미 존재함");
+    }
+
+    #[test]
+    fn test_app_error_gone_display() {
+        let error = AppError::Gone("만료됨".to_string());
+        assert_eq!(error.to_string(), "Gone: 만료됨");
+    }
+
     #[test]
     fn test_app_error_validation_display() {
         let error = AppError::Validation("유효성 검사 실패".to_string());
@@ -218,6 +251,13 @@ mod tests {
         assert_eq!(response.status(), axum::http::StatusCode::UNAUTHORIZED);
     }
 
+    #[tokio::test]
+    async fn test_forbidden_into_response() {
+        let error = AppError::Forbidden("권한 없음".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+    }
+
     #[tokio::test]
     async fn test_not_found_into_response() {
         let error = AppError::NotFound("없음".to_string());
@@ -225,6 +265,20 @@ mod tests {
         assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
     }
 
+    #[tokio::test]
+    async fn test_conflict_into_response() {
+        let error = AppError::Conflict("중복".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_gone_into_response() {
+        let error = AppError::Gone("만료됨".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::GONE);
+    }
+
     #[tokio::test]
     async fn test_validation_into_response() {
         let error = AppError::Validation("유효하지 않음".to_string());
@@ -10,6 +10,7 @@ use std::net::SocketAddr;
 use std::time::Duration;
 
 use axum::http::{header::HeaderValue, Method};
+use axum::Router;
 use tokio::signal;
 use tower_governor::{
     governor::GovernorConfigBuilder, key_extractor::SmartIpKeyExtractor, GovernorLayer,
@@ -18,7 +19,10 @@ use tower_http::{compression::CompressionLayer, cors::CorsLayer, trace::TraceLay
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use crate::api::{create_routes, AppState};
-use crate::config::{close_cache, close_db, init_cache, init_db, APP_CONFIG};
+use crate::config::{
+    close_cache, close_db, init_cache, init_db, load_app_links, load_blocklist, APP_CONFIG,
+};
+use crate::models::{ClickRecorder, WebhookDispatcher, WebhookRetryPolicy};
 
 // High-performance memory allocator for non-MSVC targets
 #[cfg(not(target_env = "msvc"))]
@@ -38,20 +42,7 @@ async fn main() {
         .init();
 
     // Initialize Sentry
-    let _guard = if APP_CONFIG.sentry_dsn.is_empty() {
-        tracing::warn!("Sentry DSN not configured, error tracking disabled");
-        None
-    } else {
-        Some(sentry::init((
-            APP_CONFIG.sentry_dsn.clone(),
-            sentry::ClientOptions {
-                release: sentry::release_name!(),
-                traces_sample_rate: APP_CONFIG.sentry_traces_sample_rate,
-                sample_rate: 1.0, // Capture all errors
-                ..Default::default()
-            },
-        )))
-    };
+    let _guard = init_sentry();
 
     // Initialize database
     let db = match init_db().await {
@@ -81,36 +72,40 @@ async fn main() {
         }
     };
 
-    // Create application state
-    let state = AppState::new(db, cache);
-
-    // Configure CORS based on environment
-    let cors = build_cors_layer();
+    // Load the app link associations
+    let app_links = match load_app_links() {
+        Ok(app_links) => app_links,
+        Err(e) => {
+            tracing::error!("Failed to load app links: {}", e);
+            std::process::exit(1);
+        }
+    };
 
-    // Configure rate limiting with SmartIpKeyExtractor for better IP detection
-    let Some(governor_config) = GovernorConfigBuilder::default()
-        .per_second(APP_CONFIG.rate_limit_per_second)
-        .burst_size(APP_CONFIG.rate_limit_burst_size)
-        .key_extractor(SmartIpKeyExtractor)
-        .finish()
-    else {
-        tracing::error!("Failed to build rate limiter config");
-        std::process::exit(1);
+    // Load the domain blocklist
+    let blocklist = match load_blocklist() {
+        Ok(blocklist) => blocklist,
+        Err(e) => {
+            tracing::error!("Failed to load domain blocklist: {}", e);
+            std::process::exit(1);
+        }
     };
 
+    // Start the batched click writer
+    let (clicks, click_writer) = ClickRecorder::spawn(
+        db.clone(),
+        APP_CONFIG.click_batch_size,
+        Duration::from_millis(APP_CONFIG.click_flush_interval_ms),
+        APP_CONFIG.click_queue_capacity,
+    );
+
+    // Start the webhook outbox dispatcher
+    let webhook_dispatcher = spawn_webhook_dispatcher(db.clone());
+
+    // Create application state
+    let state = AppState::new(db, cache, clicks, app_links, blocklist);
+
     // Create router with middleware
-    // Layer order (bottom to top execution): CORS -> Compression -> Trace -> Rate Limit
-    let app = create_routes(state)
-        .layer(cors)
-        .layer(
-            CompressionLayer::new()
-                .br(true)
-                .gzip(true)
-                .zstd(true)
-                .quality(tower_http::compression::CompressionLevel::Default),
-        )
-        .layer(TraceLayer::new_for_http())
-        .layer(GovernorLayer::new(governor_config));
+    let app = build_app(state);
 
     // Determine server address
     let port: u16 = APP_CONFIG.server_port.parse().unwrap_or(3000);
@@ -147,6 +142,18 @@ async fn main() {
     // Cleanup
     tracing::info!("Shutting down...");
 
+    // The router (and every click recorder handle) is dropped at this point,
+    // so the writer flushes the remaining clicks and exits
+    if tokio::time::timeout(Duration::from_secs(5), click_writer)
+        .await
+        .is_err()
+    {
+        tracing::warn!("Timed out flushing pending clicks");
+    }
+
+    // Undelivered webhooks stay in the outbox and are retried on the next start
+    webhook_dispatcher.abort();
+
     close_db().await;
     close_cache();
 
@@ -158,6 +165,79 @@ async fn main() {
     tracing::info!("Shutdown complete");
 }
 
+/// Initializes Sentry error tracking if a DSN is configured.
+fn init_sentry() -> Option<sentry::ClientInitGuard> {
+    if APP_CONFIG.sentry_dsn.is_empty() {
+        tracing::warn!("Sentry DSN not configured, error tracking disabled");
+        return None;
+    }
+
+    Some(sentry::init((
+        APP_CONFIG.sentry_dsn.clone(),
+        sentry::ClientOptions {
+            release: sentry::release_name!(),
+            traces_sample_rate: APP_CONFIG.sentry_traces_sample_rate,
+            sample_rate: 1.0, // Capture all errors
+            ..Default::default()
+        },
+    )))
+}
+
+/// Builds the application router with its middleware stack.
+fn build_app(state: AppState) -> Router {
+    // Configure CORS based on environment
+    let cors = build_cors_layer();
+
+    // Configure rate limiting with SmartIpKeyExtractor for better IP detection
+    let Some(governor_config) = GovernorConfigBuilder::default()
+        .per_second(APP_CONFIG.rate_limit_per_second)
+        .burst_size(APP_CONFIG.rate_limit_burst_size)
+        .key_extractor(SmartIpKeyExtractor)
+        .finish()
+    else {
+        tracing::error!("Failed to build rate limiter config");
+        std::process::exit(1);
+    };
+
+    // Layer order (bottom to top execution): CORS -> Compression -> Trace -> Rate Limit
+    create_routes(state)
+        .layer(cors)
+        .layer(
+            CompressionLayer::new()
+                .br(true)
+                .gzip(true)
+                .zstd(true)
+                .quality(tower_http::compression::CompressionLevel::Default),
+        )
+        .layer(TraceLayer::new_for_http())
+        .layer(GovernorLayer::new(governor_config))
+}
+
+/// Spawns the webhook outbox dispatcher with the configured retry policy.
+fn spawn_webhook_dispatcher(db: sqlx::PgPool) -> tokio::task::JoinHandle<()> {
+    WebhookDispatcher::spawn(
+        db,
+        WebhookRetryPolicy {
+            max_attempts: APP_CONFIG.webhook_max_attempts,
+            base_delay: Duration::from_secs(APP_CONFIG.webhook_retry_base_secs),
+            max_delay: Duration::from_secs(APP_CONFIG.webhook_retry_max_secs),
+        },
+        APP_CONFIG.webhook_batch_size,
+        Duration::from_millis(APP_CONFIG.webhook_poll_interval_ms),
+        APP_CONFIG.webhook_max_concurrent,
+        Duration::from_secs(APP_CONFIG.webhook_expiry_sweep_secs),
+    )
+}
+
+/// HTTP methods allowed by CORS.
+const ALLOWED_METHODS: [Method; 5] = [
+    Method::GET,
+    Method::POST,
+    Method::PATCH,
+    Method::DELETE,
+    Method::OPTIONS,
+];
+
 /// Builds the CORS layer based on configuration.
 fn build_cors_layer() -> CorsLayer {
     let cors_origins = &APP_CONFIG.cors_origins;
@@ -165,7 +245,7 @@ fn build_cors_layer() -> CorsLayer {
     if cors_origins == "*" {
         tracing::warn!("CORS is configured to allow all origins - not recommended for production");
         CorsLayer::new()
-            .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+            .allow_methods(ALLOWED_METHODS)
             .allow_origin(tower_http::cors::Any)
             .allow_headers(tower_http::cors::Any)
     } else {
@@ -177,13 +257,13 @@ fn build_cors_layer() -> CorsLayer {
         if origins.is_empty() {
             tracing::warn!("No valid CORS origins configured, allowing all");
             CorsLayer::new()
-                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+                .allow_methods(ALLOWED_METHODS)
                 .allow_origin(tower_http::cors::Any)
                 .allow_headers(tower_http::cors::Any)
         } else {
             tracing::info!(origins = ?origins, "CORS configured with specific origins");
             CorsLayer::new()
-                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+                .allow_methods(ALLOWED_METHODS)
                 .allow_origin(origins)
                 .allow_headers(tower_http::cors::Any)
         }
@@ -0,0 +1,428 @@
+//! Click model module.
+//!
+//! Contains click events, the batched click writer, and the repository
+//! for click analytics.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
+
+use crate::error::AppResult;
+use crate::utils::Platform;
+
+/// Maximum stored length of the `User-Agent` header (in characters).
+const MAX_USER_AGENT_LEN: usize = 512;
+
+/// Maximum stored length of the `Referer` header (in characters).
+const MAX_REFERER_LEN: usize = 2048;
+
+/// Upper bound for the insert batch size.
+///
+/// Keeps a single multi-row `INSERT` well below the Postgres bind parameter limit.
+const MAX_BATCH_SIZE: usize = 5000;
+
+/// A click event waiting to be persisted.
+#[derive(Debug, Clone)]
+pub struct NewClick {
+    pub url_id: i64,
+    pub clicked_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub referer: Option<String>,
+    pub platform: Platform,
+    pub ip_prefix: Option<String>,
+    /// A/B variant the visitor was sent to
+    pub variant: Option<String>,
+}
+
+impl NewClick {
+    /// Creates a click event for the current time, truncating oversized headers.
+    #[must_use]
+    pub fn new(
+        url_id: i64,
+        user_agent: Option<&str>,
+        referer: Option<&str>,
+        ip_prefix: Option<String>,
+    ) -> Self {
+        Self {
+            url_id,
+            clicked_at: Utc::now(),
+            platform: Platform::from_user_agent(user_agent.unwrap_or_default()),
+            user_agent: user_agent.map(|ua| truncate_chars(ua, MAX_USER_AGENT_LEN)),
+            referer: referer.map(|r| truncate_chars(r, MAX_REFERER_LEN)),
+            ip_prefix,
+            variant: None,
+        }
+    }
+
+    /// Records the A/B variant the visitor was sent to.
+    #[must_use]
+    pub fn with_variant(mut self, variant: Option<String>) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+/// Truncates a string to at most `max` characters.
+fn truncate_chars(value: &str, max: usize) -> String {
+    value.chars().take(max).collect()
+}
+
+/// Time bucket granularity for click statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsBucket {
+    /// Hourly buckets
+    Hour,
+    /// Daily buckets (default)
+    #[default]
+    Day,
+}
+
+impl StatsBucket {
+    /// Returns the `date_trunc` field name for this bucket.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+        }
+    }
+
+    /// Returns the duration of a single bucket.
+    #[must_use]
+    pub const fn duration(self) -> chrono::Duration {
+        match self {
+            Self::Hour => chrono::Duration::hours(1),
+            Self::Day => chrono::Duration::days(1),
+        }
+    }
+}
+
+/// Click count of a single time bucket.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ClickBucketCount {
+    pub bucket_start: DateTime<Utc>,
+    pub clicks: i64,
+}
+
+/// Click count of a single platform.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PlatformClickCount {
+    pub platform: String,
+    pub clicks: i64,
+}
+
+/// Click count of a single A/B variant.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct VariantClickCount {
+    pub variant: String,
+    pub clicks: i64,
+}
+
+/// Aggregated click statistics of a URL.
+#[derive(Debug, Clone)]
+pub struct ClickStats {
+    /// All-time click count
+    pub total_clicks: i64,
+    /// Click count within the requested range
+    pub range_clicks: i64,
+    /// Click counts within the requested range, grouped by platform
+    pub platforms: Vec<PlatformClickCount>,
+    /// Click counts within the requested range, grouped by A/B variant
+    pub variants: Vec<VariantClickCount>,
+    /// Non-empty buckets within the requested range, oldest first
+    pub buckets: Vec<ClickBucketCount>,
+}
+
+/// Click repository for database operations.
+pub struct ClickRepository;
+
+impl ClickRepository {
+    /// Inserts a batch of clicks with a single multi-row `INSERT`.
+    pub async fn insert_batch(pool: &PgPool, clicks: &[NewClick]) -> AppResult<u64> {
+        if clicks.is_empty() {
+            return Ok(0);
+        }
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO url_clicks \
+             (url_id, clicked_at, user_agent, referer, platform, ip_prefix, variant) ",
+        );
+        query.push_values(clicks, |mut row, click| {
+            row.push_bind(click.url_id)
+                .push_bind(click.clicked_at)
+                .push_bind(&click.user_agent)
+                .push_bind(&click.referer)
+                .push_bind(click.platform.as_str())
+                .push_bind(&click.ip_prefix)
+                .push_bind(&click.variant);
+        });
+
+        let result = query.build().execute(pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Aggregates the clicks of a URL within `[from, to)`.
+    pub async fn stats(
+        pool: &PgPool,
+        url_id: i64,
+        bucket: StatsBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> AppResult<ClickStats> {
+        let total_clicks: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM url_clicks WHERE url_id = $1")
+                .bind(url_id)
+                .fetch_one(pool)
+                .await?;
+
+        let platforms = sqlx::query_as::<_, PlatformClickCount>(
+            r"
+            SELECT platform, COUNT(*) AS clicks
+            FROM url_clicks
+            WHERE url_id = $1 AND clicked_at >= $2 AND clicked_at < $3
+            GROUP BY platform
+            ORDER BY clicks DESC, platform
+            ",
+        )
+        .bind(url_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        let variants = sqlx::query_as::<_, VariantClickCount>(
+            r"
+            SELECT variant, COUNT(*) AS clicks
+            FROM url_clicks
+            WHERE url_id = $1 AND clicked_at >= $2 AND clicked_at < $3 AND variant IS NOT NULL
+            GROUP BY variant
+            ORDER BY variant
+            ",
+        )
+        .bind(url_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        let buckets = sqlx::query_as::<_, ClickBucketCount>(
+            r"
+            SELECT date_trunc($2, clicked_at, 'UTC') AS bucket_start, COUNT(*) AS clicks
+            FROM url_clicks
+            WHERE url_id = $1 AND clicked_at >= $3 AND clicked_at < $4
+            GROUP BY bucket_start
+            ORDER BY bucket_start
+            ",
+        )
+        .bind(url_id)
+        .bind(bucket.as_str())
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        let range_clicks = platforms.iter().map(|p| p.clicks).sum();
+
+        Ok(ClickStats {
+            total_clicks,
+            range_clicks,
+            platforms,
+            variants,
+            buckets,
+        })
+    }
+}
+
+/// Handle for queueing click events to the background writer.
+///
+/// Recording never blocks the request: when the queue is full the click is dropped.
+#[derive(Clone)]
+pub struct ClickRecorder {
+    sender: mpsc::Sender<NewClick>,
+}
+
+impl ClickRecorder {
+    /// Spawns the background writer and returns a recorder handle with its task.
+    ///
+    /// The writer flushes when `batch_size` clicks are buffered or every
+    /// `flush_interval`, whichever comes first. Once every recorder handle is
+    /// dropped, the remaining clicks are flushed and the task completes.
+    pub fn spawn(
+        pool: PgPool,
+        batch_size: usize,
+        flush_interval: Duration,
+        queue_capacity: usize,
+    ) -> (Self, JoinHandle<()>) {
+        let (sender, receiver) = mpsc::channel(queue_capacity.max(1));
+        let batch_size = batch_size.clamp(1, MAX_BATCH_SIZE);
+        let handle = tokio::spawn(run_click_writer(pool, receiver, batch_size, flush_interval));
+
+        (Self { sender }, handle)
+    }
+
+    /// Queues a click event for persistence.
+    pub fn record(&self, click: NewClick) {
+        match self.sender.try_send(click) {
+            Ok(()) => {}
+            Err(TrySendError::Full(click)) => {
+                tracing::warn!(url_id = click.url_id, "Click queue full, dropping click");
+            }
+            Err(TrySendError::Closed(click)) => {
+                tracing::warn!(
+                    url_id = click.url_id,
+                    "Click writer stopped, dropping click"
+                );
+            }
+        }
+    }
+}
+
+/// Background loop that batches queued clicks into the database.
+async fn run_click_writer(
+    pool: PgPool,
+    mut receiver: mpsc::Receiver<NewClick>,
+    batch_size: usize,
+    flush_interval: Duration,
+) {
+    let mut buffer = Vec::with_capacity(batch_size);
+    let mut ticker = tokio::time::interval(flush_interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        // Never buffer more than one batch
+        let remaining = batch_size - buffer.len();
+        tokio::select! {
+            received = receiver.recv_many(&mut buffer, remaining) => {
+                // Zero means every sender is gone
+                if received == 0 {
+                    flush_clicks(&pool, &mut buffer).await;
+                    break;
+                }
+                if buffer.len() >= batch_size {
+                    flush_clicks(&pool, &mut buffer).await;
+                }
+            }
+            _ = ticker.tick() => {
+                flush_clicks(&pool, &mut buffer).await;
+            }
+        }
+    }
+
+    tracing::info!("Click writer stopped");
+}
+
+/// Writes buffered clicks and clears the buffer.
+///
+/// Failed batches are logged and dropped so that a database outage
+/// cannot grow the buffer without bound.
+async fn flush_clicks(pool: &PgPool, buffer: &mut Vec<NewClick>) {
+    if buffer.is_empty() {
+        return;
+    }
+
+    if let Err(e) = ClickRepository::insert_batch(pool, buffer).await {
+        tracing::error!(
+            count = buffer.len(),
+            error = %e,
+            "Failed to write click batch"
+        );
+    }
+    buffer.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ NewClick 테스트 ============
+
+    #[test]
+    fn test_new_click_detects_platform() {
+        let click = NewClick::new(
+            1,
+            Some("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)"),
+            None,
+            None,
+        );
+        assert_eq!(click.platform, Platform::Ios);
+        assert_eq!(click.url_id, 1);
+    }
+
+    #[test]
+    fn test_new_click_without_user_agent() {
+        let click = NewClick::new(1, None, None, None);
+        assert_eq!(click.platform, Platform::Other);
+        assert!(click.user_agent.is_none());
+    }
+
+    #[test]
+    fn test_new_click_truncates_user_agent() {
+        let long_ua = "a".repeat(MAX_USER_AGENT_LEN + 100);
+        let click = NewClick::new(1, Some(&long_ua), None, None);
+        assert_eq!(click.user_agent.unwrap().len(), MAX_USER_AGENT_LEN);
+    }
+
+    #[test]
+    fn test_new_click_truncates_referer() {
+        let long_referer = format!("https://example.com/{}", "p".repeat(MAX_REFERER_LEN));
+        let click = NewClick::new(1, None, Some(&long_referer), None);
+        assert_eq!(click.referer.unwrap().chars().count(), MAX_REFERER_LEN);
+    }
+
+    // ============ truncate_chars 함수 테스트 ============
+
+    #[test]
+    fn test_truncate_chars_multibyte() {
+        // 바이트가 아닌 문자 단위로 잘라야 함
+        assert_eq!(truncate_chars("한글테스트", 2), "한글");
+    }
+
+    #[test]
+    fn test_truncate_chars_short_input() {
+        assert_eq!(truncate_chars("abc", 10), "abc");
+    }
+
+    // ============ StatsBucket 테스트 ============
+
+    #[test]
+    fn test_stats_bucket_default_is_day() {
+        assert_eq!(StatsBucket::default(), StatsBucket::Day);
+    }
+
+    #[test]
+    fn test_stats_bucket_deserialize() {
+        let hour: StatsBucket = serde_json::from_str("\"hour\"").unwrap();
+        let day: StatsBucket = serde_json::from_str("\"day\"").unwrap();
+        assert_eq!(hour, StatsBucket::Hour);
+        assert_eq!(day, StatsBucket::Day);
+        assert!(serde_json::from_str::<StatsBucket>("\"week\"").is_err());
+    }
+
+    #[test]
+    fn test_stats_bucket_as_str_and_duration() {
+        assert_eq!(StatsBucket::Hour.as_str(), "hour");
+        assert_eq!(StatsBucket::Day.as_str(), "day");
+        assert_eq!(StatsBucket::Hour.duration(), chrono::Duration::hours(1));
+        assert_eq!(StatsBucket::Day.duration(), chrono::Duration::days(1));
+    }
+
+    // ============ ClickRecorder 테스트 ============
+
+    #[tokio::test]
+    async fn test_click_recorder_drops_when_full() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let recorder = ClickRecorder { sender };
+
+        recorder.record(NewClick::new(1, None, None, None));
+        recorder.record(NewClick::new(2, None, None, None));
+
+        // 큐 용량을 초과한 클릭은 버려짐
+        assert_eq!(receiver.recv().await.unwrap().url_id, 1);
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,393 @@
+//! Deferred deep link model module.
+//!
+//! Keeps short-lived click fingerprints in Redis so that an app installed
+//! from a short URL can claim the deep link of the click that led to the
+//! store, matched probabilistically by network, platform and browser.
+
+use chrono::{DateTime, Duration, Utc};
+use deadpool_redis::redis::{self, AsyncCommands};
+use deadpool_redis::Pool as RedisPool;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, AppResult};
+use crate::utils::{BrowserFamily, Platform};
+
+/// Maximum number of fingerprints kept per IP prefix (carrier NATs share prefixes).
+const MAX_CLICKS_PER_PREFIX: isize = 50;
+
+/// Share of the confidence earned by a match at all (same network and platform).
+const BASE_CONFIDENCE: f64 = 0.4;
+
+/// Share of the confidence earned when the browser family matches as well.
+const BROWSER_CONFIDENCE: f64 = 0.1;
+
+/// Fingerprint of a click that may be followed by an app install.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeferredClick {
+    /// Short key or alias the visitor opened
+    pub short_key: String,
+    /// Truncated client IP (`/24` or `/48`)
+    pub ip_prefix: String,
+    /// OS family of the visitor's browser
+    pub platform: Platform,
+    pub clicked_at: DateTime<Utc>,
+    /// Browser family of the visitor (absent in fingerprints of older versions)
+    #[serde(default)]
+    pub browser: BrowserFamily,
+}
+
+/// Click claimed by an installed app.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeferredMatch {
+    pub click: DeferredClick,
+    /// Match confidence between 0 and 1
+    pub confidence: f64,
+}
+
+/// Repository for deferred deep link fingerprints.
+///
+/// Fingerprints live in one sorted set per IP prefix, scored by click time.
+pub struct DeferredLinkRepository;
+
+impl DeferredLinkRepository {
+    /// Stores a click fingerprint for `ttl`.
+    pub async fn record(pool: &RedisPool, click: &DeferredClick, ttl: Duration) -> AppResult<()> {
+        let key = fingerprint_key(&click.ip_prefix);
+        let member = rmp_serde::to_vec(click)
+            .map_err(|e| AppError::Internal(format!("Serialization error: {e}")))?;
+        let mut conn = pool.get().await?;
+
+        redis::pipe()
+            .zadd(&key, member, click.clicked_at.timestamp_millis())
+            .ignore()
+            .zremrangebyrank(&key, 0, -(MAX_CLICKS_PER_PREFIX + 1))
+            .ignore()
+            .expire(&key, ttl.num_seconds())
+            .ignore()
+            .query_async::<()>(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stores a click fingerprint from a background task.
+    ///
+    /// Keeps the Redis round trip off the redirect; failures are logged.
+    pub fn spawn_record(pool: RedisPool, click: DeferredClick, ttl: Duration) {
+        tokio::spawn(async move {
+            if let Err(e) = Self::record(&pool, &click, ttl).await {
+                tracing::error!(
+                    short_key = %click.short_key,
+                    error = %e,
+                    "Failed to record deferred deep link click"
+                );
+            }
+        });
+    }
+
+    /// Claims the most likely click of an app install on the given network.
+    ///
+    /// The matched link's fingerprints for the platform are removed, so a
+    /// click is handed to one install only. Returns `None` when no click
+    /// from the IP prefix and platform happened within `ttl`.
+    pub async fn claim(
+        pool: &RedisPool,
+        ip_prefix: &str,
+        platform: Platform,
+        browser: BrowserFamily,
+        ttl: Duration,
+        now: DateTime<Utc>,
+    ) -> AppResult<Option<DeferredMatch>> {
+        let key = fingerprint_key(ip_prefix);
+        let since = (now - ttl).timestamp_millis();
+        let mut conn = pool.get().await?;
+
+        let (members,): (Vec<Vec<u8>>,) = redis::pipe()
+            .zrembyscore(&key, "-inf", since)
+            .ignore()
+            .zrangebyscore(&key, since, "+inf")
+            .query_async(&mut conn)
+            .await?;
+
+        // Unreadable entries (e.g. from an older format) are skipped
+        let clicks: Vec<(Vec<u8>, DeferredClick)> = members
+            .into_iter()
+            .filter_map(|member| {
+                let click = rmp_serde::from_slice(&member).ok()?;
+                Some((member, click))
+            })
+            .collect();
+
+        let candidates: Vec<DeferredClick> = clicks.iter().map(|(_, c)| c.clone()).collect();
+        let Some(matched) = best_match(&candidates, platform, browser, ttl, now) else {
+            return Ok(None);
+        };
+
+        let claimed: Vec<&Vec<u8>> = clicks
+            .iter()
+            .filter(|(_, c)| c.platform == platform && c.short_key == matched.click.short_key)
+            .map(|(member, _)| member)
+            .collect();
+        let removed: usize = conn.zrem(&key, claimed).await?;
+
+        // Another install claimed the click in the meantime
+        if removed == 0 {
+            return Ok(None);
+        }
+        Ok(Some(matched))
+    }
+}
+
+/// Returns the Redis key of the fingerprints from an IP prefix.
+fn fingerprint_key(ip_prefix: &str) -> String {
+    format!("deferred:{ip_prefix}")
+}
+
+/// Picks the click an install most likely came from.
+///
+/// Only clicks from the same platform within `ttl` are candidates; clicks
+/// from the same browser family are preferred, then the most recent one
+/// wins. Its confidence starts at 0.4 for matching the network and
+/// platform, gains 0.1 when the browser family matches too, rises to 1 the
+/// fresher the click is, and is scaled by the share of recent clicks that
+/// went to the same link, so several visitors on a shared network lower it.
+fn best_match(
+    clicks: &[DeferredClick],
+    platform: Platform,
+    browser: BrowserFamily,
+    ttl: Duration,
+    now: DateTime<Utc>,
+) -> Option<DeferredMatch> {
+    // An unknown family says nothing about the visitor
+    let same_browser =
+        |click: &DeferredClick| browser != BrowserFamily::Other && click.browser == browser;
+
+    #[allow(clippy::cast_precision_loss)]
+    let recency = |click: &DeferredClick| {
+        let age = (now - click.clicked_at).num_milliseconds().max(0) as f64;
+        1.0 - age / ttl.num_milliseconds().max(1) as f64
+    };
+
+    let candidates: Vec<(&DeferredClick, f64)> = clicks
+        .iter()
+        .filter(|click| click.platform == platform)
+        .map(|click| (click, recency(click)))
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect();
+
+    let (best, best_weight) = candidates.iter().max_by(|(a, _), (b, _)| {
+        (same_browser(a), a.clicked_at).cmp(&(same_browser(b), b.clicked_at))
+    })?;
+
+    let total: f64 = candidates.iter().map(|(_, weight)| weight).sum();
+    let same_link: f64 = candidates
+        .iter()
+        .filter(|(click, _)| click.short_key == best.short_key)
+        .map(|(_, weight)| weight)
+        .sum();
+
+    let browser_confidence = if same_browser(best) {
+        BROWSER_CONFIDENCE
+    } else {
+        0.0
+    };
+    let recency_confidence = (1.0 - BASE_CONFIDENCE - BROWSER_CONFIDENCE) * best_weight;
+    let confidence =
+        (BASE_CONFIDENCE + browser_confidence + recency_confidence) * same_link / total;
+    Some(DeferredMatch {
+        click: (*best).clone(),
+        confidence: (confidence * 100.0).round() / 100.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn click(
+        short_key: &str,
+        platform: Platform,
+        secs_ago: i64,
+        now: DateTime<Utc>,
+    ) -> DeferredClick {
+        DeferredClick {
+            short_key: short_key.to_string(),
+            ip_prefix: "203.0.113.0/24".to_string(),
+            platform,
+            clicked_at: now - Duration::seconds(secs_ago),
+            browser: BrowserFamily::Safari,
+        }
+    }
+
+    // ============ best_match 테스트 ============
+
+    #[test]
+    fn test_best_match_single_fresh_click() {
+        let now = Utc::now();
+        let clicks = [click("abc", Platform::Ios, 0, now)];
+
+        let matched = best_match(
+            &clicks,
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now,
+        )
+        .unwrap();
+        assert_eq!(matched.click.short_key, "abc");
+        assert!((matched.confidence - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_best_match_confidence_decays_with_age() {
+        let now = Utc::now();
+        let clicks = [click("abc", Platform::Ios, 1800, now)];
+
+        let matched = best_match(
+            &clicks,
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now,
+        )
+        .unwrap();
+        assert!((matched.confidence - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_best_match_ignores_other_platforms_and_stale_clicks() {
+        let now = Utc::now();
+        let clicks = [
+            click("android", Platform::Android, 0, now),
+            click("stale", Platform::Ios, 3600, now),
+        ];
+
+        assert!(best_match(
+            &clicks,
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now
+        )
+        .is_none());
+        assert!(best_match(
+            &[],
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_best_match_prefers_most_recent_click() {
+        let now = Utc::now();
+        let clicks = [
+            click("older", Platform::Ios, 600, now),
+            click("newer", Platform::Ios, 60, now),
+        ];
+
+        let matched = best_match(
+            &clicks,
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now,
+        )
+        .unwrap();
+        assert_eq!(matched.click.short_key, "newer");
+        // 다른 링크의 클릭이 있으면 신뢰도가 낮아짐
+        assert!(matched.confidence < 0.55);
+    }
+
+    #[test]
+    fn test_best_match_repeated_clicks_keep_confidence() {
+        let now = Utc::now();
+        let clicks = [
+            click("abc", Platform::Ios, 30, now),
+            click("abc", Platform::Ios, 0, now),
+            click("other", Platform::Android, 0, now),
+        ];
+
+        let matched = best_match(
+            &clicks,
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now,
+        )
+        .unwrap();
+        assert!((matched.confidence - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_best_match_prefers_same_browser() {
+        let now = Utc::now();
+        let mut chrome = click("chrome", Platform::Ios, 0, now);
+        chrome.browser = BrowserFamily::Chrome;
+        let clicks = [click("safari", Platform::Ios, 60, now), chrome];
+
+        let matched = best_match(
+            &clicks,
+            Platform::Ios,
+            BrowserFamily::Safari,
+            Duration::hours(1),
+            now,
+        )
+        .unwrap();
+        assert_eq!(matched.click.short_key, "safari");
+    }
+
+    #[test]
+    fn test_best_match_browser_raises_confidence() {
+        let now = Utc::now();
+        let clicks = [click("abc", Platform::Ios, 0, now)];
+        let ttl = Duration::hours(1);
+
+        // 브라우저를 알 수 없거나 다르면 브라우저 점수를 받지 못함
+        for browser in [BrowserFamily::Other, BrowserFamily::Chrome] {
+            let matched = best_match(&clicks, Platform::Ios, browser, ttl, now).unwrap();
+            assert!((matched.confidence - 0.9).abs() < f64::EPSILON);
+        }
+    }
+
+    // ============ DeferredClick 직렬화 테스트 ============
+
+    #[test]
+    fn test_deferred_click_msgpack_roundtrip() {
+        let click = click("abc", Platform::Android, 10, Utc::now());
+        let bytes = rmp_serde::to_vec(&click).unwrap();
+        let decoded: DeferredClick = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, click);
+    }
+
+    #[test]
+    fn test_deferred_click_without_browser_decodes() {
+        // 브라우저 필드가 없던 이전 형식의 지문도 읽을 수 있어야 함
+        #[derive(Serialize)]
+        struct OldDeferredClick {
+            short_key: String,
+            ip_prefix: String,
+            platform: Platform,
+            clicked_at: DateTime<Utc>,
+        }
+
+        let now = Utc::now();
+        let old = OldDeferredClick {
+            short_key: "abc".to_string(),
+            ip_prefix: "203.0.113.0/24".to_string(),
+            platform: Platform::Ios,
+            clicked_at: now,
+        };
+        let bytes = rmp_serde::to_vec(&old).unwrap();
+        let decoded: DeferredClick = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded.browser, BrowserFamily::Other);
+        assert_eq!(decoded.short_key, "abc");
+    }
+
+    #[test]
+    fn test_fingerprint_key() {
+        assert_eq!(fingerprint_key("203.0.113.0/24"), "deferred:203.0.113.0/24");
+    }
+}
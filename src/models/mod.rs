@@ -2,6 +2,20 @@
 //!
 //! Contains domain entities and repository pattern for data access.
 
+pub mod click;
+pub mod deferred;
+pub mod report;
+pub mod routing;
 pub mod url;
+pub mod utm;
+pub mod variant;
+pub mod webhook;
 
+pub use click::*;
+pub use deferred::*;
+pub use report::*;
+pub use routing::*;
 pub use url::*;
+pub use utm::*;
+pub use variant::*;
+pub use webhook::*;
@@ -0,0 +1,254 @@
+//! Abuse report model module.
+//!
+//! Contains visitor reports of malicious links and the repository for the
+//! `reports` table.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+use crate::error::{AppError, AppResult};
+
+/// Why a link was reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportReason {
+    /// Imitates another site to steal credentials
+    Phishing,
+    /// Leads to malicious downloads
+    Malware,
+    /// Unsolicited advertising
+    Spam,
+    /// Anything else, described in the details
+    Other,
+}
+
+impl ReportReason {
+    /// Returns the stored (database) representation of the reason.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Phishing => "phishing",
+            Self::Malware => "malware",
+            Self::Spam => "spam",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl TryFrom<String> for ReportReason {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "phishing" => Ok(Self::Phishing),
+            "malware" => Ok(Self::Malware),
+            "spam" => Ok(Self::Spam),
+            "other" => Ok(Self::Other),
+            _ => Err(AppError::Internal(format!(
+                "Unknown report reason: {value}"
+            ))),
+        }
+    }
+}
+
+/// Review status of a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    /// Waiting for review
+    Open,
+    /// The reported link was disabled
+    Resolved,
+}
+
+impl ReportStatus {
+    /// Returns the stored (database) representation of the status.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Resolved => "resolved",
+        }
+    }
+}
+
+impl TryFrom<String> for ReportStatus {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "open" => Ok(Self::Open),
+            "resolved" => Ok(Self::Resolved),
+            _ => Err(AppError::Internal(format!(
+                "Unknown report status: {value}"
+            ))),
+        }
+    }
+}
+
+/// A report waiting to be persisted.
+#[derive(Debug, Clone)]
+pub struct NewReport {
+    pub url_id: i64,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub reporter_email: Option<String>,
+    /// Reporter IP truncated to its network prefix
+    pub ip_prefix: Option<String>,
+}
+
+/// A stored report with the reported link.
+#[derive(Debug, Clone, FromRow)]
+pub struct Report {
+    pub id: i64,
+    pub url_id: i64,
+    #[sqlx(try_from = "String")]
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub reporter_email: Option<String>,
+    pub ip_prefix: Option<String>,
+    #[sqlx(try_from = "String")]
+    pub status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Random key of the reported link
+    pub random_key: String,
+    /// Alias of the reported link
+    pub alias: Option<String>,
+    /// Default destination of the reported link
+    pub default_fallback_url: String,
+    /// Whether the reported link still redirects
+    pub is_active: bool,
+}
+
+/// Filter for cursor-based report listing.
+///
+/// Results are ordered by ID descending; `cursor` is the last ID of the previous page.
+#[derive(Debug, Clone, Default)]
+pub struct ReportListFilter {
+    pub cursor: Option<i64>,
+    pub limit: i64,
+    /// Only reports with this status (all when `None`)
+    pub status: Option<ReportStatus>,
+}
+
+/// Repository for the `reports` table.
+pub struct ReportRepository;
+
+impl ReportRepository {
+    /// Saves a report.
+    ///
+    /// A report from an IP prefix that already has an open report on the
+    /// same link is dropped, so repeated submissions do not flood the
+    /// review queue. Returns whether the report was saved.
+    pub async fn create(pool: &PgPool, report: &NewReport) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            INSERT INTO reports (url_id, reason, details, reporter_email, ip_prefix)
+            SELECT $1, $2, $3, $4, $5
+            WHERE $5::text IS NULL OR NOT EXISTS (
+                SELECT 1 FROM reports
+                WHERE url_id = $1 AND ip_prefix = $5 AND status = 'open'
+            )
+            ",
+        )
+        .bind(report.url_id)
+        .bind(report.reason.as_str())
+        .bind(&report.details)
+        .bind(&report.reporter_email)
+        .bind(&report.ip_prefix)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Lists reports with their links, newest first.
+    pub async fn list(pool: &PgPool, filter: &ReportListFilter) -> AppResult<Vec<Report>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            r"
+            SELECT r.id, r.url_id, r.reason, r.details, r.reporter_email, r.ip_prefix,
+                   r.status, r.created_at, r.resolved_at, u.random_key, u.alias,
+                   u.default_fallback_url, u.is_active
+            FROM reports r
+            JOIN urls u ON u.id = r.url_id
+            WHERE TRUE",
+        );
+
+        if let Some(status) = filter.status {
+            query.push(" AND r.status = ").push_bind(status.as_str());
+        }
+        if let Some(cursor) = filter.cursor {
+            query.push(" AND r.id < ").push_bind(cursor);
+        }
+
+        query
+            .push(" ORDER BY r.id DESC LIMIT ")
+            .push_bind(filter.limit);
+
+        let reports = query.build_query_as::<Report>().fetch_all(pool).await?;
+
+        Ok(reports)
+    }
+
+    /// Resolves the open reports of a link, returning how many were resolved.
+    pub async fn resolve_for_url(pool: &PgPool, url_id: i64) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            UPDATE reports
+            SET status = 'resolved', resolved_at = NOW()
+            WHERE url_id = $1 AND status = 'open'
+            ",
+        )
+        .bind(url_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ ReportReason 테스트 ============
+
+    #[test]
+    fn test_report_reason_roundtrip() {
+        for reason in [
+            ReportReason::Phishing,
+            ReportReason::Malware,
+            ReportReason::Spam,
+            ReportReason::Other,
+        ] {
+            assert_eq!(
+                ReportReason::try_from(reason.as_str().to_string()).unwrap(),
+                reason
+            );
+            assert_eq!(
+                serde_json::to_string(&reason).unwrap(),
+                format!("\"{}\"", reason.as_str())
+            );
+        }
+        assert!(ReportReason::try_from("scam".to_string()).is_err());
+    }
+
+    // ============ ReportStatus 테스트 ============
+
+    #[test]
+    fn test_report_status_roundtrip() {
+        for status in [ReportStatus::Open, ReportStatus::Resolved] {
+            assert_eq!(
+                ReportStatus::try_from(status.as_str().to_string()).unwrap(),
+                status
+            );
+        }
+        assert!(ReportStatus::try_from("closed".to_string()).is_err());
+        assert_eq!(
+            serde_json::from_str::<ReportStatus>("\"resolved\"").unwrap(),
+            ReportStatus::Resolved
+        );
+    }
+}
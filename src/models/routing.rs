@@ -0,0 +1,563 @@
+//! Routing rule model module.
+//!
+//! Contains the per-link ordered routing rules, which send visitors to a
+//! destination by country, language, time window, device type or query
+//! parameter, and their evaluation against a redirect request.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::utils::Platform;
+
+/// Maximum number of routing rules per link.
+pub const MAX_ROUTING_RULES: usize = 20;
+
+/// Maximum length of a rule destination URL.
+const MAX_DESTINATION_LEN: usize = 2048;
+
+/// Device type matched by a routing rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceType {
+    /// iOS and Android
+    Mobile,
+    /// Windows, macOS, Linux and `ChromeOS`
+    Desktop,
+    /// Crawlers, link preview fetchers and HTTP tools
+    Bot,
+}
+
+impl DeviceType {
+    /// Returns the device type of a platform, `None` for unknown clients.
+    #[must_use]
+    pub const fn from_platform(platform: Platform) -> Option<Self> {
+        match platform {
+            Platform::Ios | Platform::Android => Some(Self::Mobile),
+            Platform::Windows | Platform::MacOs | Platform::Linux | Platform::ChromeOs => {
+                Some(Self::Desktop)
+            }
+            Platform::Bot => Some(Self::Bot),
+            Platform::Other => None,
+        }
+    }
+}
+
+/// Time window of a routing rule; `start` is inclusive, `end` exclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    #[serde(default)]
+    pub start: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    fn contains(&self, now: DateTime<Utc>) -> bool {
+        self.start.is_none_or(|start| start <= now) && self.end.is_none_or(|end| now < end)
+    }
+}
+
+/// Conditions of a routing rule.
+///
+/// Every set condition must match; a list matches when any of its values does.
+/// A rule without conditions matches every visit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleConditions {
+    /// ISO 3166-1 alpha-2 country codes (uppercase)
+    #[serde(default)]
+    pub countries: Option<Vec<String>>,
+    /// Language tags (lowercase) matched against the preferred language;
+    /// `en` also matches `en-us`
+    #[serde(default)]
+    pub languages: Option<Vec<String>>,
+    #[serde(default)]
+    pub time: Option<TimeWindow>,
+    #[serde(default)]
+    pub devices: Option<Vec<DeviceType>>,
+    /// Query parameters of the short URL; a `null` value matches any value
+    #[serde(default)]
+    pub query: Option<BTreeMap<String, Option<String>>>,
+}
+
+/// A routing rule: visits matching the conditions go to the destination.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoutingRule {
+    #[serde(default)]
+    pub conditions: RuleConditions,
+    /// `http(s)` URL the matching visits are redirected to
+    pub destination: String,
+}
+
+/// Facts about a redirect request that routing rules are evaluated against.
+#[derive(Debug, Clone)]
+pub struct RouteContext<'a> {
+    /// Uppercase country code from CDN headers
+    pub country: Option<&'a str>,
+    /// Lowercase preferred language tag from `Accept-Language`
+    pub language: Option<&'a str>,
+    pub platform: Platform,
+    /// Decoded query parameters of the short URL
+    pub query: &'a [(String, String)],
+    pub now: DateTime<Utc>,
+}
+
+/// Ordered routing rules of a link, stored as JSONB.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RoutingRules(pub Vec<RoutingRule>);
+
+impl TryFrom<String> for RoutingRules {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        serde_json::from_str(&value)
+            .map_err(|e| AppError::Internal(format!("Invalid routing rules: {e}")))
+    }
+}
+
+impl RoutingRules {
+    /// Returns whether the link has no rules.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the JSON representation bound to the `routing_rules` column.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Returns the destination of the first rule matching the request.
+    #[must_use]
+    pub fn resolve(&self, ctx: &RouteContext<'_>) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|rule| rule.conditions.matches(ctx))
+            .map(|rule| rule.destination.as_str())
+    }
+
+    /// Validates the rules and normalizes their case.
+    ///
+    /// # Validation Rules
+    ///
+    /// - At most 20 rules
+    /// - Destinations are `http(s)` URLs of at most 2048 characters
+    /// - Condition lists are not empty
+    /// - Countries are 2-letter codes, languages are `xx` or `xx-yy` style tags
+    /// - Time windows end after they start
+    /// - Query parameter names are not empty
+    pub fn normalize(mut self) -> Result<Self, AppError> {
+        let invalid = |message: &str| Err(AppError::Validation(message.to_string()));
+
+        if self.0.len() > MAX_ROUTING_RULES {
+            return invalid("routingRules must have at most 20 rules");
+        }
+
+        for rule in &mut self.0 {
+            if !is_http_url(&rule.destination) {
+                return invalid("Invalid routing rule destination URL");
+            }
+
+            let conditions = &mut rule.conditions;
+            if let Some(countries) = &mut conditions.countries {
+                if countries.is_empty()
+                    || !countries
+                        .iter()
+                        .all(|c| c.len() == 2 && c.chars().all(|c| c.is_ascii_alphabetic()))
+                {
+                    return invalid("Routing rule countries must be 2-letter country codes");
+                }
+                for country in countries.iter_mut() {
+                    country.make_ascii_uppercase();
+                }
+            }
+            if let Some(languages) = &mut conditions.languages {
+                if languages.is_empty() || !languages.iter().all(|l| is_language_tag(l)) {
+                    return invalid("Routing rule languages must be language tags");
+                }
+                for language in languages.iter_mut() {
+                    language.make_ascii_lowercase();
+                }
+            }
+            if let Some(TimeWindow {
+                start: Some(start),
+                end: Some(end),
+            }) = conditions.time
+            {
+                if end <= start {
+                    return invalid("Routing rule time window must end after it starts");
+                }
+            }
+            if conditions.devices.as_ref().is_some_and(Vec::is_empty) {
+                return invalid("Routing rule devices must not be empty");
+            }
+            if let Some(query) = &conditions.query {
+                if query.is_empty() || query.keys().any(String::is_empty) {
+                    return invalid("Routing rule query parameter names must not be empty");
+                }
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+impl RuleConditions {
+    /// Returns whether every set condition matches the request.
+    fn matches(&self, ctx: &RouteContext<'_>) -> bool {
+        let country = self.countries.as_ref().is_none_or(|countries| {
+            ctx.country
+                .is_some_and(|country| countries.iter().any(|c| c == country))
+        });
+        let language = self.languages.as_ref().is_none_or(|languages| {
+            ctx.language
+                .is_some_and(|language| languages.iter().any(|l| language_matches(l, language)))
+        });
+        let time = self.time.as_ref().is_none_or(|time| time.contains(ctx.now));
+        let device = self.devices.as_ref().is_none_or(|devices| {
+            DeviceType::from_platform(ctx.platform).is_some_and(|device| devices.contains(&device))
+        });
+        let query = self.query.as_ref().is_none_or(|params| {
+            params.iter().all(|(name, expected)| {
+                ctx.query.iter().any(|(key, value)| {
+                    key == name && expected.as_ref().is_none_or(|expected| expected == value)
+                })
+            })
+        });
+
+        country && language && time && device && query
+    }
+}
+
+/// Returns whether a rule language matches a language tag (`en` matches `en-us`).
+fn language_matches(rule: &str, tag: &str) -> bool {
+    tag.strip_prefix(rule)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+/// Returns whether a value looks like a language tag (`en`, `zh-hant-tw`).
+fn is_language_tag(value: &str) -> bool {
+    let mut subtags = value.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    (2..=3).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Returns whether a value is an absolute `http(s)` URL of at most 2048 characters.
+#[must_use]
+pub fn is_http_url(value: &str) -> bool {
+    value.len() <= MAX_DESTINATION_LEN
+        && url::Url::parse(value)
+            .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn rule(conditions: RuleConditions, destination: &str) -> RoutingRule {
+        RoutingRule {
+            conditions,
+            destination: destination.to_string(),
+        }
+    }
+
+    fn ctx(query: &[(String, String)]) -> RouteContext<'_> {
+        RouteContext {
+            country: Some("KR"),
+            language: Some("ko-kr"),
+            platform: Platform::Ios,
+            query,
+            now: Utc::now(),
+        }
+    }
+
+    // ============ RoutingRules::resolve 테스트 ============
+
+    #[test]
+    fn test_resolve_first_match_wins() {
+        let rules = RoutingRules(vec![
+            rule(
+                RuleConditions {
+                    countries: Some(vec!["US".to_string()]),
+                    ..Default::default()
+                },
+                "https://example.com/us",
+            ),
+            rule(
+                RuleConditions {
+                    countries: Some(vec!["JP".to_string(), "KR".to_string()]),
+                    ..Default::default()
+                },
+                "https://example.com/asia",
+            ),
+            rule(RuleConditions::default(), "https://example.com/all"),
+        ]);
+
+        assert_eq!(rules.resolve(&ctx(&[])), Some("https://example.com/asia"));
+    }
+
+    #[test]
+    fn test_resolve_no_match() {
+        let rules = RoutingRules(vec![rule(
+            RuleConditions {
+                countries: Some(vec!["US".to_string()]),
+                ..Default::default()
+            },
+            "https://example.com/us",
+        )]);
+
+        assert!(rules.resolve(&ctx(&[])).is_none());
+        assert!(RoutingRules::default().resolve(&ctx(&[])).is_none());
+
+        // 국가를 알 수 없으면 국가 조건은 일치하지 않음
+        let mut unknown = ctx(&[]);
+        unknown.country = None;
+        assert!(rules.resolve(&unknown).is_none());
+    }
+
+    #[test]
+    fn test_resolve_all_conditions_must_match() {
+        let rules = RoutingRules(vec![rule(
+            RuleConditions {
+                countries: Some(vec!["KR".to_string()]),
+                devices: Some(vec![DeviceType::Desktop]),
+                ..Default::default()
+            },
+            "https://example.com/kr-desktop",
+        )]);
+
+        assert!(rules.resolve(&ctx(&[])).is_none());
+
+        let mut desktop = ctx(&[]);
+        desktop.platform = Platform::Windows;
+        assert_eq!(
+            rules.resolve(&desktop),
+            Some("https://example.com/kr-desktop")
+        );
+    }
+
+    #[test]
+    fn test_resolve_language() {
+        let rules = RoutingRules(vec![rule(
+            RuleConditions {
+                languages: Some(vec!["ko".to_string()]),
+                ..Default::default()
+            },
+            "https://example.com/ko",
+        )]);
+
+        assert!(rules.resolve(&ctx(&[])).is_some());
+
+        let mut korean = ctx(&[]);
+        korean.language = Some("ko");
+        assert!(rules.resolve(&korean).is_some());
+
+        // "ko"는 "kok" 같은 다른 언어와 일치하지 않음
+        let mut konkani = ctx(&[]);
+        konkani.language = Some("kok");
+        assert!(rules.resolve(&konkani).is_none());
+    }
+
+    #[test]
+    fn test_resolve_time_window() {
+        let now = Utc::now();
+        let window = |start: Option<i64>, end: Option<i64>| {
+            RoutingRules(vec![rule(
+                RuleConditions {
+                    time: Some(TimeWindow {
+                        start: start.map(|h| now + Duration::hours(h)),
+                        end: end.map(|h| now + Duration::hours(h)),
+                    }),
+                    ..Default::default()
+                },
+                "https://example.com/sale",
+            )])
+        };
+
+        let mut at_now = ctx(&[]);
+        at_now.now = now;
+        assert!(window(Some(-1), Some(1)).resolve(&at_now).is_some());
+        assert!(window(Some(0), None).resolve(&at_now).is_some());
+        assert!(window(None, Some(0)).resolve(&at_now).is_none());
+        assert!(window(Some(1), None).resolve(&at_now).is_none());
+    }
+
+    #[test]
+    fn test_resolve_query_params() {
+        let rules = RoutingRules(vec![rule(
+            RuleConditions {
+                query: Some(BTreeMap::from([
+                    ("src".to_string(), Some("newsletter".to_string())),
+                    ("promo".to_string(), None),
+                ])),
+                ..Default::default()
+            },
+            "https://example.com/newsletter",
+        )]);
+
+        let params = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect()
+        };
+
+        assert!(rules
+            .resolve(&ctx(&params(&[("src", "newsletter"), ("promo", "")])))
+            .is_some());
+        assert!(rules
+            .resolve(&ctx(&params(&[("src", "ads"), ("promo", "1")])))
+            .is_none());
+        assert!(rules
+            .resolve(&ctx(&params(&[("src", "newsletter")])))
+            .is_none());
+    }
+
+    #[test]
+    fn test_device_type_from_platform() {
+        assert_eq!(
+            DeviceType::from_platform(Platform::Android),
+            Some(DeviceType::Mobile)
+        );
+        assert_eq!(
+            DeviceType::from_platform(Platform::ChromeOs),
+            Some(DeviceType::Desktop)
+        );
+        assert_eq!(
+            DeviceType::from_platform(Platform::Bot),
+            Some(DeviceType::Bot)
+        );
+        assert_eq!(DeviceType::from_platform(Platform::Other), None);
+    }
+
+    // ============ RoutingRules::normalize 테스트 ============
+
+    #[test]
+    fn test_normalize_case() {
+        let rules = RoutingRules(vec![rule(
+            RuleConditions {
+                countries: Some(vec!["kr".to_string()]),
+                languages: Some(vec!["EN-us".to_string()]),
+                ..Default::default()
+            },
+            "https://example.com",
+        )])
+        .normalize()
+        .unwrap();
+
+        assert_eq!(
+            rules.0[0].conditions.countries,
+            Some(vec!["KR".to_string()])
+        );
+        assert_eq!(
+            rules.0[0].conditions.languages,
+            Some(vec!["en-us".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_normalize_rejects_invalid_rules() {
+        let invalid = |conditions: RuleConditions, destination: &str| {
+            RoutingRules(vec![rule(conditions, destination)])
+                .normalize()
+                .is_err()
+        };
+
+        assert!(invalid(RuleConditions::default(), "myapp://path"));
+        assert!(invalid(RuleConditions::default(), "not a url"));
+        assert!(invalid(
+            RuleConditions {
+                countries: Some(vec!["KOR".to_string()]),
+                ..Default::default()
+            },
+            "https://example.com"
+        ));
+        assert!(invalid(
+            RuleConditions {
+                languages: Some(vec![]),
+                ..Default::default()
+            },
+            "https://example.com"
+        ));
+        assert!(invalid(
+            RuleConditions {
+                languages: Some(vec!["en_US".to_string()]),
+                ..Default::default()
+            },
+            "https://example.com"
+        ));
+        assert!(invalid(
+            RuleConditions {
+                devices: Some(vec![]),
+                ..Default::default()
+            },
+            "https://example.com"
+        ));
+        assert!(invalid(
+            RuleConditions {
+                query: Some(BTreeMap::from([(String::new(), None)])),
+                ..Default::default()
+            },
+            "https://example.com"
+        ));
+
+        let now = Utc::now();
+        assert!(invalid(
+            RuleConditions {
+                time: Some(TimeWindow {
+                    start: Some(now),
+                    end: Some(now),
+                }),
+                ..Default::default()
+            },
+            "https://example.com"
+        ));
+    }
+
+    #[test]
+    fn test_normalize_rejects_too_many_rules() {
+        let rules = RoutingRules(vec![
+            rule(RuleConditions::default(), "https://example.com");
+            MAX_ROUTING_RULES + 1
+        ]);
+        assert!(rules.normalize().is_err());
+    }
+
+    // ============ 직렬화 테스트 ============
+
+    #[test]
+    fn test_routing_rules_json_roundtrip() {
+        let json = r#"[{"conditions": {"countries": ["KR"], "devices": ["mobile"],
+                        "query": {"src": "ad", "promo": null},
+                        "time": {"start": "2025-01-01T00:00:00Z"}},
+                        "destination": "https://example.com/kr"},
+                       {"destination": "https://example.com"}]"#;
+        let rules: RoutingRules = serde_json::from_str(json).unwrap();
+
+        assert_eq!(rules.0.len(), 2);
+        assert_eq!(rules.0[1].conditions, RuleConditions::default());
+        assert_eq!(RoutingRules::try_from(rules.to_json()).unwrap(), rules);
+        assert!(RoutingRules::try_from("{".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_routing_rules_msgpack_roundtrip() {
+        let rules: RoutingRules = serde_json::from_str(
+            r#"[{"conditions": {"languages": ["en"]}, "destination": "https://example.com"}]"#,
+        )
+        .unwrap();
+        let bytes = rmp_serde::to_vec(&rules).unwrap();
+        assert_eq!(
+            rmp_serde::from_slice::<RoutingRules>(&bytes).unwrap(),
+            rules
+        );
+    }
+}
@@ -2,41 +2,105 @@
 //!
 //! Contains URL entity, cache data, and repository for database operations.
 
-use std::borrow::Cow;
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use once_cell::sync::Lazy;
-use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
-use tokio::sync::Semaphore;
+use sqlx::{FromRow, PgConnection, Postgres, QueryBuilder};
 
-use crate::config::APP_CONFIG;
 use crate::error::{AppError, AppResult};
+use crate::models::{RoutingRules, UrlVariants, UtmParams, VariantRepository};
+use crate::utils::{android_intent_url, join_path, merge_query, Platform};
+
+/// How a short URL responds to a visit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RedirectMode {
+    /// Render the HTML redirect page, which detects the platform and tries deep links
+    #[default]
+    #[serde(rename = "interstitial")]
+    Interstitial,
+    /// Respond with `302 Found` to the platform fallback URL
+    #[serde(rename = "http_302")]
+    Http302,
+    /// Respond with `301 Moved Permanently` to the platform fallback URL
+    #[serde(rename = "http_301")]
+    Http301,
+    /// Respond with `307 Temporary Redirect` to the platform fallback URL
+    #[serde(rename = "http_307")]
+    Http307,
+}
+
+impl RedirectMode {
+    /// Returns the stored (database) representation of the mode.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Interstitial => "interstitial",
+            Self::Http302 => "http_302",
+            Self::Http301 => "http_301",
+            Self::Http307 => "http_307",
+        }
+    }
+}
 
-/// Global HTTP client for webhook requests.
+impl TryFrom<String> for RedirectMode {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "interstitial" => Ok(Self::Interstitial),
+            "http_302" => Ok(Self::Http302),
+            "http_301" => Ok(Self::Http301),
+            "http_307" => Ok(Self::Http307),
+            _ => Err(AppError::Internal(format!(
+                "Unknown redirect mode: {value}"
+            ))),
+        }
+    }
+}
+
+/// How a short URL treats visits from in-app browsers (webviews of social apps).
 ///
-/// Configured with connection pooling and timeouts. Initialized once at first use.
-/// Panics if client creation fails, as this indicates a critical system issue.
-static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
-    let mut default_headers = HeaderMap::new();
-    default_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-
-    reqwest::Client::builder()
-        .timeout(Duration::from_secs(APP_CONFIG.webhook_timeout_secs))
-        .connect_timeout(Duration::from_secs(5))
-        .pool_max_idle_per_host(10)
-        .pool_idle_timeout(Duration::from_secs(60))
-        .default_headers(default_headers)
-        .build()
-        .expect("Failed to create HTTP client - check reqwest/TLS configuration")
-});
-
-/// Semaphore to limit concurrent webhook calls.
-static WEBHOOK_SEMAPHORE: Lazy<Arc<Semaphore>> =
-    Lazy::new(|| Arc::new(Semaphore::new(APP_CONFIG.webhook_max_concurrent)));
+/// Only applies to the interstitial redirect mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InAppBrowserMode {
+    /// Try to reopen the link in the system browser, showing the
+    /// "open in browser" page when the app offers no way out
+    #[default]
+    Escape,
+    /// Always show the "open in browser" page, escaping only on a tap
+    Prompt,
+    /// Treat in-app browsers like regular browsers
+    Off,
+}
+
+impl InAppBrowserMode {
+    /// Returns the stored (database) representation of the mode.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Escape => "escape",
+            Self::Prompt => "prompt",
+            Self::Off => "off",
+        }
+    }
+}
+
+impl TryFrom<String> for InAppBrowserMode {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "escape" => Ok(Self::Escape),
+            "prompt" => Ok(Self::Prompt),
+            "off" => Ok(Self::Off),
+            _ => Err(AppError::Internal(format!(
+                "Unknown in-app browser mode: {value}"
+            ))),
+        }
+    }
+}
 
 /// URL model struct that stores shortened URL information.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -47,13 +111,50 @@ pub struct Url {
     pub ios_fallback_url: Option<String>,
     pub android_deep_link: Option<String>,
     pub android_fallback_url: Option<String>,
+    /// Android app package, scheme and path the intent URL is built from
+    pub android_package: Option<String>,
+    pub android_scheme: Option<String>,
+    pub android_path: Option<String>,
+    /// Desktop app deep link and per-OS desktop fallback URLs
+    pub desktop_deep_link: Option<String>,
+    pub macos_fallback_url: Option<String>,
+    pub windows_fallback_url: Option<String>,
+    pub linux_fallback_url: Option<String>,
+    /// Ordered routing rules, evaluated before the platform destinations
+    #[sqlx(try_from = "String")]
+    pub routing_rules: RoutingRules,
+    /// Weighted A/B destinations replacing the default fallback URL per visitor
+    #[sqlx(try_from = "String")]
+    pub variants: UrlVariants,
+    /// UTM parameters merged into every destination
+    #[sqlx(try_from = "String")]
+    pub utm_params: UtmParams,
+    /// Whether the short URL's query parameters are forwarded to the destination
+    pub query_passthrough: bool,
+    /// Whether a path after the short key is appended to the destination
+    pub allow_path_forwarding: bool,
     pub default_fallback_url: String,
     pub hashed_value: String,
     pub webhook_url: Option<String>,
+    /// Key of the HMAC-SHA256 signature sent with webhook requests
+    #[serde(skip_serializing, default)]
+    pub webhook_secret: String,
     pub og_title: Option<String>,
     pub og_description: Option<String>,
     pub og_image_url: Option<String>,
     pub is_active: bool,
+    #[sqlx(try_from = "String")]
+    pub redirect_mode: RedirectMode,
+    #[sqlx(try_from = "String")]
+    pub in_app_browser_mode: InAppBrowserMode,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_clicks: Option<i64>,
+    pub click_count: i64,
+    pub alias: Option<String>,
+    /// Argon2id hash of the link password (never exposed)
+    #[serde(skip_serializing)]
+    pub password_hash: Option<String>,
+    pub owner: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -69,12 +170,42 @@ pub struct UrlCacheData {
     pub ios_fallback_url: Option<String>,
     pub android_deep_link: Option<String>,
     pub android_fallback_url: Option<String>,
+    /// Android app package, scheme and path the intent URL is built from
+    pub android_package: Option<String>,
+    pub android_scheme: Option<String>,
+    pub android_path: Option<String>,
+    /// Desktop app deep link and per-OS desktop fallback URLs
+    pub desktop_deep_link: Option<String>,
+    pub macos_fallback_url: Option<String>,
+    pub windows_fallback_url: Option<String>,
+    pub linux_fallback_url: Option<String>,
+    /// Ordered routing rules, evaluated before the platform destinations
+    #[sqlx(try_from = "String")]
+    pub routing_rules: RoutingRules,
+    /// Weighted A/B destinations replacing the default fallback URL per visitor
+    #[sqlx(try_from = "String")]
+    pub variants: UrlVariants,
+    /// UTM parameters merged into every destination
+    #[sqlx(try_from = "String")]
+    pub utm_params: UtmParams,
+    /// Whether the short URL's query parameters are forwarded to the destination
+    pub query_passthrough: bool,
+    /// Whether a path after the short key is appended to the destination
+    pub allow_path_forwarding: bool,
     pub default_fallback_url: String,
     pub webhook_url: Option<String>,
     pub og_title: Option<String>,
     pub og_description: Option<String>,
     pub og_image_url: Option<String>,
     pub is_active: bool,
+    #[sqlx(try_from = "String")]
+    pub redirect_mode: RedirectMode,
+    #[sqlx(try_from = "String")]
+    pub in_app_browser_mode: InAppBrowserMode,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_clicks: Option<i64>,
+    pub alias: Option<String>,
+    pub password_hash: Option<String>,
 }
 
 impl From<Url> for UrlCacheData {
@@ -86,24 +217,183 @@ impl From<Url> for UrlCacheData {
             ios_fallback_url: url.ios_fallback_url,
             android_deep_link: url.android_deep_link,
             android_fallback_url: url.android_fallback_url,
+            android_package: url.android_package,
+            android_scheme: url.android_scheme,
+            android_path: url.android_path,
+            desktop_deep_link: url.desktop_deep_link,
+            macos_fallback_url: url.macos_fallback_url,
+            windows_fallback_url: url.windows_fallback_url,
+            linux_fallback_url: url.linux_fallback_url,
+            routing_rules: url.routing_rules,
+            variants: url.variants,
+            utm_params: url.utm_params,
+            query_passthrough: url.query_passthrough,
+            allow_path_forwarding: url.allow_path_forwarding,
             default_fallback_url: url.default_fallback_url,
             webhook_url: url.webhook_url,
             og_title: url.og_title,
             og_description: url.og_description,
             og_image_url: url.og_image_url,
             is_active: url.is_active,
+            redirect_mode: url.redirect_mode,
+            in_app_browser_mode: url.in_app_browser_mode,
+            expires_at: url.expires_at,
+            max_clicks: url.max_clicks,
+            alias: url.alias,
+            password_hash: url.password_hash,
+        }
+    }
+}
+
+impl UrlCacheData {
+    /// Returns whether the link has passed its expiration time.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Returns the cache TTL in seconds, clamped to the time left until expiration.
+    ///
+    /// Returns 0 for expired links, which must not be cached.
+    #[must_use]
+    pub fn cache_ttl_secs(&self, max_ttl_secs: u64, now: DateTime<Utc>) -> u64 {
+        self.expires_at.map_or(max_ttl_secs, |expires_at| {
+            let remaining = u64::try_from((expires_at - now).num_seconds()).unwrap_or(0);
+            remaining.min(max_ttl_secs)
+        })
+    }
+
+    /// Returns the `intent://` URL opening the link's Android app.
+    ///
+    /// Built from the app package, scheme and path; when the app is not
+    /// installed, Chrome navigates to the Android fallback URL by itself.
+    /// Returns `None` for links without an app package and scheme.
+    #[must_use]
+    pub fn android_intent_url(&self) -> Option<String> {
+        let package = self.android_package.as_deref()?;
+        let scheme = self.android_scheme.as_deref()?;
+        let target = format!(
+            "{scheme}://{}",
+            self.android_path.as_deref().unwrap_or_default()
+        );
+
+        android_intent_url(
+            &target,
+            Some(package),
+            Some(self.fallback_url_for(Platform::Android)),
+        )
+    }
+
+    /// Returns the deep link an installed app on the platform should open.
+    ///
+    /// On Android the `{scheme}://{path}` of the app link takes precedence
+    /// over the raw deep link. Returns `None` for other platforms.
+    #[must_use]
+    pub fn deep_link_for(&self, platform: Platform) -> Option<String> {
+        match platform {
+            Platform::Ios => self.ios_deep_link.clone(),
+            Platform::Android => self
+                .android_scheme
+                .as_deref()
+                .map(|scheme| {
+                    format!(
+                        "{scheme}://{}",
+                        self.android_path.as_deref().unwrap_or_default()
+                    )
+                })
+                .or_else(|| self.android_deep_link.clone()),
+            _ => None,
+        }
+        .filter(|link| !link.is_empty())
+    }
+
+    /// Merges query parameters into every destination of the link.
+    ///
+    /// See [`merge_query`] for how `defaults` and `overrides` combine with
+    /// the parameters a destination already has.
+    pub fn merge_query(&mut self, defaults: &[(&str, &str)], overrides: &[(String, String)]) {
+        if defaults.is_empty() && overrides.is_empty() {
+            return;
         }
+        self.map_destinations(|target| merge_query(target, defaults, overrides));
+    }
+
+    /// Appends an encoded path suffix to the path of every destination of the link.
+    pub fn join_path(&mut self, suffix: &str) {
+        self.map_destinations(|target| join_path(target, suffix));
+    }
+
+    /// Rewrites the deep links, the Android app link path and the fallback URLs.
+    fn map_destinations(&mut self, rewrite: impl Fn(&str) -> String) {
+        for target in [
+            &mut self.ios_deep_link,
+            &mut self.ios_fallback_url,
+            &mut self.android_deep_link,
+            &mut self.android_fallback_url,
+            &mut self.desktop_deep_link,
+            &mut self.macos_fallback_url,
+            &mut self.windows_fallback_url,
+            &mut self.linux_fallback_url,
+        ] {
+            if let Some(url) = target.as_mut().filter(|url| !url.is_empty()) {
+                *url = rewrite(url);
+            }
+        }
+        if self.android_scheme.is_some() {
+            let path = self.android_path.as_deref().unwrap_or_default();
+            self.android_path = Some(rewrite(path));
+        }
+        self.default_fallback_url = rewrite(&self.default_fallback_url);
+    }
+
+    /// Returns the fallback URL for a platform.
+    ///
+    /// Platform-specific fallbacks take precedence over the default fallback URL.
+    #[must_use]
+    pub fn fallback_url_for(&self, platform: Platform) -> &str {
+        let platform_fallback = match platform {
+            Platform::Ios => self.ios_fallback_url.as_deref(),
+            Platform::Android => self.android_fallback_url.as_deref(),
+            Platform::MacOs => self.macos_fallback_url.as_deref(),
+            Platform::Windows => self.windows_fallback_url.as_deref(),
+            Platform::Linux => self.linux_fallback_url.as_deref(),
+            _ => None,
+        };
+
+        platform_fallback
+            .filter(|url| !url.is_empty())
+            .unwrap_or(&self.default_fallback_url)
     }
 }
 
 /// Struct for creating a new URL record.
 #[derive(Debug, Clone)]
 pub struct NewUrl {
+    pub owner: String,
     pub random_key: String,
     pub ios_deep_link: Option<String>,
     pub ios_fallback_url: Option<String>,
     pub android_deep_link: Option<String>,
     pub android_fallback_url: Option<String>,
+    /// Android app package, scheme and path the intent URL is built from
+    pub android_package: Option<String>,
+    pub android_scheme: Option<String>,
+    pub android_path: Option<String>,
+    /// Desktop app deep link and per-OS desktop fallback URLs
+    pub desktop_deep_link: Option<String>,
+    pub macos_fallback_url: Option<String>,
+    pub windows_fallback_url: Option<String>,
+    pub linux_fallback_url: Option<String>,
+    /// Ordered routing rules, evaluated before the platform destinations
+    pub routing_rules: RoutingRules,
+    /// Weighted A/B destinations replacing the default fallback URL per visitor
+    pub variants: UrlVariants,
+    /// UTM parameters merged into every destination
+    pub utm_params: UtmParams,
+    /// Whether the short URL's query parameters are forwarded to the destination
+    pub query_passthrough: bool,
+    /// Whether a path after the short key is appended to the destination
+    pub allow_path_forwarding: bool,
     pub default_fallback_url: String,
     pub hashed_value: String,
     pub webhook_url: Option<String>,
@@ -111,73 +401,97 @@ pub struct NewUrl {
     pub og_description: Option<String>,
     pub og_image_url: Option<String>,
     pub is_active: bool,
+    pub redirect_mode: RedirectMode,
+    pub in_app_browser_mode: InAppBrowserMode,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_clicks: Option<i64>,
+    pub alias: Option<String>,
+    pub password_hash: Option<String>,
 }
 
-/// Webhook payload sent when URL is accessed.
-#[derive(Debug, Serialize)]
-struct WebhookPayload {
-    short_key: String,
-    user_agent: String,
+/// Struct for overwriting the mutable fields of an existing URL record.
+#[derive(Debug, Clone)]
+pub struct UrlUpdate {
+    pub ios_deep_link: Option<String>,
+    pub ios_fallback_url: Option<String>,
+    pub android_deep_link: Option<String>,
+    pub android_fallback_url: Option<String>,
+    /// Android app package, scheme and path the intent URL is built from
+    pub android_package: Option<String>,
+    pub android_scheme: Option<String>,
+    pub android_path: Option<String>,
+    /// Desktop app deep link and per-OS desktop fallback URLs
+    pub desktop_deep_link: Option<String>,
+    pub macos_fallback_url: Option<String>,
+    pub windows_fallback_url: Option<String>,
+    pub linux_fallback_url: Option<String>,
+    /// Ordered routing rules, evaluated before the platform destinations
+    pub routing_rules: RoutingRules,
+    /// Weighted A/B destinations replacing the default fallback URL per visitor
+    pub variants: UrlVariants,
+    /// UTM parameters merged into every destination
+    pub utm_params: UtmParams,
+    /// Whether the short URL's query parameters are forwarded to the destination
+    pub query_passthrough: bool,
+    /// Whether a path after the short key is appended to the destination
+    pub allow_path_forwarding: bool,
+    pub default_fallback_url: String,
+    pub hashed_value: String,
+    pub webhook_url: Option<String>,
+    pub og_title: Option<String>,
+    pub og_description: Option<String>,
+    pub og_image_url: Option<String>,
+    pub is_active: bool,
+    pub redirect_mode: RedirectMode,
+    pub in_app_browser_mode: InAppBrowserMode,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_clicks: Option<i64>,
+    pub alias: Option<String>,
+    pub password_hash: Option<String>,
 }
 
-impl UrlCacheData {
-    /// Spawns an async task to send webhook notification with concurrency control.
-    /// Uses `Cow` to avoid unnecessary allocations when possible.
-    pub fn spawn_webhook_task(self, short_key: Cow<'static, str>, user_agent: Cow<'static, str>) {
-        let semaphore = WEBHOOK_SEMAPHORE.clone();
-
-        tokio::spawn(async move {
-            // Try to acquire permit, skip if queue is full
-            let Ok(permit) = semaphore.try_acquire() else {
-                tracing::warn!(
-                    short_key = %short_key,
-                    "Webhook queue full, skipping notification"
-                );
-                return;
-            };
-
-            if let Err(e) =
-                send_webhook_internal(self.webhook_url.as_ref(), &short_key, &user_agent).await
-            {
-                tracing::warn!(
-                    short_key = %short_key,
-                    error = %e,
-                    "Webhook request failed"
-                );
-            }
-
-            drop(permit);
-        });
-    }
+/// Status filter for listing URLs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlStatusFilter {
+    /// Active and inactive URLs that are not deleted (default)
+    #[default]
+    Live,
+    /// Active, non-deleted URLs only
+    Active,
+    /// Deactivated, non-deleted URLs only
+    Inactive,
+    /// Soft-deleted URLs only
+    Deleted,
+    /// Every URL, including deleted ones
+    All,
 }
 
-/// Internal webhook sending function using the global HTTP client.
-async fn send_webhook_internal(
-    webhook_url: Option<&String>,
-    short_key: &str,
-    user_agent: &str,
-) -> AppResult<()> {
-    let Some(url) = webhook_url.filter(|u| !u.is_empty()) else {
-        return Ok(());
-    };
-
-    let payload = WebhookPayload {
-        short_key: short_key.to_string(),
-        user_agent: user_agent.to_string(),
-    };
-
-    // Content-Type header is pre-configured in HTTP_CLIENT
-    let response = HTTP_CLIENT.post(url).json(&payload).send().await?;
+/// Filter for cursor-based URL listing.
+///
+/// Results are ordered by ID descending; `cursor` is the last ID of the previous page.
+#[derive(Debug, Clone, Default)]
+pub struct UrlListFilter {
+    /// Only URLs of this owner (all owners when `None`, for admins)
+    pub owner: Option<String>,
+    pub cursor: Option<i64>,
+    pub limit: i64,
+    pub status: UrlStatusFilter,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub search: Option<String>,
+}
 
-    if !response.status().is_success() {
-        tracing::warn!(
-            webhook_url = %url,
-            status = %response.status().as_u16(),
-            "Webhook returned non-success status"
-        );
+/// Escapes `LIKE` wildcard characters so the input is matched literally.
+fn escape_like(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
     }
-
-    Ok(())
+    escaped
 }
 
 /// Result of create or find operation.
@@ -191,24 +505,133 @@ pub enum CreateOrFindResult {
 /// URL repository for database operations.
 pub struct UrlRepository;
 
+/// Columns written when inserting a URL, in bind order.
+macro_rules! url_insert_columns {
+    () => {
+        "random_key, ios_deep_link, ios_fallback_url,
+         android_deep_link, android_fallback_url, default_fallback_url,
+         hashed_value, webhook_url, og_title, og_description,
+         og_image_url, is_active, redirect_mode, expires_at, max_clicks,
+         alias, password_hash, in_app_browser_mode, android_package, android_scheme,
+         android_path, owner, desktop_deep_link, macos_fallback_url,
+         windows_fallback_url, linux_fallback_url, routing_rules, utm_params,
+         query_passthrough, allow_path_forwarding, created_at, updated_at"
+    };
+}
+
+/// Variants of the selected link as a JSON array, in position order.
+macro_rules! url_variants_column {
+    () => {
+        "COALESCE((
+             SELECT json_agg(json_build_object(
+                 'name', v.name, 'destination', v.destination, 'weight', v.weight
+             ) ORDER BY v.position)
+             FROM url_variants v WHERE v.url_id = urls.id
+         ), '[]')::text AS variants"
+    };
+}
+
+/// Columns selected when loading a full [`Url`] row.
+macro_rules! url_columns {
+    () => {
+        concat!(
+            "id, random_key, ios_deep_link, ios_fallback_url,
+             android_deep_link, android_fallback_url, android_package, android_scheme,
+             android_path, desktop_deep_link, macos_fallback_url, windows_fallback_url,
+             linux_fallback_url, routing_rules::text AS routing_rules, ",
+            url_variants_column!(),
+            ", utm_params::text AS utm_params, query_passthrough, allow_path_forwarding,
+             default_fallback_url, hashed_value, webhook_url, webhook_secret, og_title,
+             og_description, og_image_url, is_active, redirect_mode, in_app_browser_mode,
+             expires_at, max_clicks, click_count, alias, password_hash, owner, created_at,
+             updated_at, deleted_at"
+        )
+    };
+}
+
+/// Columns selected when loading [`UrlCacheData`].
+macro_rules! url_cache_columns {
+    () => {
+        concat!(
+            "id, random_key, ios_deep_link, ios_fallback_url,
+             android_deep_link, android_fallback_url, android_package, android_scheme,
+             android_path, desktop_deep_link, macos_fallback_url, windows_fallback_url,
+             linux_fallback_url, routing_rules::text AS routing_rules, ",
+            url_variants_column!(),
+            ", utm_params::text AS utm_params, query_passthrough, allow_path_forwarding,
+             default_fallback_url, webhook_url, og_title, og_description, og_image_url,
+             is_active, redirect_mode, in_app_browser_mode, expires_at, max_clicks, alias,
+             password_hash"
+        )
+    };
+}
+
+/// Maximum number of rows inserted by one statement of
+/// [`UrlRepository::create_or_find_many`], keeping binds below the Postgres limit.
+const BATCH_INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Name of the unique index on `urls.alias`.
+const ALIAS_UNIQUE_INDEX: &str = "idx_urls_alias_unique";
+
+/// Maps a unique violation on `urls` to a [`AppError::Conflict`] naming the clashing field.
+fn map_unique_violation(error: sqlx::Error) -> AppError {
+    match error {
+        sqlx::Error::Database(e) if e.is_unique_violation() => {
+            if e.constraint() == Some(ALIAS_UNIQUE_INDEX) {
+                AppError::Conflict("Alias is already in use".to_string())
+            } else {
+                AppError::Conflict(
+                    "Another URL with the same destinations already exists".to_string(),
+                )
+            }
+        }
+        e => e.into(),
+    }
+}
+
+/// Inserts the variants of the URLs created by a batch and attaches them to the rows.
+///
+/// Duplicates within the batch share the inserted URL, and so its variants.
+async fn insert_batch_variants(
+    conn: &mut PgConnection,
+    new_urls: &[NewUrl],
+    inserted: &mut HashMap<(String, String), Url>,
+) -> AppResult<()> {
+    let mut variants: HashMap<i64, &UrlVariants> = HashMap::new();
+    for new_url in new_urls {
+        let key = (new_url.owner.clone(), new_url.hashed_value.clone());
+        if let Some(url) = inserted.get_mut(&key) {
+            url.variants = new_url.variants.clone();
+            variants.insert(url.id, &new_url.variants);
+        }
+    }
+
+    let variants: Vec<(i64, &UrlVariants)> = variants.into_iter().collect();
+    VariantRepository::insert_many(conn, &variants).await
+}
+
+/// Rejects cache data of an expired URL with [`AppError::Gone`].
+fn reject_expired(url: Option<UrlCacheData>) -> AppResult<Option<UrlCacheData>> {
+    if url.as_ref().is_some_and(|url| url.is_expired(Utc::now())) {
+        return Err(AppError::Gone("URL has expired".to_string()));
+    }
+    Ok(url)
+}
+
 impl UrlRepository {
-    /// Finds an existing URL by its hash value.
+    /// Finds an owner's existing URL by its hash value.
     /// Returns the URL if it exists and is not deleted.
     pub async fn find_by_hashed_value(
         pool: &sqlx::PgPool,
+        owner: &str,
         hashed_value: &str,
     ) -> AppResult<Option<Url>> {
-        let url = sqlx::query_as::<_, Url>(
-            r"
-            SELECT id, random_key, ios_deep_link, ios_fallback_url,
-                   android_deep_link, android_fallback_url, default_fallback_url,
-                   hashed_value, webhook_url, og_title, og_description,
-                   og_image_url, is_active, created_at, updated_at, deleted_at
-            FROM urls
-            WHERE hashed_value = $1 AND deleted_at IS NULL
-            LIMIT 1
-            ",
-        )
+        let url = sqlx::query_as::<_, Url>(concat!(
+            "SELECT ",
+            url_columns!(),
+            " FROM urls WHERE owner = $1 AND hashed_value = $2 AND deleted_at IS NULL LIMIT 1"
+        ))
+        .bind(owner)
         .bind(hashed_value)
         .fetch_optional(pool)
         .await?;
@@ -216,53 +639,119 @@ impl UrlRepository {
         Ok(url)
     }
 
+    /// Finds an owner's URL by its ID, including inactive ones; `None` as the
+    /// owner matches URLs of every owner.
+    /// Returns `None` if the URL does not exist, belongs to another owner, or has been deleted.
+    pub async fn find_by_id(
+        pool: &sqlx::PgPool,
+        owner: Option<&str>,
+        id: i64,
+    ) -> AppResult<Option<Url>> {
+        let url = sqlx::query_as::<_, Url>(concat!(
+            "SELECT ",
+            url_columns!(),
+            " FROM urls WHERE id = $1 AND ($2::text IS NULL OR owner = $2)",
+            " AND deleted_at IS NULL LIMIT 1"
+        ))
+        .bind(id)
+        .bind(owner)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(url)
+    }
+
     /// Finds a URL by its ID and returns only cache-relevant fields.
     /// Optimized query that excludes timestamps for better performance.
+    ///
+    /// Inactive URLs are returned as well, so disabled links can be told
+    /// apart from unknown ones.
+    ///
+    /// Fails with [`AppError::Gone`] if the URL has expired.
     pub async fn find_by_id_for_cache(
         pool: &sqlx::PgPool,
         id: i64,
     ) -> AppResult<Option<UrlCacheData>> {
-        let url = sqlx::query_as::<_, UrlCacheData>(
-            r"
-            SELECT id, random_key, ios_deep_link, ios_fallback_url,
-                   android_deep_link, android_fallback_url, default_fallback_url,
-                   webhook_url, og_title, og_description, og_image_url, is_active
-            FROM urls
-            WHERE id = $1 AND deleted_at IS NULL AND is_active = true
-            LIMIT 1
-            ",
-        )
+        let url = sqlx::query_as::<_, UrlCacheData>(concat!(
+            "SELECT ",
+            url_cache_columns!(),
+            " FROM urls WHERE id = $1 AND deleted_at IS NULL LIMIT 1"
+        ))
         .bind(id)
         .fetch_optional(pool)
         .await?;
 
+        reject_expired(url)
+    }
+
+    /// Finds a URL by its alias and returns only cache-relevant fields,
+    /// including inactive ones.
+    ///
+    /// Fails with [`AppError::Gone`] if the URL has expired.
+    pub async fn find_by_alias_for_cache(
+        pool: &sqlx::PgPool,
+        alias: &str,
+    ) -> AppResult<Option<UrlCacheData>> {
+        let url = sqlx::query_as::<_, UrlCacheData>(concat!(
+            "SELECT ",
+            url_cache_columns!(),
+            " FROM urls WHERE alias = $1 AND deleted_at IS NULL LIMIT 1"
+        ))
+        .bind(alias)
+        .fetch_optional(pool)
+        .await?;
+
+        reject_expired(url)
+    }
+
+    /// Finds an owner's URL by its alias, including inactive ones; `None` as
+    /// the owner matches URLs of every owner.
+    /// Returns `None` if no live URL of the owner has the alias.
+    pub async fn find_by_alias(
+        pool: &sqlx::PgPool,
+        owner: Option<&str>,
+        alias: &str,
+    ) -> AppResult<Option<Url>> {
+        let url = sqlx::query_as::<_, Url>(concat!(
+            "SELECT ",
+            url_columns!(),
+            " FROM urls WHERE alias = $1 AND ($2::text IS NULL OR owner = $2)",
+            " AND deleted_at IS NULL LIMIT 1"
+        ))
+        .bind(alias)
+        .bind(owner)
+        .fetch_optional(pool)
+        .await?;
+
         Ok(url)
     }
 
     /// Creates a new URL record or returns existing one if hash already exists.
     /// This prevents race conditions using ON CONFLICT.
+    ///
+    /// The URL and its variants are inserted in one transaction.
+    /// Fails with [`AppError::Conflict`] if the alias is already in use.
     pub async fn create_or_find(
         pool: &sqlx::PgPool,
         new_url: &NewUrl,
     ) -> AppResult<CreateOrFindResult> {
+        let mut tx = pool.begin().await?;
+
         // First, try to insert. If conflict on hashed_value, do nothing.
-        let insert_result = sqlx::query_as::<_, Url>(
-            r"
-            INSERT INTO urls (
-                random_key, ios_deep_link, ios_fallback_url,
-                android_deep_link, android_fallback_url, default_fallback_url,
-                hashed_value, webhook_url, og_title, og_description,
-                og_image_url, is_active, created_at, updated_at
+        let insert_result = sqlx::query_as::<_, Url>(concat!(
+            "INSERT INTO urls (",
+            url_insert_columns!(),
+            r")
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17,
+                $18, $19, $20, $21, $22, $23, $24, $25, $26, $27::jsonb, $28::jsonb, $29, $30,
+                NOW(), NOW()
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, NOW(), NOW())
-            ON CONFLICT (hashed_value) WHERE deleted_at IS NULL
+            ON CONFLICT (owner, hashed_value) WHERE deleted_at IS NULL
             DO NOTHING
-            RETURNING id, random_key, ios_deep_link, ios_fallback_url,
-                      android_deep_link, android_fallback_url, default_fallback_url,
-                      hashed_value, webhook_url, og_title, og_description,
-                      og_image_url, is_active, created_at, updated_at, deleted_at
-            ",
-        )
+            RETURNING ",
+            url_columns!()
+        ))
         .bind(&new_url.random_key)
         .bind(&new_url.ios_deep_link)
         .bind(&new_url.ios_fallback_url)
@@ -275,15 +764,38 @@ impl UrlRepository {
         .bind(&new_url.og_description)
         .bind(&new_url.og_image_url)
         .bind(new_url.is_active)
-        .fetch_optional(pool)
-        .await?;
-
-        if let Some(url) = insert_result {
+        .bind(new_url.redirect_mode.as_str())
+        .bind(new_url.expires_at)
+        .bind(new_url.max_clicks)
+        .bind(&new_url.alias)
+        .bind(&new_url.password_hash)
+        .bind(new_url.in_app_browser_mode.as_str())
+        .bind(&new_url.android_package)
+        .bind(&new_url.android_scheme)
+        .bind(&new_url.android_path)
+        .bind(&new_url.owner)
+        .bind(&new_url.desktop_deep_link)
+        .bind(&new_url.macos_fallback_url)
+        .bind(&new_url.windows_fallback_url)
+        .bind(&new_url.linux_fallback_url)
+        .bind(new_url.routing_rules.to_json())
+        .bind(new_url.utm_params.to_json())
+        .bind(new_url.query_passthrough)
+        .bind(new_url.allow_path_forwarding)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_unique_violation)?;
+
+        if let Some(mut url) = insert_result {
+            VariantRepository::insert_many(&mut tx, &[(url.id, &new_url.variants)]).await?;
+            tx.commit().await?;
+            url.variants = new_url.variants.clone();
             return Ok(CreateOrFindResult::Created(url));
         }
+        tx.rollback().await?;
 
         // Insert returned nothing (conflict), find the existing record
-        let existing = Self::find_by_hashed_value(pool, &new_url.hashed_value)
+        let existing = Self::find_by_hashed_value(pool, &new_url.owner, &new_url.hashed_value)
             .await?
             .ok_or_else(|| {
                 AppError::Internal("Race condition: URL not found after conflict".to_string())
@@ -291,6 +803,374 @@ impl UrlRepository {
 
         Ok(CreateOrFindResult::Existing(existing))
     }
+
+    /// Creates many URL records at once, returning one result per input in the same order.
+    ///
+    /// The batch counterpart of [`Self::create_or_find`]: URLs are inserted with
+    /// multi-row `INSERT ... ON CONFLICT DO NOTHING` statements, and inputs whose hash
+    /// already exists for the owner resolve to the existing URL. An input whose alias
+    /// is already in use resolves to [`AppError::Conflict`] without failing the others.
+    pub async fn create_or_find_many(
+        pool: &sqlx::PgPool,
+        new_urls: &[NewUrl],
+    ) -> AppResult<Vec<AppResult<CreateOrFindResult>>> {
+        let mut results = Vec::with_capacity(new_urls.len());
+        for chunk in new_urls.chunks(BATCH_INSERT_CHUNK_SIZE) {
+            results.extend(Self::create_or_find_chunk(pool, chunk).await?);
+        }
+        Ok(results)
+    }
+
+    /// Inserts one chunk of [`Self::create_or_find_many`] with a single statement.
+    async fn create_or_find_chunk(
+        pool: &sqlx::PgPool,
+        new_urls: &[NewUrl],
+    ) -> AppResult<Vec<AppResult<CreateOrFindResult>>> {
+        let mut tx = pool.begin().await?;
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new(concat!("INSERT INTO urls (", url_insert_columns!(), ") "));
+        query.push_values(new_urls, |mut row, new_url| {
+            row.push_bind(&new_url.random_key)
+                .push_bind(&new_url.ios_deep_link)
+                .push_bind(&new_url.ios_fallback_url)
+                .push_bind(&new_url.android_deep_link)
+                .push_bind(&new_url.android_fallback_url)
+                .push_bind(&new_url.default_fallback_url)
+                .push_bind(&new_url.hashed_value)
+                .push_bind(&new_url.webhook_url)
+                .push_bind(&new_url.og_title)
+                .push_bind(&new_url.og_description)
+                .push_bind(&new_url.og_image_url)
+                .push_bind(new_url.is_active)
+                .push_bind(new_url.redirect_mode.as_str())
+                .push_bind(new_url.expires_at)
+                .push_bind(new_url.max_clicks)
+                .push_bind(&new_url.alias)
+                .push_bind(&new_url.password_hash)
+                .push_bind(new_url.in_app_browser_mode.as_str())
+                .push_bind(&new_url.android_package)
+                .push_bind(&new_url.android_scheme)
+                .push_bind(&new_url.android_path)
+                .push_bind(&new_url.owner)
+                .push_bind(&new_url.desktop_deep_link)
+                .push_bind(&new_url.macos_fallback_url)
+                .push_bind(&new_url.windows_fallback_url)
+                .push_bind(&new_url.linux_fallback_url)
+                .push_bind(new_url.routing_rules.to_json())
+                .push_unseparated("::jsonb")
+                .push_bind(new_url.utm_params.to_json())
+                .push_unseparated("::jsonb")
+                .push_bind(new_url.query_passthrough)
+                .push_bind(new_url.allow_path_forwarding)
+                .push("NOW()")
+                .push("NOW()");
+        });
+        // No conflict target: rows clashing on the alias index are skipped as well,
+        // so one taken alias does not abort the whole statement
+        query.push(concat!(
+            " ON CONFLICT DO NOTHING RETURNING ",
+            url_columns!()
+        ));
+
+        let mut inserted: HashMap<(String, String), Url> = query
+            .build_query_as::<Url>()
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|url| ((url.owner.clone(), url.hashed_value.clone()), url))
+            .collect();
+        insert_batch_variants(&mut tx, new_urls, &mut inserted).await?;
+        tx.commit().await?;
+
+        // Skipped rows either already exist (same owner and hash) or clash on the alias
+        let (owners, hashed_values): (Vec<&str>, Vec<&str>) = new_urls
+            .iter()
+            .filter(|new_url| {
+                !inserted.contains_key(&(new_url.owner.clone(), new_url.hashed_value.clone()))
+            })
+            .map(|new_url| (new_url.owner.as_str(), new_url.hashed_value.as_str()))
+            .unzip();
+
+        let mut existing: HashMap<(String, String), Url> = if owners.is_empty() {
+            HashMap::new()
+        } else {
+            sqlx::query_as::<_, Url>(concat!(
+                "SELECT ",
+                url_columns!(),
+                r"
+                FROM urls
+                WHERE deleted_at IS NULL
+                  AND (owner, hashed_value) IN (
+                      SELECT * FROM UNNEST($1::varchar[], $2::varchar[])
+                  )"
+            ))
+            .bind(&owners)
+            .bind(&hashed_values)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|url| ((url.owner.clone(), url.hashed_value.clone()), url))
+            .collect()
+        };
+
+        let results = new_urls
+            .iter()
+            .map(|new_url| {
+                let key = (new_url.owner.clone(), new_url.hashed_value.clone());
+                if let Some(url) = inserted.remove(&key) {
+                    // Later duplicates within the batch resolve to this URL
+                    existing.insert(key, url.clone());
+                    Ok(CreateOrFindResult::Created(url))
+                } else if let Some(url) = existing.get(&key) {
+                    Ok(CreateOrFindResult::Existing(url.clone()))
+                } else {
+                    Err(AppError::Conflict("Alias is already in use".to_string()))
+                }
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Overwrites the mutable fields of an owner's URL, replacing its variants.
+    ///
+    /// Returns `None` if the URL does not exist, belongs to another owner, or has been deleted.
+    /// Fails with [`AppError::Conflict`] if another URL of the owner has the same destinations
+    /// or the alias is already in use.
+    pub async fn update(
+        pool: &sqlx::PgPool,
+        owner: &str,
+        id: i64,
+        changes: &UrlUpdate,
+    ) -> AppResult<Option<Url>> {
+        let mut tx = pool.begin().await?;
+        let url = sqlx::query_as::<_, Url>(concat!(
+            r"
+            UPDATE urls
+            SET ios_deep_link = $2, ios_fallback_url = $3,
+                android_deep_link = $4, android_fallback_url = $5,
+                default_fallback_url = $6, hashed_value = $7, webhook_url = $8,
+                og_title = $9, og_description = $10, og_image_url = $11,
+                -- Links disabled by an admin stay inactive
+                is_active = $12 AND disabled_at IS NULL, redirect_mode = $13, expires_at = $14, max_clicks = $15,
+                alias = $16, password_hash = $17, in_app_browser_mode = $19,
+                android_package = $20, android_scheme = $21, android_path = $22,
+                desktop_deep_link = $23, macos_fallback_url = $24,
+                windows_fallback_url = $25, linux_fallback_url = $26,
+                routing_rules = $27::jsonb, utm_params = $28::jsonb, query_passthrough = $29,
+                allow_path_forwarding = $30,
+                updated_at = NOW(),
+                -- Report the next expiry again once the current one is lifted
+                expired_notified_at = CASE
+                    WHEN $14 <= NOW() OR click_count >= $15 THEN expired_notified_at
+                END
+            WHERE id = $1 AND owner = $18 AND deleted_at IS NULL
+            RETURNING ",
+            url_columns!()
+        ))
+        .bind(id)
+        .bind(&changes.ios_deep_link)
+        .bind(&changes.ios_fallback_url)
+        .bind(&changes.android_deep_link)
+        .bind(&changes.android_fallback_url)
+        .bind(&changes.default_fallback_url)
+        .bind(&changes.hashed_value)
+        .bind(&changes.webhook_url)
+        .bind(&changes.og_title)
+        .bind(&changes.og_description)
+        .bind(&changes.og_image_url)
+        .bind(changes.is_active)
+        .bind(changes.redirect_mode.as_str())
+        .bind(changes.expires_at)
+        .bind(changes.max_clicks)
+        .bind(&changes.alias)
+        .bind(&changes.password_hash)
+        .bind(owner)
+        .bind(changes.in_app_browser_mode.as_str())
+        .bind(&changes.android_package)
+        .bind(&changes.android_scheme)
+        .bind(&changes.android_path)
+        .bind(&changes.desktop_deep_link)
+        .bind(&changes.macos_fallback_url)
+        .bind(&changes.windows_fallback_url)
+        .bind(&changes.linux_fallback_url)
+        .bind(changes.routing_rules.to_json())
+        .bind(changes.utm_params.to_json())
+        .bind(changes.query_passthrough)
+        .bind(changes.allow_path_forwarding)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_unique_violation)?;
+
+        let Some(mut url) = url else {
+            return Ok(None);
+        };
+        VariantRepository::replace(&mut tx, url.id, &changes.variants).await?;
+        tx.commit().await?;
+        url.variants = changes.variants.clone();
+
+        Ok(Some(url))
+    }
+
+    /// Lists an owner's URLs matching the filter, newest first.
+    pub async fn list(pool: &sqlx::PgPool, filter: &UrlListFilter) -> AppResult<Vec<Url>> {
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new(concat!("SELECT ", url_columns!(), " FROM urls WHERE TRUE"));
+
+        if let Some(owner) = &filter.owner {
+            query.push(" AND owner = ").push_bind(owner);
+        }
+
+        match filter.status {
+            UrlStatusFilter::Live => {
+                query.push(" AND deleted_at IS NULL");
+            }
+            UrlStatusFilter::Active => {
+                query.push(" AND deleted_at IS NULL AND is_active = true");
+            }
+            UrlStatusFilter::Inactive => {
+                query.push(" AND deleted_at IS NULL AND is_active = false");
+            }
+            UrlStatusFilter::Deleted => {
+                query.push(" AND deleted_at IS NOT NULL");
+            }
+            UrlStatusFilter::All => {}
+        }
+
+        if let Some(cursor) = filter.cursor {
+            query.push(" AND id < ").push_bind(cursor);
+        }
+        if let Some(created_from) = filter.created_from {
+            query.push(" AND created_at >= ").push_bind(created_from);
+        }
+        if let Some(created_to) = filter.created_to {
+            query.push(" AND created_at < ").push_bind(created_to);
+        }
+        if let Some(search) = filter.search.as_deref().filter(|s| !s.is_empty()) {
+            let pattern = format!("%{}%", escape_like(search));
+            query
+                .push(" AND (default_fallback_url ILIKE ")
+                .push_bind(pattern.clone())
+                .push(" OR og_title ILIKE ")
+                .push_bind(pattern)
+                .push(")");
+        }
+
+        query
+            .push(" ORDER BY id DESC LIMIT ")
+            .push_bind(filter.limit);
+
+        let urls = query.build_query_as::<Url>().fetch_all(pool).await?;
+
+        Ok(urls)
+    }
+
+    /// Counts a click against the click limit of a capped URL.
+    ///
+    /// The check and increment happen in a single statement, so concurrent
+    /// visits cannot exceed the limit. Returns `false` once the limit is reached.
+    pub async fn consume_click(pool: &sqlx::PgPool, id: i64) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            UPDATE urls
+            SET click_count = click_count + 1
+            WHERE id = $1 AND max_clicks IS NOT NULL AND click_count < max_clicks
+            ",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Soft-deletes an owner's URL by setting `deleted_at`.
+    ///
+    /// Returns `false` if the URL does not exist, belongs to another owner, or was already deleted.
+    pub async fn soft_delete(pool: &sqlx::PgPool, owner: &str, id: i64) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            UPDATE urls
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND owner = $2 AND deleted_at IS NULL
+            ",
+        )
+        .bind(id)
+        .bind(owner)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Permanently deletes URLs soft-deleted before `cutoff`, with their
+    /// clicks, variants, reports and webhook deliveries.
+    ///
+    /// Returns the number of purged URLs.
+    pub async fn purge_deleted(pool: &sqlx::PgPool, cutoff: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query("DELETE FROM urls WHERE deleted_at < $1")
+            .bind(cutoff)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Moves every URL of one owner to another.
+    ///
+    /// Live URLs whose destinations the new owner already has stay with the
+    /// old owner, since an owner holds at most one live URL per destination
+    /// hash. Returns the number of moved URLs.
+    pub async fn reassign_owner(pool: &sqlx::PgPool, from: &str, to: &str) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            UPDATE urls u
+            SET owner = $2, updated_at = NOW()
+            WHERE u.owner = $1
+              AND (u.deleted_at IS NOT NULL OR NOT EXISTS (
+                  SELECT 1 FROM urls o
+                  WHERE o.owner = $2 AND o.hashed_value = u.hashed_value
+                    AND o.deleted_at IS NULL
+              ))
+            ",
+        )
+        .bind(from)
+        .bind(to)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Counts the URLs of an owner, including deleted ones.
+    pub async fn count_by_owner(pool: &sqlx::PgPool, owner: &str) -> AppResult<i64> {
+        let count = sqlx::query_scalar("SELECT COUNT(*) FROM urls WHERE owner = $1")
+            .bind(owner)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Disables a URL of any owner as an admin takedown.
+    ///
+    /// The URL stays inactive even if its owner tries to re-activate it.
+    /// Returns `None` if the URL does not exist or has been deleted.
+    pub async fn disable(pool: &sqlx::PgPool, id: i64) -> AppResult<Option<Url>> {
+        let url = sqlx::query_as::<_, Url>(concat!(
+            r"
+            UPDATE urls
+            SET is_active = false, disabled_at = COALESCE(disabled_at, NOW()), updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING ",
+            url_columns!()
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(url)
+    }
 }
 
 #[cfg(test)]
@@ -305,6 +1185,18 @@ mod tests {
             ios_fallback_url: Some("https://apps.apple.com".to_string()),
             android_deep_link: Some("app://android".to_string()),
             android_fallback_url: Some("https://play.google.com".to_string()),
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://example.com".to_string(),
             hashed_value: "abc123hash".to_string(),
             webhook_url: Some("https://webhook.example.com".to_string()),
@@ -315,6 +1207,15 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
         }
     }
 
@@ -326,6 +1227,18 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://minimal.com".to_string(),
             hashed_value: "minimal123".to_string(),
             webhook_url: None,
@@ -336,9 +1249,114 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
         }
     }
 
+    // ============ escape_like 테스트 ============
+
+    #[test]
+    fn test_escape_like_plain_text() {
+        assert_eq!(escape_like("example.com"), "example.com");
+    }
+
+    #[test]
+    fn test_escape_like_wildcards() {
+        assert_eq!(escape_like("100%_off"), "100\\%\\_off");
+    }
+
+    #[test]
+    fn test_escape_like_backslash() {
+        assert_eq!(escape_like("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn test_escape_like_unicode() {
+        assert_eq!(escape_like("한글%"), "한글\\%");
+    }
+
+    // ============ UrlStatusFilter 테스트 ============
+
+    #[test]
+    fn test_url_status_filter_default_is_live() {
+        assert_eq!(UrlStatusFilter::default(), UrlStatusFilter::Live);
+    }
+
+    #[test]
+    fn test_url_status_filter_deserialize() {
+        let status: UrlStatusFilter = serde_json::from_str(r#""deleted""#).unwrap();
+        assert_eq!(status, UrlStatusFilter::Deleted);
+        assert!(serde_json::from_str::<UrlStatusFilter>(r#""unknown""#).is_err());
+    }
+
+    // ============ RedirectMode 테스트 ============
+
+    #[test]
+    fn test_redirect_mode_default_is_interstitial() {
+        assert_eq!(RedirectMode::default(), RedirectMode::Interstitial);
+    }
+
+    #[test]
+    fn test_redirect_mode_serde_matches_as_str() {
+        for mode in [
+            RedirectMode::Interstitial,
+            RedirectMode::Http302,
+            RedirectMode::Http301,
+            RedirectMode::Http307,
+        ] {
+            let json = serde_json::to_string(&mode).unwrap();
+            assert_eq!(json, format!("\"{}\"", mode.as_str()));
+            assert_eq!(
+                RedirectMode::try_from(mode.as_str().to_string()).unwrap(),
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn test_redirect_mode_unknown_value() {
+        assert!(RedirectMode::try_from("http_308".to_string()).is_err());
+        assert!(serde_json::from_str::<RedirectMode>(r#""http302""#).is_err());
+    }
+
+    // ============ InAppBrowserMode 테스트 ============
+
+    #[test]
+    fn test_in_app_browser_mode_default_is_escape() {
+        assert_eq!(InAppBrowserMode::default(), InAppBrowserMode::Escape);
+    }
+
+    #[test]
+    fn test_in_app_browser_mode_serde_matches_as_str() {
+        for mode in [
+            InAppBrowserMode::Escape,
+            InAppBrowserMode::Prompt,
+            InAppBrowserMode::Off,
+        ] {
+            assert_eq!(
+                serde_json::to_string(&mode).unwrap(),
+                format!("\"{}\"", mode.as_str())
+            );
+            assert_eq!(
+                InAppBrowserMode::try_from(mode.as_str().to_string()).unwrap(),
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_app_browser_mode_unknown_value() {
+        assert!(InAppBrowserMode::try_from("block".to_string()).is_err());
+    }
+
     // ============ Url 구조체 테스트 ============
 
     #[test]
@@ -452,6 +1470,218 @@ mod tests {
         assert!(debug_str.contains("UrlCacheData"));
     }
 
+    #[test]
+    fn test_fallback_url_for_platforms() {
+        let cache_data: UrlCacheData = create_test_url().into();
+
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Ios),
+            "https://apps.apple.com"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Android),
+            "https://play.google.com"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Other),
+            "https://example.com"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Windows),
+            "https://example.com"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Bot),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_fallback_url_for_desktop_platforms() {
+        let mut cache_data: UrlCacheData = create_test_url().into();
+        cache_data.macos_fallback_url = Some("https://example.com/mac".to_string());
+        cache_data.windows_fallback_url = Some("https://example.com/windows".to_string());
+        cache_data.linux_fallback_url = Some(String::new());
+
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::MacOs),
+            "https://example.com/mac"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Windows),
+            "https://example.com/windows"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Linux),
+            "https://example.com"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::ChromeOs),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_fallback_url_for_uses_default_when_missing() {
+        let mut cache_data: UrlCacheData = create_minimal_url().into();
+        cache_data.android_fallback_url = Some(String::new());
+
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Ios),
+            "https://minimal.com"
+        );
+        assert_eq!(
+            cache_data.fallback_url_for(Platform::Android),
+            "https://minimal.com"
+        );
+    }
+
+    #[test]
+    fn test_android_intent_url() {
+        let mut cache_data: UrlCacheData = create_test_url().into();
+        assert!(cache_data.android_intent_url().is_none());
+
+        cache_data.android_package = Some("com.example.app".to_string());
+        cache_data.android_scheme = Some("myapp".to_string());
+        cache_data.android_path = Some("product/42".to_string());
+        assert_eq!(
+            cache_data.android_intent_url().as_deref(),
+            Some(
+                "intent://product/42#Intent;scheme=myapp;package=com.example.app;\
+                 S.browser_fallback_url=https%3A%2F%2Fplay.google.com;end"
+            )
+        );
+
+        cache_data.android_path = None;
+        cache_data.android_fallback_url = None;
+        assert_eq!(
+            cache_data.android_intent_url().as_deref(),
+            Some(
+                "intent://#Intent;scheme=myapp;package=com.example.app;\
+                 S.browser_fallback_url=https%3A%2F%2Fexample.com;end"
+            )
+        );
+    }
+
+    #[test]
+    fn test_merge_query_into_destinations() {
+        let mut cache_data: UrlCacheData = create_test_url().into();
+        cache_data.android_package = Some("com.example.app".to_string());
+        cache_data.android_scheme = Some("myapp".to_string());
+        cache_data.default_fallback_url = "https://example.com/?utm_source=site".to_string();
+
+        cache_data.merge_query(
+            &[("utm_source", "mail")],
+            &[("ref".to_string(), "a b".to_string())],
+        );
+
+        assert_eq!(
+            cache_data.ios_deep_link.as_deref(),
+            Some("app://ios?ref=a+b&utm_source=mail")
+        );
+        assert_eq!(
+            cache_data.default_fallback_url,
+            "https://example.com/?utm_source=site&ref=a+b"
+        );
+        assert!(cache_data.macos_fallback_url.is_none());
+        assert!(cache_data
+            .android_intent_url()
+            .unwrap()
+            .starts_with("intent://?ref=a+b&utm_source=mail#Intent;scheme=myapp;"));
+    }
+
+    #[test]
+    fn test_join_path_into_destinations() {
+        let mut cache_data: UrlCacheData = create_test_url().into();
+        cache_data.android_package = Some("com.example.app".to_string());
+        cache_data.android_scheme = Some("myapp".to_string());
+
+        cache_data.join_path("products/42");
+
+        assert_eq!(
+            cache_data.android_deep_link.as_deref(),
+            Some("app://android/products/42")
+        );
+        assert_eq!(
+            cache_data.default_fallback_url,
+            "https://example.com/products/42"
+        );
+        assert_eq!(cache_data.android_path.as_deref(), Some("products/42"));
+        assert_eq!(
+            cache_data.deep_link_for(Platform::Android).as_deref(),
+            Some("myapp://products/42")
+        );
+    }
+
+    #[test]
+    fn test_merge_query_without_parameters_keeps_destinations() {
+        let mut cache_data: UrlCacheData = create_test_url().into();
+        cache_data.merge_query(&[], &[]);
+
+        assert_eq!(cache_data.ios_deep_link.as_deref(), Some("app://ios"));
+        assert_eq!(cache_data.default_fallback_url, "https://example.com");
+        assert!(cache_data.android_path.is_none());
+    }
+
+    #[test]
+    fn test_deep_link_for_platforms() {
+        let mut cache_data: UrlCacheData = create_test_url().into();
+        assert_eq!(
+            cache_data.deep_link_for(Platform::Ios),
+            cache_data.ios_deep_link
+        );
+        assert_eq!(
+            cache_data.deep_link_for(Platform::Android),
+            cache_data.android_deep_link
+        );
+        assert!(cache_data.deep_link_for(Platform::Windows).is_none());
+
+        cache_data.android_scheme = Some("myapp".to_string());
+        cache_data.android_path = Some("product/42".to_string());
+        assert_eq!(
+            cache_data.deep_link_for(Platform::Android).as_deref(),
+            Some("myapp://product/42")
+        );
+
+        cache_data.ios_deep_link = Some(String::new());
+        assert!(cache_data.deep_link_for(Platform::Ios).is_none());
+    }
+
+    #[test]
+    fn test_url_cache_data_is_expired() {
+        let now = chrono::Utc::now();
+        let mut cache_data: UrlCacheData = create_test_url().into();
+
+        assert!(!cache_data.is_expired(now));
+
+        cache_data.expires_at = Some(now + chrono::Duration::minutes(5));
+        assert!(!cache_data.is_expired(now));
+
+        cache_data.expires_at = Some(now);
+        assert!(cache_data.is_expired(now));
+    }
+
+    #[test]
+    fn test_url_cache_data_cache_ttl_secs() {
+        let now = chrono::Utc::now();
+        let mut cache_data: UrlCacheData = create_test_url().into();
+
+        // 만료 시각이 없으면 기본 TTL
+        assert_eq!(cache_data.cache_ttl_secs(3600, now), 3600);
+
+        // 만료가 TTL보다 이르면 남은 시간으로 제한
+        cache_data.expires_at = Some(now + chrono::Duration::seconds(90));
+        assert_eq!(cache_data.cache_ttl_secs(3600, now), 90);
+
+        // 만료가 TTL보다 늦으면 기본 TTL
+        cache_data.expires_at = Some(now + chrono::Duration::days(1));
+        assert_eq!(cache_data.cache_ttl_secs(3600, now), 3600);
+
+        // 이미 만료된 링크는 캐시하지 않음
+        cache_data.expires_at = Some(now - chrono::Duration::seconds(1));
+        assert_eq!(cache_data.cache_ttl_secs(3600, now), 0);
+    }
+
     // ============ NewUrl 구조체 테스트 ============
 
     #[test]
@@ -462,6 +1692,18 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://example.com".to_string(),
             hashed_value: "hash123".to_string(),
             webhook_url: None,
@@ -469,6 +1711,13 @@ mod tests {
             og_description: None,
             og_image_url: None,
             is_active: true,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password_hash: None,
         };
 
         assert_eq!(new_url.random_key, "AbXy");
@@ -483,6 +1732,18 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://test.com".to_string(),
             hashed_value: "testhash".to_string(),
             webhook_url: None,
@@ -490,6 +1751,13 @@ mod tests {
             og_description: None,
             og_image_url: None,
             is_active: false,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password_hash: None,
         };
 
         let cloned = new_url.clone();
@@ -505,6 +1773,18 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://debug.com".to_string(),
             hashed_value: "debughash".to_string(),
             webhook_url: None,
@@ -512,6 +1792,13 @@ mod tests {
             og_description: None,
             og_image_url: None,
             is_active: true,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            alias: None,
+            password_hash: None,
         };
 
         let debug_str = format!("{new_url:?}");
@@ -583,6 +1870,18 @@ mod tests {
             ios_fallback_url: Some(String::new()),
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: String::new(),
             hashed_value: String::new(),
             webhook_url: None,
@@ -593,6 +1892,15 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
         };
 
         let cache_data: UrlCacheData = url.into();
@@ -609,6 +1917,18 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://example.com/한글".to_string(),
             hashed_value: "유니코드해시".to_string(),
             webhook_url: None,
@@ -619,6 +1939,15 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
         };
 
         let json = serde_json::to_string(&url).unwrap();
@@ -637,6 +1966,18 @@ mod tests {
             ios_fallback_url: None,
             android_deep_link: None,
             android_fallback_url: None,
+            android_package: None,
+            android_scheme: None,
+            android_path: None,
+            desktop_deep_link: None,
+            macos_fallback_url: None,
+            windows_fallback_url: None,
+            linux_fallback_url: None,
+            routing_rules: RoutingRules::default(),
+            variants: UrlVariants::default(),
+            utm_params: UtmParams::default(),
+            query_passthrough: false,
+            allow_path_forwarding: false,
             default_fallback_url: "https://large-id.com".to_string(),
             hashed_value: "largeidhash".to_string(),
             webhook_url: None,
@@ -647,6 +1988,15 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
+            owner: "guest".to_string(),
+            redirect_mode: RedirectMode::Interstitial,
+            in_app_browser_mode: InAppBrowserMode::Escape,
+            expires_at: None,
+            max_clicks: None,
+            click_count: 0,
+            alias: None,
+            password_hash: None,
+            webhook_secret: "test-webhook-secret".to_string(),
         };
 
         let cache_data: UrlCacheData = url.into();
@@ -0,0 +1,191 @@
+//! UTM parameter model module.
+//!
+//! Contains the per-link campaign tags (`utm_source`, `utm_medium`, ...)
+//! merged into the destination of every redirect.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Maximum length of a UTM parameter value.
+const MAX_UTM_VALUE_LEN: usize = 255;
+
+/// Default UTM parameters of a link, all unset when it has none.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UtmParams {
+    /// `utm_source`, e.g. `newsletter`
+    #[serde(default)]
+    pub source: Option<String>,
+    /// `utm_medium`, e.g. `email`
+    #[serde(default)]
+    pub medium: Option<String>,
+    /// `utm_campaign`, e.g. `spring_sale`
+    #[serde(default)]
+    pub campaign: Option<String>,
+    /// `utm_term`, the paid search keyword
+    #[serde(default)]
+    pub term: Option<String>,
+    /// `utm_content`, telling apart links of the same campaign
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+impl TryFrom<String> for UtmParams {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        serde_json::from_str(&value)
+            .map_err(|e| AppError::Internal(format!("Invalid UTM parameters: {e}")))
+    }
+}
+
+impl UtmParams {
+    /// Returns whether no parameter is set.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.source.is_none()
+            && self.medium.is_none()
+            && self.campaign.is_none()
+            && self.term.is_none()
+            && self.content.is_none()
+    }
+
+    /// Returns the JSON representation stored in the database and used in the hash.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Returns the set parameters as query parameters, in `utm_*` order.
+    #[must_use]
+    pub fn query_params(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("utm_source", &self.source),
+            ("utm_medium", &self.medium),
+            ("utm_campaign", &self.campaign),
+            ("utm_term", &self.term),
+            ("utm_content", &self.content),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value.as_deref()?)))
+        .collect()
+    }
+
+    /// Validates the parameters and trims their values.
+    ///
+    /// # Validation Rules
+    ///
+    /// - Values are at most 255 characters
+    /// - Blank values unset the parameter
+    pub fn normalize(self) -> Result<Self, AppError> {
+        let normalize = |value: Option<String>| -> Result<Option<String>, AppError> {
+            let Some(value) = value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+            else {
+                return Ok(None);
+            };
+            if value.chars().count() > MAX_UTM_VALUE_LEN {
+                return Err(AppError::Validation(
+                    "UTM parameters must be at most 255 characters".to_string(),
+                ));
+            }
+            Ok(Some(value))
+        };
+
+        Ok(Self {
+            source: normalize(self.source)?,
+            medium: normalize(self.medium)?,
+            campaign: normalize(self.campaign)?,
+            term: normalize(self.term)?,
+            content: normalize(self.content)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn campaign() -> UtmParams {
+        UtmParams {
+            source: Some("newsletter".to_string()),
+            medium: Some("email".to_string()),
+            campaign: Some("spring sale".to_string()),
+            ..UtmParams::default()
+        }
+    }
+
+    // ============ UtmParams::normalize 테스트 ============
+
+    #[test]
+    fn test_normalize_trims_and_drops_blank_values() {
+        let utm = UtmParams {
+            source: Some("  newsletter ".to_string()),
+            medium: Some("   ".to_string()),
+            ..UtmParams::default()
+        }
+        .normalize()
+        .unwrap();
+
+        assert_eq!(utm.source.as_deref(), Some("newsletter"));
+        assert!(utm.medium.is_none());
+        assert!(UtmParams {
+            medium: Some(String::new()),
+            ..UtmParams::default()
+        }
+        .normalize()
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn test_normalize_rejects_long_values() {
+        let utm = UtmParams {
+            term: Some("a".repeat(256)),
+            ..UtmParams::default()
+        };
+        assert!(utm.normalize().is_err());
+
+        let utm = UtmParams {
+            term: Some("가".repeat(255)),
+            ..UtmParams::default()
+        };
+        assert!(utm.normalize().is_ok());
+    }
+
+    // ============ UtmParams::query_params 테스트 ============
+
+    #[test]
+    fn test_query_params_in_order() {
+        assert_eq!(
+            campaign().query_params(),
+            [
+                ("utm_source", "newsletter"),
+                ("utm_medium", "email"),
+                ("utm_campaign", "spring sale"),
+            ]
+        );
+        assert!(UtmParams::default().query_params().is_empty());
+    }
+
+    // ============ 직렬화 테스트 ============
+
+    #[test]
+    fn test_utm_params_json_roundtrip() {
+        let parsed = UtmParams::try_from(campaign().to_json()).unwrap();
+        assert_eq!(parsed, campaign());
+        assert_eq!(
+            UtmParams::try_from("{}".to_string()).unwrap(),
+            UtmParams::default()
+        );
+        assert!(UtmParams::try_from("not json".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_utm_params_msgpack_roundtrip() {
+        let bytes = rmp_serde::to_vec(&campaign()).unwrap();
+        let decoded: UtmParams = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, campaign());
+    }
+}
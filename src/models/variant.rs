@@ -0,0 +1,302 @@
+//! A/B variant model module.
+//!
+//! Contains the weighted destinations a link splits its traffic across,
+//! the deterministic assignment of visitors to them, and the repository
+//! for the `url_variants` child table.
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::error::{AppError, AppResult};
+use crate::models::routing::is_http_url;
+
+/// Maximum number of variants per link.
+pub const MAX_VARIANTS: usize = 10;
+
+/// Sum of the variant weights of a link (weights are percentages).
+pub const TOTAL_VARIANT_WEIGHT: u32 = 100;
+
+/// Maximum length of a variant name.
+const MAX_VARIANT_NAME_LEN: usize = 64;
+
+/// A weighted destination of a split link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlVariant {
+    /// Name recorded with clicks and webhook events
+    pub name: String,
+    /// `http(s)` URL the assigned visitors are sent to
+    pub destination: String,
+    /// Share of the traffic in percent
+    pub weight: u32,
+}
+
+/// Ordered A/B variants of a link, empty when its traffic is not split.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UrlVariants(pub Vec<UrlVariant>);
+
+impl TryFrom<String> for UrlVariants {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        serde_json::from_str(&value)
+            .map_err(|e| AppError::Internal(format!("Invalid variants: {e}")))
+    }
+}
+
+impl UrlVariants {
+    /// Returns whether the link has no variants.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the JSON representation used in the duplicate-detection hash.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Validates the variants, returning the message of the first violation.
+    ///
+    /// # Validation Rules
+    ///
+    /// - Either no variants or 2 to 10 of them
+    /// - Names are unique, 1-64 letters, numbers, `-` or `_`
+    /// - Destinations are `http(s)` URLs of at most 2048 characters
+    /// - Weights are at least 1 and sum to 100
+    pub fn check(&self) -> Result<(), &'static str> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        if !(2..=MAX_VARIANTS).contains(&self.0.len()) {
+            return Err("variants must have 2 to 10 entries");
+        }
+
+        for (index, variant) in self.0.iter().enumerate() {
+            if variant.name.is_empty()
+                || variant.name.len() > MAX_VARIANT_NAME_LEN
+                || !variant
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+            {
+                return Err("Variant names must be 1-64 letters, numbers, '-' or '_'");
+            }
+            if self.0[..index].iter().any(|v| v.name == variant.name) {
+                return Err("Variant names must be unique");
+            }
+            if !is_http_url(&variant.destination) {
+                return Err("Invalid variant destination URL");
+            }
+            if variant.weight == 0 {
+                return Err("Variant weights must be at least 1");
+            }
+        }
+
+        let total: u32 = self.0.iter().map(|v| v.weight).fold(0, u32::saturating_add);
+        if total != TOTAL_VARIANT_WEIGHT {
+            return Err("Variant weights must sum to 100");
+        }
+
+        Ok(())
+    }
+
+    /// Returns the variant a visitor is assigned to.
+    ///
+    /// The link ID and visitor ID are hashed into a bucket from 0 to 99,
+    /// and the variants take consecutive bucket ranges sized by their
+    /// weights, so a visitor keeps the same variant as long as the weights
+    /// do not change. Returns `None` for a link without variants.
+    #[must_use]
+    pub fn assign(&self, url_id: i64, visitor_id: &str) -> Option<&UrlVariant> {
+        let hash = xxh3_64(format!("{url_id}:{visitor_id}").as_bytes());
+        let mut bucket = hash % u64::from(TOTAL_VARIANT_WEIGHT);
+
+        for variant in &self.0 {
+            let weight = u64::from(variant.weight);
+            if bucket < weight {
+                return Some(variant);
+            }
+            bucket -= weight;
+        }
+        self.0.last()
+    }
+}
+
+/// Repository for the `url_variants` table.
+///
+/// Variants are written on the connection of the transaction that writes
+/// their link, and read back as part of the link's columns.
+pub struct VariantRepository;
+
+impl VariantRepository {
+    /// Inserts the variants of newly created links.
+    pub async fn insert_many(
+        conn: &mut PgConnection,
+        links: &[(i64, &UrlVariants)],
+    ) -> AppResult<()> {
+        let rows: Vec<(i64, i32, &UrlVariant)> = links
+            .iter()
+            .flat_map(|(url_id, variants)| {
+                (0..)
+                    .zip(&variants.0)
+                    .map(|(position, variant)| (*url_id, position, variant))
+            })
+            .collect();
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let url_ids: Vec<i64> = rows.iter().map(|(url_id, _, _)| *url_id).collect();
+        let positions: Vec<i32> = rows.iter().map(|(_, position, _)| *position).collect();
+        let names: Vec<&str> = rows.iter().map(|(_, _, v)| v.name.as_str()).collect();
+        let destinations: Vec<&str> = rows
+            .iter()
+            .map(|(_, _, v)| v.destination.as_str())
+            .collect();
+        let weights: Vec<i32> = rows
+            .iter()
+            .map(|(_, _, v)| i32::try_from(v.weight).unwrap_or(i32::MAX))
+            .collect();
+
+        sqlx::query(
+            r"
+            INSERT INTO url_variants (url_id, position, name, destination, weight)
+            SELECT * FROM UNNEST($1::bigint[], $2::int[], $3::varchar[], $4::text[], $5::int[])
+            ",
+        )
+        .bind(&url_ids)
+        .bind(&positions)
+        .bind(&names)
+        .bind(&destinations)
+        .bind(&weights)
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Replaces the variants of a link.
+    pub async fn replace(
+        conn: &mut PgConnection,
+        url_id: i64,
+        variants: &UrlVariants,
+    ) -> AppResult<()> {
+        sqlx::query("DELETE FROM url_variants WHERE url_id = $1")
+            .bind(url_id)
+            .execute(&mut *conn)
+            .await?;
+
+        Self::insert_many(conn, &[(url_id, variants)]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(name: &str, weight: u32) -> UrlVariant {
+        UrlVariant {
+            name: name.to_string(),
+            destination: format!("https://example.com/{name}"),
+            weight,
+        }
+    }
+
+    fn split(weights: &[(&str, u32)]) -> UrlVariants {
+        UrlVariants(weights.iter().map(|(name, w)| variant(name, *w)).collect())
+    }
+
+    // ============ UrlVariants::check 테스트 ============
+
+    #[test]
+    fn test_check_valid_variants() {
+        assert!(UrlVariants::default().check().is_ok());
+        assert!(split(&[("a", 70), ("b", 30)]).check().is_ok());
+        assert!(split(&[("control", 34), ("test-1", 33), ("test_2", 33)])
+            .check()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_check_weights_must_sum_to_100() {
+        assert_eq!(
+            split(&[("a", 70), ("b", 20)]).check(),
+            Err("Variant weights must sum to 100")
+        );
+        assert_eq!(
+            split(&[("a", 100), ("b", 0)]).check(),
+            Err("Variant weights must be at least 1")
+        );
+        assert!(split(&[("a", u32::MAX), ("b", 1)]).check().is_err());
+    }
+
+    #[test]
+    fn test_check_rejects_invalid_entries() {
+        assert!(split(&[("a", 100)]).check().is_err());
+        assert!(split(&[("a", 50), ("a", 50)]).check().is_err());
+        assert!(split(&[("a b", 50), ("c", 50)]).check().is_err());
+        assert!(split(&[("", 50), ("c", 50)]).check().is_err());
+
+        let mut variants = split(&[("a", 50), ("b", 50)]);
+        variants.0[1].destination = "javascript:alert(1)".to_string();
+        assert_eq!(variants.check(), Err("Invalid variant destination URL"));
+
+        let many: Vec<(String, u32)> = (0..11).map(|i| (format!("v{i}"), 1)).collect();
+        let many: Vec<(&str, u32)> = many.iter().map(|(n, w)| (n.as_str(), *w)).collect();
+        assert!(split(&many).check().is_err());
+    }
+
+    // ============ UrlVariants::assign 테스트 ============
+
+    #[test]
+    fn test_assign_is_deterministic() {
+        let variants = split(&[("a", 50), ("b", 50)]);
+        let first = variants.assign(1, "visitor").unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(variants.assign(1, "visitor"), Some(first));
+        }
+        assert!(UrlVariants::default().assign(1, "visitor").is_none());
+    }
+
+    #[test]
+    fn test_assign_follows_weights() {
+        let variants = split(&[("a", 70), ("b", 30)]);
+        let a_count = (0..10_000)
+            .filter(|i| variants.assign(42, &format!("visitor-{i}")).unwrap().name == "a")
+            .count();
+
+        // 70% ± 2%
+        assert!((6800..=7200).contains(&a_count), "{a_count}");
+    }
+
+    #[test]
+    fn test_assign_full_weight_variant() {
+        let variants = split(&[("a", 1), ("b", 99)]);
+        let b_count = (0..1000)
+            .filter(|i| variants.assign(7, &i.to_string()).unwrap().name == "b")
+            .count();
+        assert!(b_count > 950);
+    }
+
+    // ============ 직렬화 테스트 ============
+
+    #[test]
+    fn test_variants_json_roundtrip() {
+        let variants = split(&[("a", 70), ("b", 30)]);
+        let parsed = UrlVariants::try_from(variants.to_json()).unwrap();
+        assert_eq!(parsed, variants);
+        assert!(UrlVariants::try_from("not json".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_variants_msgpack_roundtrip() {
+        let variants = split(&[("a", 70), ("b", 30)]);
+        let bytes = rmp_serde::to_vec(&variants).unwrap();
+        let decoded: UrlVariants = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, variants);
+    }
+}
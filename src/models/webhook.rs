@@ -0,0 +1,791 @@
+//! Webhook model module.
+//!
+//! Contains webhook events, the webhook outbox, its repository, and the
+//! background dispatcher that delivers signed webhook requests with retries.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use serde::Serialize;
+use sha2::Sha256;
+use sqlx::{FromRow, PgExecutor, PgPool, Postgres, QueryBuilder};
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::config::APP_CONFIG;
+use crate::error::{AppError, AppResult};
+use crate::utils::{merge_short_key, InAppBrowser, Platform, UserAgentInfo};
+
+/// Version of the webhook event envelope.
+///
+/// Bumped only for breaking changes; new optional fields keep the version.
+pub const WEBHOOK_EVENT_VERSION: u32 = 1;
+
+/// Header carrying the HMAC-SHA256 signature of the request body.
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Header carrying the delivery ID, stable across retries of the same delivery.
+pub const DELIVERY_ID_HEADER: &str = "X-Webhook-Id";
+
+/// Maximum stored length of the last delivery error (in characters).
+const MAX_ERROR_LEN: usize = 1024;
+
+/// Extra time on top of the request timeout before a claimed delivery
+/// is considered abandoned and picked up again.
+const LEASE_MARGIN_SECS: u64 = 30;
+
+/// Global HTTP client for webhook requests.
+///
+/// Configured with connection pooling and timeouts. Initialized once at first use.
+/// Panics if client creation fails, as this indicates a critical system issue.
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    let mut default_headers = HeaderMap::new();
+    default_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(APP_CONFIG.webhook_timeout_secs))
+        .connect_timeout(Duration::from_secs(5))
+        .pool_max_idle_per_host(10)
+        .pool_idle_timeout(Duration::from_mins(1))
+        .default_headers(default_headers)
+        .build()
+        .expect("Failed to create HTTP client - check reqwest/TLS configuration")
+});
+
+/// Computes the signature header value of a webhook body.
+///
+/// Returns `sha256=<hex digest>` of HMAC-SHA256 over the raw body, keyed
+/// with the link's webhook secret.
+#[must_use]
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Delivery state of an outbox entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first attempt or a retry
+    Pending,
+    /// The endpoint answered with a 2xx status
+    Delivered,
+    /// Every attempt failed (dead letter)
+    Dead,
+}
+
+impl WebhookDeliveryStatus {
+    /// Returns the status as stored in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+/// Type of a webhook event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WebhookEventType {
+    /// A visitor was redirected
+    #[serde(rename = "link.clicked")]
+    Clicked,
+    /// The link was created
+    #[serde(rename = "link.created")]
+    Created,
+    /// The link was updated
+    #[serde(rename = "link.updated")]
+    Updated,
+    /// The link was deleted
+    #[serde(rename = "link.deleted")]
+    Deleted,
+    /// The link passed its expiration time or reached its click limit
+    #[serde(rename = "link.expired")]
+    Expired,
+}
+
+/// Body of a webhook request.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookEvent {
+    /// Envelope version ([`WEBHOOK_EVENT_VERSION`])
+    pub version: u32,
+    /// Unique event ID, for deduplication by receivers
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub event_type: WebhookEventType,
+    /// Time the event happened (RFC 3339)
+    pub timestamp: DateTime<Utc>,
+    pub data: WebhookEventData,
+}
+
+/// Link and visit details of a webhook event.
+///
+/// Visit fields are only present on `link.clicked` events, and only when known.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WebhookEventData {
+    pub short_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// Platform detected from the `User-Agent` header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<Platform>,
+    /// App whose embedded webview made the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_app_browser: Option<InAppBrowser>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referer: Option<String>,
+    /// ISO 3166-1 alpha-2 country code from CDN headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// A/B variant the visitor was sent to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+
+impl WebhookEvent {
+    /// Creates an event about a link, happening now.
+    #[must_use]
+    pub fn new(
+        event_type: WebhookEventType,
+        url_id: i64,
+        random_key: &str,
+        alias: Option<String>,
+    ) -> Self {
+        #[allow(clippy::cast_sign_loss)]
+        let short_key = merge_short_key(random_key, url_id as u64);
+
+        Self {
+            version: WEBHOOK_EVENT_VERSION,
+            id: uuid::Builder::from_random_bytes(rand::random()).into_uuid(),
+            event_type,
+            timestamp: Utc::now(),
+            data: WebhookEventData {
+                short_key,
+                alias,
+                ..WebhookEventData::default()
+            },
+        }
+    }
+
+    /// Creates a `link.clicked` event with the details of the visit.
+    #[must_use]
+    pub fn clicked(
+        url_id: i64,
+        random_key: &str,
+        alias: Option<String>,
+        user_agent: Option<&str>,
+        referer: Option<&str>,
+        country: Option<String>,
+        variant: Option<String>,
+    ) -> Self {
+        let mut event = Self::new(WebhookEventType::Clicked, url_id, random_key, alias);
+        event.data.user_agent = user_agent.map(str::to_string);
+        let client = UserAgentInfo::parse(user_agent.unwrap_or_default());
+        event.data.platform = Some(client.platform);
+        event.data.in_app_browser = client.in_app_browser;
+        event.data.referer = referer.map(str::to_string);
+        event.data.country = country;
+        event.data.variant = variant;
+        event
+    }
+}
+
+/// A webhook request waiting to be written to the outbox.
+#[derive(Debug, Clone)]
+pub struct NewWebhookDelivery {
+    pub url_id: i64,
+    pub webhook_url: String,
+    /// Serialized JSON body
+    pub payload: String,
+}
+
+impl NewWebhookDelivery {
+    /// Creates an outbox entry with `payload` serialized as JSON.
+    pub fn new(url_id: i64, webhook_url: String, payload: &impl Serialize) -> AppResult<Self> {
+        let payload = serde_json::to_string(payload)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize webhook payload: {e}")))?;
+
+        Ok(Self {
+            url_id,
+            webhook_url,
+            payload,
+        })
+    }
+}
+
+/// An outbox entry claimed for delivery.
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub url_id: i64,
+    pub webhook_url: String,
+    /// Request body, exactly as sent and signed
+    pub payload: String,
+    /// Attempts made so far, including the claimed one
+    pub attempts: i32,
+    /// Signing secret of the link
+    pub webhook_secret: String,
+}
+
+/// Exponential backoff schedule for failed deliveries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    /// Attempts made before a delivery is dead-lettered
+    pub max_attempts: u32,
+    /// Delay after the first failed attempt
+    pub base_delay: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl WebhookRetryPolicy {
+    /// Returns the delay before the next attempt after `attempts` failed attempts,
+    /// or `None` once the delivery should be dead-lettered.
+    ///
+    /// The delay doubles with every attempt: `base`, `2 * base`, `4 * base`, ...
+    #[must_use]
+    pub fn next_delay(&self, attempts: u32) -> Option<Duration> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+
+        let factor = 1u32
+            .checked_shl(attempts.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        Some(self.base_delay.saturating_mul(factor).min(self.max_delay))
+    }
+}
+
+/// Webhook outbox repository for database operations.
+pub struct WebhookRepository;
+
+impl WebhookRepository {
+    /// Writes a webhook request to the outbox, due immediately.
+    pub async fn enqueue(
+        executor: impl PgExecutor<'_>,
+        delivery: &NewWebhookDelivery,
+    ) -> AppResult<i64> {
+        let id = sqlx::query_scalar(
+            r"
+            INSERT INTO webhook_deliveries (url_id, webhook_url, payload)
+            VALUES ($1, $2, $3::jsonb)
+            RETURNING id
+            ",
+        )
+        .bind(delivery.url_id)
+        .bind(&delivery.webhook_url)
+        .bind(&delivery.payload)
+        .fetch_one(executor)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Writes several webhook requests to the outbox with a single multi-row `INSERT`.
+    pub async fn enqueue_many(
+        executor: impl PgExecutor<'_>,
+        deliveries: &[NewWebhookDelivery],
+    ) -> AppResult<u64> {
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new("INSERT INTO webhook_deliveries (url_id, webhook_url, payload) ");
+        query.push_values(deliveries, |mut row, delivery| {
+            row.push_bind(delivery.url_id)
+                .push_bind(&delivery.webhook_url)
+                .push_bind(&delivery.payload)
+                .push_unseparated("::jsonb");
+        });
+
+        let result = query.build().execute(executor).await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Queues a `link.expired` event for every newly expired link with a webhook.
+    ///
+    /// Links are marked as reported in the same transaction, so each expiry
+    /// is reported exactly once, even with several dispatchers running.
+    pub async fn enqueue_expired_events(pool: &PgPool) -> AppResult<u64> {
+        let mut tx = pool.begin().await?;
+
+        let expired = sqlx::query_as::<_, ExpiredLink>(
+            r"
+            UPDATE urls
+            SET expired_notified_at = NOW()
+            WHERE expired_notified_at IS NULL
+              AND deleted_at IS NULL
+              AND webhook_url IS NOT NULL
+              AND (expires_at IS NOT NULL OR max_clicks IS NOT NULL)
+              AND webhook_url <> ''
+              AND (expires_at <= NOW() OR click_count >= max_clicks)
+            RETURNING id, random_key, alias, webhook_url
+            ",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let deliveries = expired
+            .into_iter()
+            .map(|link| {
+                let event = WebhookEvent::new(
+                    WebhookEventType::Expired,
+                    link.id,
+                    &link.random_key,
+                    link.alias,
+                );
+                NewWebhookDelivery::new(link.id, link.webhook_url, &event)
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let queued = Self::enqueue_many(&mut *tx, &deliveries).await?;
+        tx.commit().await?;
+
+        Ok(queued)
+    }
+
+    /// Claims up to `limit` due deliveries for an attempt.
+    ///
+    /// Claimed rows count the attempt and are hidden for `lease`, so other
+    /// dispatchers skip them and a crashed attempt is retried afterwards.
+    pub async fn claim_due(
+        pool: &PgPool,
+        limit: usize,
+        lease: Duration,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        let deliveries = sqlx::query_as::<_, WebhookDelivery>(
+            r"
+            UPDATE webhook_deliveries d
+            SET attempts = d.attempts + 1,
+                next_attempt_at = NOW() + $2 * INTERVAL '1 second'
+            FROM urls u
+            WHERE u.id = d.url_id
+              AND d.id IN (
+                  SELECT id FROM webhook_deliveries
+                  WHERE status = 'pending' AND next_attempt_at <= NOW()
+                  ORDER BY next_attempt_at
+                  LIMIT $1
+                  FOR UPDATE SKIP LOCKED
+              )
+            RETURNING d.id, d.url_id, d.webhook_url, d.payload::text AS payload,
+                      d.attempts, u.webhook_secret
+            ",
+        )
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .bind(lease.as_secs_f64())
+        .fetch_all(pool)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    /// Marks a delivery as delivered.
+    pub async fn mark_delivered(pool: &PgPool, id: i64, status_code: u16) -> AppResult<()> {
+        sqlx::query(
+            r"
+            UPDATE webhook_deliveries
+            SET status = $2, last_status_code = $3, last_error = NULL, delivered_at = NOW()
+            WHERE id = $1
+            ",
+        )
+        .bind(id)
+        .bind(WebhookDeliveryStatus::Delivered.as_str())
+        .bind(i32::from(status_code))
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes delivered and dead-lettered deliveries created before `cutoff`.
+    ///
+    /// Pending deliveries are kept. Returns the number of deleted deliveries.
+    pub async fn purge_finished(pool: &PgPool, cutoff: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            DELETE FROM webhook_deliveries
+            WHERE status IN ($1, $2) AND created_at < $3
+            ",
+        )
+        .bind(WebhookDeliveryStatus::Delivered.as_str())
+        .bind(WebhookDeliveryStatus::Dead.as_str())
+        .bind(cutoff)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Records a failed attempt.
+    ///
+    /// The delivery is retried at `retry_at`, or dead-lettered when it is `None`.
+    pub async fn mark_failed(
+        pool: &PgPool,
+        id: i64,
+        retry_at: Option<DateTime<Utc>>,
+        status_code: Option<u16>,
+        error: &str,
+    ) -> AppResult<()> {
+        let status = if retry_at.is_some() {
+            WebhookDeliveryStatus::Pending
+        } else {
+            WebhookDeliveryStatus::Dead
+        };
+
+        sqlx::query(
+            r"
+            UPDATE webhook_deliveries
+            SET status = $2, next_attempt_at = COALESCE($3, next_attempt_at),
+                last_status_code = $4, last_error = $5
+            WHERE id = $1
+            ",
+        )
+        .bind(id)
+        .bind(status.as_str())
+        .bind(retry_at)
+        .bind(status_code.map(i32::from))
+        .bind(error.chars().take(MAX_ERROR_LEN).collect::<String>())
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// A link whose expiry has not been reported yet.
+#[derive(Debug, FromRow)]
+struct ExpiredLink {
+    id: i64,
+    random_key: String,
+    alias: Option<String>,
+    webhook_url: String,
+}
+
+/// A failed delivery attempt.
+#[derive(Debug)]
+struct FailedAttempt {
+    status_code: Option<u16>,
+    error: String,
+}
+
+/// Background dispatcher that delivers due outbox entries.
+pub struct WebhookDispatcher;
+
+impl WebhookDispatcher {
+    /// Spawns the dispatcher loop and returns its task.
+    ///
+    /// Claims at most `batch_size` due deliveries at a time, never runs more
+    /// than `max_concurrent` requests at once, and polls every `poll_interval`
+    /// while the outbox is drained. Every `expiry_sweep_interval` it also
+    /// queues `link.expired` events. Aborting the task is safe: in-flight
+    /// deliveries are retried once their lease expires.
+    pub fn spawn(
+        pool: PgPool,
+        policy: WebhookRetryPolicy,
+        batch_size: usize,
+        poll_interval: Duration,
+        max_concurrent: usize,
+        expiry_sweep_interval: Duration,
+    ) -> JoinHandle<()> {
+        tokio::spawn(run_dispatcher(
+            pool,
+            policy,
+            batch_size.max(1),
+            poll_interval,
+            max_concurrent.max(1),
+            expiry_sweep_interval,
+        ))
+    }
+}
+
+/// Background loop that claims and delivers due webhooks.
+async fn run_dispatcher(
+    pool: PgPool,
+    policy: WebhookRetryPolicy,
+    batch_size: usize,
+    poll_interval: Duration,
+    max_concurrent: usize,
+    expiry_sweep_interval: Duration,
+) {
+    let semaphore = Arc::new(Semaphore::new(max_concurrent));
+    let lease = Duration::from_secs(APP_CONFIG.webhook_timeout_secs + LEASE_MARGIN_SECS);
+    let mut next_sweep = Instant::now();
+
+    loop {
+        if Instant::now() >= next_sweep {
+            if let Err(e) = WebhookRepository::enqueue_expired_events(&pool).await {
+                tracing::error!(error = %e, "Failed to queue link.expired events");
+            }
+            next_sweep = Instant::now() + expiry_sweep_interval;
+        }
+
+        // Only claim what can be sent right away, so leases cover the request alone
+        let capacity = semaphore.available_permits().min(batch_size);
+        if capacity == 0 {
+            tokio::time::sleep(poll_interval).await;
+            continue;
+        }
+
+        let claimed = match WebhookRepository::claim_due(&pool, capacity, lease).await {
+            Ok(claimed) => claimed,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to claim webhook deliveries");
+                Vec::new()
+            }
+        };
+        let drained = claimed.len() < capacity;
+
+        for delivery in claimed {
+            let Ok(permit) = semaphore.clone().acquire_owned().await else {
+                return;
+            };
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                process_delivery(&pool, policy, delivery).await;
+                drop(permit);
+            });
+        }
+
+        if drained {
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+/// Attempts a delivery and records its outcome.
+async fn process_delivery(pool: &PgPool, policy: WebhookRetryPolicy, delivery: WebhookDelivery) {
+    let result = match send_webhook(&delivery).await {
+        Ok(status_code) => WebhookRepository::mark_delivered(pool, delivery.id, status_code).await,
+        Err(failure) => {
+            let attempts = u32::try_from(delivery.attempts).unwrap_or(0);
+            let retry_at = policy
+                .next_delay(attempts)
+                .and_then(|delay| chrono::Duration::from_std(delay).ok())
+                .map(|delay| Utc::now() + delay);
+
+            if retry_at.is_none() {
+                tracing::warn!(
+                    delivery_id = delivery.id,
+                    url_id = delivery.url_id,
+                    attempts = attempts,
+                    error = %failure.error,
+                    "Webhook delivery dead-lettered"
+                );
+            }
+
+            WebhookRepository::mark_failed(
+                pool,
+                delivery.id,
+                retry_at,
+                failure.status_code,
+                &failure.error,
+            )
+            .await
+        }
+    };
+
+    if let Err(e) = result {
+        tracing::error!(
+            delivery_id = delivery.id,
+            error = %e,
+            "Failed to record webhook delivery result"
+        );
+    }
+}
+
+/// Sends a signed webhook request, returning the status code of a 2xx response.
+async fn send_webhook(delivery: &WebhookDelivery) -> Result<u16, FailedAttempt> {
+    // Content-Type header is pre-configured in HTTP_CLIENT
+    let response = HTTP_CLIENT
+        .post(&delivery.webhook_url)
+        .header(DELIVERY_ID_HEADER, delivery.id)
+        .header(
+            SIGNATURE_HEADER,
+            sign_payload(&delivery.webhook_secret, delivery.payload.as_bytes()),
+        )
+        .body(delivery.payload.clone())
+        .send()
+        .await
+        .map_err(|e| FailedAttempt {
+            status_code: None,
+            error: e.to_string(),
+        })?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(status.as_u16())
+    } else {
+        Err(FailedAttempt {
+            status_code: Some(status.as_u16()),
+            error: format!("Webhook returned non-success status {status}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> WebhookRetryPolicy {
+        WebhookRetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(30),
+            max_delay: Duration::from_mins(5),
+        }
+    }
+
+    // ============ sign_payload 테스트 ============
+
+    #[test]
+    fn test_sign_payload_rfc4231_vector() {
+        // RFC 4231 Test Case 2
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_sign_payload_depends_on_secret_and_body() {
+        let body = br#"{"short_key":"Ab3D7Xy"}"#;
+        let signature = sign_payload("secret-a", body);
+
+        assert_eq!(signature, sign_payload("secret-a", body));
+        assert_ne!(signature, sign_payload("secret-b", body));
+        assert_ne!(signature, sign_payload("secret-a", b"{}"));
+    }
+
+    // ============ WebhookRetryPolicy 테스트 ============
+
+    #[test]
+    fn test_retry_policy_exponential_backoff() {
+        let policy = policy();
+
+        assert_eq!(policy.next_delay(1), Some(Duration::from_secs(30)));
+        assert_eq!(policy.next_delay(2), Some(Duration::from_mins(1)));
+        assert_eq!(policy.next_delay(3), Some(Duration::from_mins(2)));
+        assert_eq!(policy.next_delay(4), Some(Duration::from_mins(4)));
+    }
+
+    #[test]
+    fn test_retry_policy_caps_delay() {
+        let policy = WebhookRetryPolicy {
+            max_attempts: 100,
+            ..policy()
+        };
+
+        assert_eq!(policy.next_delay(5), Some(Duration::from_mins(5)));
+        assert_eq!(policy.next_delay(60), Some(Duration::from_mins(5)));
+    }
+
+    #[test]
+    fn test_retry_policy_dead_letters_after_max_attempts() {
+        let policy = policy();
+
+        assert!(policy.next_delay(5).is_none());
+        assert!(policy.next_delay(6).is_none());
+    }
+
+    // ============ WebhookEvent 테스트 ============
+
+    #[test]
+    fn test_webhook_event_envelope() {
+        let event = WebhookEvent::new(WebhookEventType::Created, 12345, "AbXy", None);
+        let json = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(json["version"], WEBHOOK_EVENT_VERSION);
+        assert_eq!(json["type"], "link.created");
+        assert_eq!(json["id"].as_str().unwrap().len(), 36);
+        assert!(json["timestamp"].as_str().unwrap().ends_with('Z'));
+        assert_eq!(json["data"]["short_key"], merge_short_key("AbXy", 12345));
+        // 알 수 없는 값은 생략됨
+        assert!(json["data"].get("alias").is_none());
+        assert!(json["data"].get("platform").is_none());
+    }
+
+    #[test]
+    fn test_webhook_event_ids_are_unique() {
+        let a = WebhookEvent::new(WebhookEventType::Updated, 1, "AbXy", None);
+        let b = WebhookEvent::new(WebhookEventType::Updated, 1, "AbXy", None);
+
+        assert_ne!(a.id, b.id);
+        assert_eq!(a.id.get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_webhook_event_clicked_includes_visit() {
+        let event = WebhookEvent::clicked(
+            1,
+            "AbXy",
+            Some("summer-sale".to_string()),
+            Some("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) KAKAOTALK 10.4.0"),
+            Some("https://news.example.com/"),
+            Some("KR".to_string()),
+            Some("b".to_string()),
+        );
+        let json = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(json["type"], "link.clicked");
+        assert_eq!(json["data"]["alias"], "summer-sale");
+        assert_eq!(json["data"]["platform"], "ios");
+        assert_eq!(json["data"]["in_app_browser"], "kakaotalk");
+        assert_eq!(json["data"]["referer"], "https://news.example.com/");
+        assert_eq!(json["data"]["country"], "KR");
+        assert_eq!(json["data"]["variant"], "b");
+    }
+
+    #[test]
+    fn test_webhook_event_type_names() {
+        let names: Vec<String> = [
+            WebhookEventType::Clicked,
+            WebhookEventType::Created,
+            WebhookEventType::Updated,
+            WebhookEventType::Deleted,
+            WebhookEventType::Expired,
+        ]
+        .iter()
+        .map(|t| serde_json::to_string(t).unwrap())
+        .collect();
+
+        assert_eq!(
+            names,
+            [
+                r#""link.clicked""#,
+                r#""link.created""#,
+                r#""link.updated""#,
+                r#""link.deleted""#,
+                r#""link.expired""#
+            ]
+        );
+    }
+
+    // ============ NewWebhookDelivery 테스트 ============
+
+    #[test]
+    fn test_new_webhook_delivery_serializes_payload() {
+        let event = WebhookEvent::new(WebhookEventType::Deleted, 7, "AbXy", None);
+        let delivery =
+            NewWebhookDelivery::new(7, "https://hook.example.com".to_string(), &event).unwrap();
+
+        assert_eq!(delivery.url_id, 7);
+        assert_eq!(delivery.payload, serde_json::to_string(&event).unwrap());
+    }
+
+    #[test]
+    fn test_webhook_delivery_status_as_str() {
+        assert_eq!(WebhookDeliveryStatus::Pending.as_str(), "pending");
+        assert_eq!(WebhookDeliveryStatus::Delivered.as_str(), "delivered");
+        assert_eq!(WebhookDeliveryStatus::Dead.as_str(), "dead");
+    }
+}
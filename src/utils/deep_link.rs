@@ -0,0 +1,167 @@
+//! Deep link URL builder module.
+//!
+//! Builds the URLs used to hand a visit over to another app: Android
+//! `intent://` URLs and the escape hatches of in-app browsers.
+
+use url::form_urlencoded;
+
+use crate::utils::{InAppBrowser, Platform};
+
+/// Package of Chrome for Android, which opens escaped web links.
+const CHROME_PACKAGE: &str = "com.android.chrome";
+
+/// Percent-encodes a value for use inside a URL component.
+fn encode_component(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// Builds an Android `intent://` URL that opens `target`.
+///
+/// `target` is an absolute URL (`scheme://rest`); its fragment is dropped,
+/// since the intent parameters take its place. When the intent cannot be
+/// resolved (app not installed), Chrome and most webviews navigate to
+/// `fallback_url` instead.
+///
+/// Returns `None` if `target` has no scheme.
+#[must_use]
+pub fn android_intent_url(
+    target: &str,
+    package: Option<&str>,
+    fallback_url: Option<&str>,
+) -> Option<String> {
+    let (scheme, rest) = target.split_once("://")?;
+    if scheme.is_empty() {
+        return None;
+    }
+    let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+
+    let mut intent = format!("intent://{rest}#Intent;scheme={scheme};");
+    if let Some(package) = package {
+        intent.push_str("package=");
+        intent.push_str(package);
+        intent.push(';');
+    }
+    if let Some(fallback_url) = fallback_url {
+        intent.push_str("S.browser_fallback_url=");
+        intent.push_str(&encode_component(fallback_url));
+        intent.push(';');
+    }
+    intent.push_str("end");
+    Some(intent)
+}
+
+/// Returns the URL that reopens `target` outside of an in-app browser.
+///
+/// - [`InAppBrowser::KakaoTalk`] (any platform): the `kakaotalk://web/openExternal` scheme
+/// - Other apps on Android: an `intent://` URL opening Chrome, falling
+///   back to `fallback_url` inside the webview when Chrome is missing
+///
+/// Returns `None` when the app offers no way out (e.g. Instagram on iOS),
+/// so the visitor has to use the app's "open in browser" menu.
+#[must_use]
+pub fn in_app_escape_url(
+    browser: InAppBrowser,
+    platform: Platform,
+    target: &str,
+    fallback_url: &str,
+) -> Option<String> {
+    match (browser, platform) {
+        (InAppBrowser::KakaoTalk, _) => Some(format!(
+            "kakaotalk://web/openExternal?url={}",
+            encode_component(target)
+        )),
+        (_, Platform::Android) => {
+            android_intent_url(target, Some(CHROME_PACKAGE), Some(fallback_url))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ android_intent_url 테스트 ============
+
+    #[test]
+    fn test_android_intent_url_with_package_and_fallback() {
+        let intent = android_intent_url(
+            "myapp://product/42?ref=short",
+            Some("com.example.app"),
+            Some("https://example.com/p?id=42"),
+        );
+
+        assert_eq!(
+            intent.as_deref(),
+            Some(
+                "intent://product/42?ref=short#Intent;scheme=myapp;package=com.example.app;\
+                 S.browser_fallback_url=https%3A%2F%2Fexample.com%2Fp%3Fid%3D42;end"
+            )
+        );
+    }
+
+    #[test]
+    fn test_android_intent_url_minimal() {
+        assert_eq!(
+            android_intent_url("https://sho.rt/Ab3D", None, None).as_deref(),
+            Some("intent://sho.rt/Ab3D#Intent;scheme=https;end")
+        );
+    }
+
+    #[test]
+    fn test_android_intent_url_drops_fragment() {
+        assert_eq!(
+            android_intent_url("myapp://home#top", None, None).as_deref(),
+            Some("intent://home#Intent;scheme=myapp;end")
+        );
+    }
+
+    #[test]
+    fn test_android_intent_url_requires_scheme() {
+        assert!(android_intent_url("example.com/path", None, None).is_none());
+        assert!(android_intent_url("://example.com", None, None).is_none());
+    }
+
+    // ============ in_app_escape_url 테스트 ============
+
+    const TARGET: &str = "https://sho.rt/Ab3D";
+    const FALLBACK: &str = "https://sho.rt/Ab3D?stayInApp=1";
+
+    #[test]
+    fn test_in_app_escape_url_kakaotalk_uses_external_scheme() {
+        for platform in [Platform::Ios, Platform::Android] {
+            assert_eq!(
+                in_app_escape_url(InAppBrowser::KakaoTalk, platform, TARGET, FALLBACK).as_deref(),
+                Some("kakaotalk://web/openExternal?url=https%3A%2F%2Fsho.rt%2FAb3D")
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_app_escape_url_android_opens_chrome() {
+        for browser in [
+            InAppBrowser::Instagram,
+            InAppBrowser::Facebook,
+            InAppBrowser::Line,
+        ] {
+            assert_eq!(
+                in_app_escape_url(browser, Platform::Android, TARGET, FALLBACK).as_deref(),
+                Some(
+                    "intent://sho.rt/Ab3D#Intent;scheme=https;package=com.android.chrome;\
+                     S.browser_fallback_url=https%3A%2F%2Fsho.rt%2FAb3D%3FstayInApp%3D1;end"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_app_escape_url_ios_has_no_escape() {
+        for browser in [
+            InAppBrowser::Instagram,
+            InAppBrowser::Facebook,
+            InAppBrowser::Line,
+        ] {
+            assert!(in_app_escape_url(browser, Platform::Ios, TARGET, FALLBACK).is_none());
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Client IP address utilities.
+//!
+//! Resolves the client address behind trusted reverse proxies and truncates
+//! it to a network prefix before it is stored. Also reads the client country
+//! set by CDNs and geo-IP proxies.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use axum::http::HeaderMap;
+use once_cell::sync::Lazy;
+
+use crate::config::APP_CONFIG;
+
+/// Prefix length kept for IPv4 addresses.
+const IPV4_PREFIX_LEN: u8 = 24;
+
+/// Prefix length kept for IPv6 addresses.
+const IPV6_PREFIX_LEN: u8 = 48;
+
+/// Headers carrying the client country code, in order of precedence.
+const COUNTRY_HEADERS: [&str; 3] = [
+    "cf-ipcountry",
+    "cloudfront-viewer-country",
+    "x-country-code",
+];
+
+/// Reverse proxies configured in `TRUSTED_PROXIES`.
+static TRUSTED_PROXIES: Lazy<TrustedProxies> =
+    Lazy::new(|| TrustedProxies::parse(&APP_CONFIG.trusted_proxies));
+
+/// Addresses and networks of the reverse proxies whose forwarded headers are trusted.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    networks: Vec<(IpAddr, u8)>,
+}
+
+impl TrustedProxies {
+    /// Parses comma-separated addresses and CIDR ranges (e.g. `10.0.0.0/8, 192.0.2.1`).
+    ///
+    /// Invalid entries are logged and skipped.
+    #[must_use]
+    pub fn parse(list: &str) -> Self {
+        let networks = list
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| {
+                let network = parse_network(entry);
+                if network.is_none() {
+                    tracing::warn!(entry, "Ignoring invalid trusted proxy");
+                }
+                network
+            })
+            .collect();
+
+        Self { networks }
+    }
+
+    /// Returns whether `ip` belongs to a trusted proxy.
+    #[must_use]
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.networks
+            .iter()
+            .any(|&(network, prefix_len)| in_network(ip, network, prefix_len))
+    }
+}
+
+/// Parses an address or CIDR range into its network address and prefix length.
+fn parse_network(entry: &str) -> Option<(IpAddr, u8)> {
+    let (addr, prefix_len) = match entry.split_once('/') {
+        Some((addr, prefix_len)) => (addr, Some(prefix_len.parse::<u8>().ok()?)),
+        None => (entry, None),
+    };
+    let addr = addr.parse::<IpAddr>().ok()?.to_canonical();
+    let max_len = if addr.is_ipv4() { 32 } else { 128 };
+    let prefix_len = prefix_len.unwrap_or(max_len);
+
+    (prefix_len <= max_len).then_some((addr, prefix_len))
+}
+
+/// Returns whether `ip` lies within `network/prefix_len`.
+fn in_network(ip: IpAddr, network: IpAddr, prefix_len: u8) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX
+                .checked_shl(32 - u32::from(prefix_len))
+                .unwrap_or(0);
+            u32::from(ip) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX
+                .checked_shl(128 - u32::from(prefix_len))
+                .unwrap_or(0);
+            u128::from(ip) & mask == u128::from(network) & mask
+        }
+        _ => false,
+    }
+}
+
+/// Resolves the client IP address.
+///
+/// `X-Forwarded-For` and `X-Real-IP` are set by the client itself unless a
+/// proxy overwrites them, so they are only read when the peer of the
+/// connection is one of the `TRUSTED_PROXIES`; otherwise the peer address
+/// is the client.
+#[must_use]
+pub fn client_ip(headers: &HeaderMap, peer: Option<IpAddr>) -> Option<IpAddr> {
+    resolve_client_ip(headers, peer, &TRUSTED_PROXIES)
+}
+
+/// Resolves the client IP address behind the given trusted proxies.
+///
+/// `X-Forwarded-For` is read from the nearest hop backwards, skipping
+/// trusted proxies, so entries the client prepended are never used.
+fn resolve_client_ip(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trusted: &TrustedProxies,
+) -> Option<IpAddr> {
+    let peer = peer?;
+    if !trusted.contains(peer) {
+        return Some(peer);
+    }
+
+    forwarded_client(headers, trusted)
+        .or_else(|| {
+            headers
+                .get("x-real-ip")
+                .and_then(|h| h.to_str().ok())
+                .and_then(|s| s.trim().parse().ok())
+        })
+        .or(Some(peer))
+}
+
+/// Returns the nearest `X-Forwarded-For` entry that is not a trusted proxy.
+///
+/// Ignores the header when any entry is not an IP address.
+fn forwarded_client(headers: &HeaderMap, trusted: &TrustedProxies) -> Option<IpAddr> {
+    let chain: Vec<IpAddr> = headers
+        .get("x-forwarded-for")
+        .and_then(|h| h.to_str().ok())?
+        .split(',')
+        .map(|s| s.trim().parse().ok())
+        .collect::<Option<_>>()?;
+
+    chain
+        .iter()
+        .rev()
+        .find(|ip| !trusted.contains(**ip))
+        .or_else(|| chain.first())
+        .copied()
+}
+
+/// Truncates an IP address to its network prefix (`/24` for IPv4, `/48` for IPv6).
+///
+/// Returns the prefix in CIDR notation (e.g. `203.0.113.0/24`).
+#[must_use]
+pub fn truncate_ip(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(v4) => {
+            let mask = u32::MAX << (32 - IPV4_PREFIX_LEN);
+            let network = Ipv4Addr::from(u32::from(v4) & mask);
+            format!("{network}/{IPV4_PREFIX_LEN}")
+        }
+        IpAddr::V6(v6) => {
+            // Report IPv4-mapped addresses as plain IPv4
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return truncate_ip(IpAddr::V4(v4));
+            }
+            let mask = u128::MAX << (128 - IPV6_PREFIX_LEN);
+            let network = Ipv6Addr::from(u128::from(v6) & mask);
+            format!("{network}/{IPV6_PREFIX_LEN}")
+        }
+    }
+}
+
+/// Resolves the client country from CDN / proxy headers.
+///
+/// Returns the uppercase ISO 3166-1 alpha-2 code, or `None` when no header
+/// carries one. Placeholders such as Cloudflare's `XX` (unknown) and `T1`
+/// (Tor) are ignored.
+#[must_use]
+pub fn client_country(headers: &HeaderMap) -> Option<String> {
+    COUNTRY_HEADERS
+        .iter()
+        .filter_map(|name| headers.get(*name).and_then(|h| h.to_str().ok()))
+        .map(str::trim)
+        .find(|code| {
+            code.len() == 2
+                && code.bytes().all(|b| b.is_ascii_alphabetic())
+                && !code.eq_ignore_ascii_case("xx")
+        })
+        .map(str::to_ascii_uppercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    // ============ TrustedProxies 테스트 ============
+
+    #[test]
+    fn test_trusted_proxies_parse() {
+        let trusted =
+            TrustedProxies::parse("10.0.0.0/8, 192.0.2.1, 2001:db8::/32, bogus, 1.2.3.4/40");
+
+        assert!(trusted.contains("10.20.30.40".parse().unwrap()));
+        assert!(trusted.contains("192.0.2.1".parse().unwrap()));
+        assert!(!trusted.contains("192.0.2.2".parse().unwrap()));
+        assert!(trusted.contains("2001:db8:1::1".parse().unwrap()));
+        // 잘못된 항목은 무시
+        assert_eq!(trusted.networks.len(), 3);
+    }
+
+    #[test]
+    fn test_trusted_proxies_ipv4_mapped_peer() {
+        let trusted = TrustedProxies::parse("10.0.0.0/8");
+        assert!(trusted.contains("::ffff:10.0.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_trusted_proxies_empty() {
+        let trusted = TrustedProxies::parse("");
+        assert!(!trusted.contains("10.0.0.1".parse().unwrap()));
+    }
+
+    // ============ client_ip 함수 테스트 ============
+
+    fn trusted() -> TrustedProxies {
+        TrustedProxies::parse("10.0.0.0/8")
+    }
+
+    #[test]
+    fn test_client_ip_from_forwarded_for() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("203.0.113.7, 10.0.0.1"),
+        );
+
+        let ip = resolve_client_ip(&headers, Some("10.0.0.2".parse().unwrap()), &trusted());
+        assert_eq!(ip, Some("203.0.113.7".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_client_ip_skips_spoofed_forwarded_for() {
+        // 클라이언트가 앞에 붙인 주소가 아니라 프록시가 추가한 주소를 사용
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("198.51.100.1, 203.0.113.7"),
+        );
+
+        let ip = resolve_client_ip(&headers, Some("10.0.0.2".parse().unwrap()), &trusted());
+        assert_eq!(ip, Some("203.0.113.7".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_client_ip_ignores_headers_from_untrusted_peer() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_static("203.0.113.7"));
+        headers.insert("x-real-ip", HeaderValue::from_static("198.51.100.4"));
+
+        let peer: IpAddr = "192.0.2.1".parse().unwrap();
+        assert_eq!(
+            resolve_client_ip(&headers, Some(peer), &trusted()),
+            Some(peer)
+        );
+    }
+
+    #[test]
+    fn test_client_ip_from_real_ip() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-real-ip", HeaderValue::from_static("198.51.100.4"));
+
+        let ip = resolve_client_ip(&headers, Some("10.0.0.2".parse().unwrap()), &trusted());
+        assert_eq!(ip, Some("198.51.100.4".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_client_ip_invalid_header_falls_back_to_peer() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_static("not-an-ip"));
+
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+        assert_eq!(
+            resolve_client_ip(&headers, Some(peer), &trusted()),
+            Some(peer)
+        );
+    }
+
+    #[test]
+    fn test_client_ip_none() {
+        assert_eq!(client_ip(&HeaderMap::new(), None), None);
+    }
+
+    // ============ truncate_ip 함수 테스트 ============
+
+    #[test]
+    fn test_truncate_ipv4() {
+        let ip: IpAddr = "203.0.113.77".parse().unwrap();
+        assert_eq!(truncate_ip(ip), "203.0.113.0/24");
+    }
+
+    #[test]
+    fn test_truncate_ipv6() {
+        let ip: IpAddr = "2001:db8:abcd:1234::1".parse().unwrap();
+        assert_eq!(truncate_ip(ip), "2001:db8:abcd::/48");
+    }
+
+    #[test]
+    fn test_truncate_ipv4_mapped_ipv6() {
+        let ip: IpAddr = "::ffff:198.51.100.9".parse().unwrap();
+        assert_eq!(truncate_ip(ip), "198.51.100.0/24");
+    }
+
+    // ============ client_country 함수 테스트 ============
+
+    #[test]
+    fn test_client_country_from_cloudflare() {
+        let mut headers = HeaderMap::new();
+        headers.insert("cf-ipcountry", HeaderValue::from_static("kr"));
+
+        assert_eq!(client_country(&headers), Some("KR".to_string()));
+    }
+
+    #[test]
+    fn test_client_country_header_precedence() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-country-code", HeaderValue::from_static("US"));
+        headers.insert("cloudfront-viewer-country", HeaderValue::from_static("JP"));
+
+        assert_eq!(client_country(&headers), Some("JP".to_string()));
+    }
+
+    #[test]
+    fn test_client_country_ignores_placeholders() {
+        let mut headers = HeaderMap::new();
+        headers.insert("cf-ipcountry", HeaderValue::from_static("XX"));
+        assert!(client_country(&headers).is_none());
+
+        // Tor(T1)은 국가 코드가 아님
+        headers.insert("cf-ipcountry", HeaderValue::from_static("T1"));
+        assert!(client_country(&headers).is_none());
+
+        headers.insert("x-country-code", HeaderValue::from_static("DE"));
+        assert_eq!(client_country(&headers), Some("DE".to_string()));
+    }
+
+    #[test]
+    fn test_client_country_none() {
+        assert!(client_country(&HeaderMap::new()).is_none());
+    }
+}
@@ -4,7 +4,7 @@
 
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use once_cell::sync::Lazy;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::config::get_env;
 use crate::error::AppResult;
@@ -12,6 +12,12 @@ use crate::error::AppResult;
 /// Minimum recommended length for JWT secrets.
 const MIN_SECRET_LENGTH: usize = 32;
 
+/// Subject prefix of tokens issued to anonymous visitors of the index page.
+pub const GUEST_SUBJECT_PREFIX: &str = "guest-";
+
+/// Subject shared by the guest tokens issued before per-visitor subjects.
+const LEGACY_GUEST_SUBJECT: &str = "guest";
+
 /// JWT signing secret loaded from environment.
 ///
 /// # Security Behavior
@@ -56,8 +62,49 @@ static JWT_EXPIRATION: Lazy<i64> = Lazy::new(|| {
         .unwrap_or(24)
 });
 
+/// Permission granted by a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scope {
+    /// Create links
+    #[serde(rename = "urls:create")]
+    UrlsCreate,
+    /// List, read, update and delete the subject's own links
+    #[serde(rename = "urls:manage")]
+    UrlsManage,
+    /// Admin API (all owners' links, takedowns, maintenance); implies every other scope
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Scope {
+    /// Scopes of the guest tokens issued by the main page.
+    pub const GUEST: &'static [Self] = &[Self::UrlsCreate, Self::UrlsManage];
+
+    /// Returns the claim representation of the scope.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::UrlsCreate => "urls:create",
+            Self::UrlsManage => "urls:manage",
+            Self::Admin => "admin",
+        }
+    }
+
+    /// Parses the claim representation of a scope.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "urls:create" => Some(Self::UrlsCreate),
+            "urls:manage" => Some(Self::UrlsManage),
+            "admin" => Some(Self::Admin),
+            _ => None,
+        }
+    }
+}
+
 /// JWT claims structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawClaims")]
 pub struct Claims {
     /// Subject (user identifier)
     pub sub: String,
@@ -65,11 +112,70 @@ pub struct Claims {
     pub exp: i64,
     /// Issued at (Unix timestamp)
     pub iat: i64,
+    /// Granted scopes
+    pub scopes: Vec<Scope>,
+}
+
+/// Claims as found in a token, telling a missing `scopes` claim from an empty one.
+#[derive(Deserialize)]
+struct RawClaims {
+    sub: String,
+    exp: i64,
+    iat: i64,
+    #[serde(default, deserialize_with = "deserialize_scopes")]
+    scopes: Option<Vec<Scope>>,
+}
+
+impl From<RawClaims> for Claims {
+    /// Tokens issued before scopes existed carry no `scopes` claim; all of
+    /// them were guest tokens, so guest subjects keep the guest scopes and
+    /// any other subject gets none.
+    fn from(raw: RawClaims) -> Self {
+        let scopes = raw.scopes.unwrap_or_else(|| {
+            if raw.sub == LEGACY_GUEST_SUBJECT || raw.sub.starts_with(GUEST_SUBJECT_PREFIX) {
+                Scope::GUEST.to_vec()
+            } else {
+                Vec::new()
+            }
+        });
+
+        Self {
+            sub: raw.sub,
+            exp: raw.exp,
+            iat: raw.iat,
+            scopes,
+        }
+    }
+}
+
+impl Claims {
+    /// Returns whether the token grants a scope; `admin` grants every scope.
+    #[must_use]
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scopes
+            .iter()
+            .any(|&granted| granted == scope || granted == Scope::Admin)
+    }
+}
+
+/// Deserializes the `scopes` claim, skipping scopes this service does not
+/// know so tokens shared with other services stay valid.
+fn deserialize_scopes<'de, D>(deserializer: D) -> Result<Option<Vec<Scope>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let scopes = Vec::<String>::deserialize(deserializer)?;
+    Ok(Some(
+        scopes
+            .iter()
+            .filter_map(|scope| Scope::parse(scope))
+            .collect(),
+    ))
 }
 
-/// Generates a JWT token for the given subject.
+/// Generates a JWT token for the given subject and scopes.
 #[must_use = "the generated token should be used"]
-pub fn gen_token(subject: &str) -> AppResult<String> {
+pub fn gen_token(subject: &str, scopes: &[Scope]) -> AppResult<String> {
     let now = chrono::Utc::now().timestamp();
     let exp = now + (*JWT_EXPIRATION * 3600);
 
@@ -77,6 +183,7 @@ pub fn gen_token(subject: &str) -> AppResult<String> {
         sub: subject.to_string(),
         exp,
         iat: now,
+        scopes: scopes.to_vec(),
     };
 
     let token = encode(
@@ -101,27 +208,32 @@ pub fn parse_token(token: &str) -> AppResult<Claims> {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::unreadable_literal,
+    clippy::needless_collect,
+    clippy::redundant_clone
+)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_gen_and_parse_token() {
         let subject = "test_user";
-        let token = gen_token(subject).expect("Failed to generate token");
+        let token = gen_token(subject, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, subject);
     }
 
     #[test]
     fn test_gen_token_guest() {
-        let token = gen_token("guest").expect("Failed to generate token");
+        let token = gen_token("guest", Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, "guest");
     }
 
     #[test]
     fn test_gen_token_empty_subject() {
-        let token = gen_token("").expect("Failed to generate token");
+        let token = gen_token("", Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, "");
     }
@@ -129,7 +241,7 @@ mod tests {
     #[test]
     fn test_gen_token_unicode_subject() {
         let subject = "사용자_テスト_🚀";
-        let token = gen_token(subject).expect("Failed to generate token");
+        let token = gen_token(subject, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, subject);
     }
@@ -137,14 +249,14 @@ mod tests {
     #[test]
     fn test_gen_token_long_subject() {
         let subject = "a".repeat(1000);
-        let token = gen_token(&subject).expect("Failed to generate token");
+        let token = gen_token(&subject, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, subject);
     }
 
     #[test]
     fn test_claims_exp_is_future() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         let now = chrono::Utc::now().timestamp();
         assert!(claims.exp > now);
@@ -152,7 +264,7 @@ mod tests {
 
     #[test]
     fn test_claims_iat_is_past_or_now() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         let now = chrono::Utc::now().timestamp();
         assert!(claims.iat <= now);
@@ -184,15 +296,15 @@ mod tests {
 
     #[test]
     fn test_token_uniqueness() {
-        let token1 = gen_token("user1").expect("Failed to generate token");
-        let token2 = gen_token("user2").expect("Failed to generate token");
+        let token1 = gen_token("user1", Scope::GUEST).expect("Failed to generate token");
+        let token2 = gen_token("user2", Scope::GUEST).expect("Failed to generate token");
         assert_ne!(token1, token2);
     }
 
     #[test]
     fn test_same_subject_different_tokens() {
         // 동일 subject라도 iat가 다를 수 있어 토큰이 다를 수 있음
-        let token1 = gen_token("same_user").expect("Failed to generate token");
+        let token1 = gen_token("same_user", Scope::GUEST).expect("Failed to generate token");
         let claims1 = parse_token(&token1).expect("Failed to parse token");
         let claims2 = parse_token(&token1).expect("Failed to parse token");
         // 같은 토큰을 파싱하면 같은 클레임
@@ -203,7 +315,7 @@ mod tests {
     #[test]
     fn test_special_characters_in_subject() {
         let special_chars = "!@#$%^&*()_+-=[]{}|;':\",./<>?`~";
-        let token = gen_token(special_chars).expect("Failed to generate token");
+        let token = gen_token(special_chars, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, special_chars);
     }
@@ -216,6 +328,7 @@ mod tests {
             sub: "test".to_string(),
             exp: 9999999999,
             iat: 1000000000,
+            scopes: vec![Scope::Admin],
         };
         let cloned = claims.clone();
         assert_eq!(claims.sub, cloned.sub);
@@ -229,6 +342,7 @@ mod tests {
             sub: "debug_test".to_string(),
             exp: 123456,
             iat: 654321,
+            scopes: Vec::new(),
         };
         let debug_str = format!("{claims:?}");
         assert!(debug_str.contains("Claims"));
@@ -241,6 +355,7 @@ mod tests {
             sub: "serialize_test".to_string(),
             exp: 1234567890,
             iat: 1234567800,
+            scopes: Vec::new(),
         };
         let json = serde_json::to_string(&claims).unwrap();
         assert!(json.contains("serialize_test"));
@@ -259,14 +374,14 @@ mod tests {
 
     #[test]
     fn test_token_has_three_parts() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         let parts: Vec<&str> = token.split('.').collect();
         assert_eq!(parts.len(), 3, "JWT should have 3 parts separated by '.'");
     }
 
     #[test]
     fn test_token_parts_not_empty() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         for part in token.split('.') {
             assert!(!part.is_empty(), "JWT part should not be empty");
         }
@@ -274,7 +389,7 @@ mod tests {
 
     #[test]
     fn test_token_is_base64_like() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         // JWT 파트는 Base64URL 인코딩 (alphanumeric + - + _)
         for part in token.split('.') {
             assert!(
@@ -289,7 +404,7 @@ mod tests {
 
     #[test]
     fn test_parse_token_tampered() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         // 토큰의 마지막 문자 변경 (서명 변조)
         let mut tampered = token.clone();
         tampered.push('x');
@@ -298,7 +413,7 @@ mod tests {
 
     #[test]
     fn test_parse_token_truncated() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         // 토큰 앞부분만 사용
         let truncated = &token[..token.len() / 2];
         assert!(parse_token(truncated).is_err());
@@ -318,7 +433,7 @@ mod tests {
 
     #[test]
     fn test_parse_token_newlines() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         let with_newline = format!("{token}\n");
         // 개행 문자가 있으면 파싱 실패
         assert!(parse_token(&with_newline).is_err());
@@ -329,7 +444,7 @@ mod tests {
     #[test]
     fn test_token_with_email_subject() {
         let email = "user@example.com";
-        let token = gen_token(email).expect("Failed to generate token");
+        let token = gen_token(email, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, email);
     }
@@ -337,7 +452,7 @@ mod tests {
     #[test]
     fn test_token_with_uuid_subject() {
         let uuid = "550e8400-e29b-41d4-a716-446655440000";
-        let token = gen_token(uuid).expect("Failed to generate token");
+        let token = gen_token(uuid, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, uuid);
     }
@@ -345,7 +460,7 @@ mod tests {
     #[test]
     fn test_token_with_numeric_subject() {
         let numeric = "12345678901234567890";
-        let token = gen_token(numeric).expect("Failed to generate token");
+        let token = gen_token(numeric, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, numeric);
     }
@@ -353,7 +468,7 @@ mod tests {
     #[test]
     fn test_token_with_json_subject() {
         let json_sub = r#"{"user_id": 123, "role": "admin"}"#;
-        let token = gen_token(json_sub).expect("Failed to generate token");
+        let token = gen_token(json_sub, Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert_eq!(claims.sub, json_sub);
     }
@@ -362,14 +477,14 @@ mod tests {
 
     #[test]
     fn test_claims_exp_greater_than_iat() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         assert!(claims.exp > claims.iat, "exp should be greater than iat");
     }
 
     #[test]
     fn test_claims_exp_iat_difference() {
-        let token = gen_token("test").expect("Failed to generate token");
+        let token = gen_token("test", Scope::GUEST).expect("Failed to generate token");
         let claims = parse_token(&token).expect("Failed to parse token");
         let diff = claims.exp - claims.iat;
         // 기본 24시간 = 86400초, 환경 변수에 따라 다를 수 있음
@@ -382,7 +497,7 @@ mod tests {
     fn test_multiple_tokens_same_subject() {
         let subject = "repeated_user";
         let tokens: Vec<String> = (0..5)
-            .map(|_| gen_token(subject).expect("Failed to generate token"))
+            .map(|_| gen_token(subject, Scope::GUEST).expect("Failed to generate token"))
             .collect();
 
         // 모든 토큰이 유효한지 확인
@@ -397,7 +512,7 @@ mod tests {
         let subjects = ["user1", "user2", "user3"];
         let tokens: Vec<String> = subjects
             .iter()
-            .map(|s| gen_token(s).expect("Failed to generate token"))
+            .map(|s| gen_token(s, Scope::GUEST).expect("Failed to generate token"))
             .collect();
 
         // 모든 토큰이 서로 다른지 확인
@@ -416,6 +531,7 @@ mod tests {
             sub: "roundtrip_test".to_string(),
             exp: 9876543210,
             iat: 1234567890,
+            scopes: vec![Scope::UrlsCreate],
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -424,5 +540,98 @@ mod tests {
         assert_eq!(original.sub, restored.sub);
         assert_eq!(original.exp, restored.exp);
         assert_eq!(original.iat, restored.iat);
+        assert_eq!(original.scopes, restored.scopes);
+    }
+
+    // ============ 스코프 테스트 ============
+
+    #[test]
+    fn test_token_carries_scopes() {
+        let token = gen_token("ops", &[Scope::Admin]).expect("Failed to generate token");
+        let claims = parse_token(&token).expect("Failed to parse token");
+        assert_eq!(claims.scopes, vec![Scope::Admin]);
+
+        let token = gen_token("guest", Scope::GUEST).expect("Failed to generate token");
+        let claims = parse_token(&token).expect("Failed to parse token");
+        assert_eq!(claims.scopes, Scope::GUEST);
+    }
+
+    #[test]
+    fn test_token_without_scopes_claim() {
+        // 스코프 도입 이전에 발급된 토큰 (scopes 클레임 없음)
+        #[derive(Serialize)]
+        struct LegacyClaims<'a> {
+            sub: &'a str,
+            exp: i64,
+            iat: i64,
+        }
+        let legacy_token = |sub| {
+            let now = chrono::Utc::now().timestamp();
+            let claims = LegacyClaims {
+                sub,
+                exp: now + 3600,
+                iat: now,
+            };
+            encode(
+                &Header::default(),
+                &claims,
+                &EncodingKey::from_secret(JWT_SECRET.as_bytes()),
+            )
+            .unwrap()
+        };
+
+        // 이전 게스트 토큰은 게스트 스코프를 유지
+        for sub in ["guest", "guest-AbCdEfGh12345678"] {
+            let claims = parse_token(&legacy_token(sub)).expect("Failed to parse token");
+            assert_eq!(claims.scopes, Scope::GUEST, "{sub}");
+        }
+
+        // 게스트가 아닌 subject는 권한 없음
+        let claims = parse_token(&legacy_token("ops")).expect("Failed to parse token");
+        assert!(claims.scopes.is_empty());
+
+        // 빈 스코프 클레임은 그대로 권한 없음
+        let claims: Claims =
+            serde_json::from_str(r#"{"sub":"guest-a","exp":9999999999,"iat":1,"scopes":[]}"#)
+                .unwrap();
+        assert!(claims.scopes.is_empty());
+    }
+
+    #[test]
+    fn test_has_scope() {
+        let mut claims = Claims {
+            sub: "user".to_string(),
+            exp: 9_999_999_999,
+            iat: 1_000_000_000,
+            scopes: vec![Scope::UrlsCreate],
+        };
+        assert!(claims.has_scope(Scope::UrlsCreate));
+        assert!(!claims.has_scope(Scope::UrlsManage));
+        assert!(!claims.has_scope(Scope::Admin));
+
+        // admin은 모든 스코프를 포함
+        claims.scopes = vec![Scope::Admin];
+        assert!(claims.has_scope(Scope::UrlsCreate));
+        assert!(claims.has_scope(Scope::UrlsManage));
+        assert!(claims.has_scope(Scope::Admin));
+    }
+
+    #[test]
+    fn test_scopes_claim_deserialization() {
+        let json = r#"{"sub":"u","exp":9999999999,"iat":1,"scopes":["urls:manage","billing:read","admin"]}"#;
+        let claims: Claims = serde_json::from_str(json).unwrap();
+        // 알 수 없는 스코프는 무시
+        assert_eq!(claims.scopes, vec![Scope::UrlsManage, Scope::Admin]);
+
+        // 스코프 클레임이 없는 토큰은 아무 권한도 없음
+        let claims: Claims =
+            serde_json::from_str(r#"{"sub":"u","exp":9999999999,"iat":1}"#).unwrap();
+        assert!(claims.scopes.is_empty());
+        assert!(!claims.has_scope(Scope::UrlsCreate));
+
+        assert_eq!(
+            serde_json::to_string(&Scope::UrlsCreate).unwrap(),
+            format!("\"{}\"", Scope::UrlsCreate.as_str())
+        );
     }
 }
@@ -0,0 +1,85 @@
+//! Client language utilities.
+//!
+//! Reads the visitor's preferred language from the `Accept-Language` header.
+
+use axum::http::{header, HeaderMap};
+
+/// Resolves the client's most preferred language.
+///
+/// Returns the lowercase language tag with the highest quality value
+/// (the first one on ties), e.g. `ko-kr` for `ko-KR,ko;q=0.9,en;q=0.8`.
+/// Wildcards and tags with `q=0` are ignored.
+#[must_use]
+pub fn preferred_language(headers: &HeaderMap) -> Option<String> {
+    let accept_language = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|h| h.to_str().ok())?;
+
+    let mut best: Option<(&str, f32)> = None;
+    for entry in accept_language.split(',') {
+        let mut parts = entry.split(';').map(str::trim);
+        let tag = parts.next().unwrap_or_default();
+        let quality = parts
+            .find_map(|param| param.strip_prefix("q="))
+            .map_or(Some(1.0), |q| q.parse::<f32>().ok());
+
+        let Some(quality) = quality else { continue };
+        if tag.is_empty() || tag == "*" || quality <= 0.0 {
+            continue;
+        }
+        if best.is_none_or(|(_, best_quality)| quality > best_quality) {
+            best = Some((tag, quality));
+        }
+    }
+
+    best.map(|(tag, _)| tag.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(accept_language: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            HeaderValue::from_str(accept_language).unwrap(),
+        );
+        headers
+    }
+
+    // ============ preferred_language 함수 테스트 ============
+
+    #[test]
+    fn test_preferred_language_first_tag() {
+        assert_eq!(
+            preferred_language(&headers("ko-KR,ko;q=0.9,en-US;q=0.8,en;q=0.7")).as_deref(),
+            Some("ko-kr")
+        );
+        assert_eq!(preferred_language(&headers("en")).as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn test_preferred_language_highest_quality() {
+        assert_eq!(
+            preferred_language(&headers("en;q=0.5, ja;q=0.8, de;q=0.8")).as_deref(),
+            Some("ja")
+        );
+    }
+
+    #[test]
+    fn test_preferred_language_ignores_wildcards_and_invalid() {
+        assert_eq!(
+            preferred_language(&headers("*, fr;q=0.3")).as_deref(),
+            Some("fr")
+        );
+        assert_eq!(
+            preferred_language(&headers("de;q=abc, es;q=0.1")).as_deref(),
+            Some("es")
+        );
+        assert!(preferred_language(&headers("en;q=0")).is_none());
+        assert!(preferred_language(&headers("")).is_none());
+        assert!(preferred_language(&HeaderMap::new()).is_none());
+    }
+}
@@ -1,11 +1,36 @@
 //! Utility module.
 //!
-//! Provides JWT, random string generation, and short key encoding utilities.
+//! Provides JWT, random string generation, short key encoding,
+//! client IP, country and language, user agent classification, deep link URLs,
+//! path suffixes, query strings, URL safety, link password, visitor ID and QR code
+//! utilities.
 
+pub mod deep_link;
+pub mod ip;
 pub mod jwt;
+pub mod locale;
+pub mod password;
+pub mod path;
+pub mod platform;
+pub mod qr;
+pub mod query;
 pub mod rand;
 pub mod short_key;
+pub mod url_safety;
+pub mod visitor;
 
-pub use jwt::{gen_token, parse_token, Claims};
+pub use deep_link::{android_intent_url, in_app_escape_url};
+pub use ip::{client_country, client_ip, truncate_ip};
+pub use jwt::{gen_token, parse_token, Claims, Scope, GUEST_SUBJECT_PREFIX};
+pub use locale::preferred_language;
+pub use password::{
+    hash_password, is_unlocked, unlock_cookie, unlock_cookie_name, verify_password,
+};
+pub use path::{join_path, path_suffix};
+pub use platform::{BrowserFamily, InAppBrowser, Platform, UserAgentInfo};
+pub use qr::{render_qr, QrErrorCorrection, QrFormat, QrOptions};
+pub use query::{merge_query, parse_query};
 pub use rand::gen_rand_str;
 pub use short_key::{merge_short_key, split_short_key};
+pub use url_safety::{check_url_safety, SchemePolicy};
+pub use visitor::{new_visitor_id, visitor_cookie, visitor_id, VISITOR_COOKIE_NAME};
@@ -0,0 +1,182 @@
+//! Link password utility module.
+//!
+//! Provides password hashing for protected links and the signed cookie
+//! that lets a visitor skip the prompt after entering the correct password.
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use cookie::{time::Duration, Cookie, CookieJar, Key, SameSite};
+use once_cell::sync::Lazy;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::config::{get_env, APP_CONFIG};
+use crate::error::{AppError, AppResult};
+
+/// Minimum length for the cookie signing secret.
+const MIN_SECRET_LENGTH: usize = 32;
+
+/// Lifetime of the cookie issued after a correct password, in seconds.
+pub const UNLOCK_COOKIE_MAX_AGE_SECS: i64 = 86_400;
+
+/// Cookie signing key derived from `COOKIE_SECRET`.
+///
+/// # Security Behavior
+///
+/// - In production (`RUST_ENV=production`): Panics if `COOKIE_SECRET` is not set
+/// - In development: Uses an insecure default with a warning
+/// - Panics if the secret is shorter than 32 characters
+static COOKIE_KEY: Lazy<Key> = Lazy::new(|| {
+    let secret = get_env("COOKIE_SECRET", None);
+
+    if secret.is_empty() {
+        assert!(
+            !APP_CONFIG.is_production,
+            "COOKIE_SECRET must be set in production environment"
+        );
+        tracing::warn!("⚠️  COOKIE_SECRET not set - using insecure default.");
+        return Key::derive_from(b"default-cookie-secret-change-me-in-production");
+    }
+
+    assert!(
+        secret.len() >= MIN_SECRET_LENGTH,
+        "COOKIE_SECRET must be at least {MIN_SECRET_LENGTH} characters"
+    );
+    Key::derive_from(secret.as_bytes())
+});
+
+/// Hashes a link password with Argon2id and a random salt.
+///
+/// Returns the hash in PHC string format. This is CPU-intensive,
+/// so call it from a blocking task.
+pub fn hash_password(password: &str) -> AppResult<String> {
+    let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>())
+        .map_err(|e| AppError::Internal(format!("Failed to generate salt: {e}")))?;
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AppError::Internal(format!("Failed to hash password: {e}")))
+}
+
+/// Verifies a link password against its PHC string hash.
+///
+/// Returns `false` for a wrong password or an unreadable hash. This is
+/// CPU-intensive, so call it from a blocking task.
+#[must_use]
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    let Ok(parsed) = PasswordHash::new(password_hash) else {
+        tracing::error!("Stored link password hash is not a valid PHC string");
+        return false;
+    };
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed)
+        .is_ok()
+}
+
+/// Returns the name of the cookie that unlocks a URL.
+#[must_use]
+pub fn unlock_cookie_name(url_id: i64) -> String {
+    format!("unlock_{url_id}")
+}
+
+/// Returns the cookie value that unlocks a URL.
+///
+/// Derived from the password hash, so changing the password
+/// invalidates previously issued cookies.
+fn unlock_token(password_hash: &str) -> String {
+    format!("{:016x}", xxh3_64(password_hash.as_bytes()))
+}
+
+/// Builds the signed cookie issued after a correct password.
+#[must_use]
+pub fn unlock_cookie(url_id: i64, password_hash: &str) -> Cookie<'static> {
+    let name = unlock_cookie_name(url_id);
+    let cookie = Cookie::build((name.clone(), unlock_token(password_hash)))
+        .path("/")
+        .http_only(true)
+        .secure(APP_CONFIG.is_production)
+        .same_site(SameSite::Lax)
+        .max_age(Duration::seconds(UNLOCK_COOKIE_MAX_AGE_SECS))
+        .build();
+
+    let mut jar = CookieJar::new();
+    jar.signed_mut(&COOKIE_KEY).add(cookie);
+    jar.get(&name)
+        .cloned()
+        .expect("signed cookie was just added to the jar")
+}
+
+/// Checks whether a request cookie unlocks a URL with the given password hash.
+///
+/// The cookie must carry a valid signature and the token of the current password.
+#[must_use]
+pub fn is_unlocked(cookie: Option<&Cookie<'_>>, password_hash: &str) -> bool {
+    let Some(cookie) = cookie else {
+        return false;
+    };
+    let name = cookie.name().to_string();
+
+    let mut jar = CookieJar::new();
+    jar.add_original(cookie.clone().into_owned());
+    jar.signed(&COOKIE_KEY)
+        .get(&name)
+        .is_some_and(|verified| verified.value() == unlock_token(password_hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ hash_password / verify_password 테스트 ============
+
+    #[test]
+    fn test_hash_password_roundtrip() {
+        let hash = hash_password("s3cret").unwrap();
+
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(verify_password("s3cret", &hash));
+        assert!(!verify_password("wrong", &hash));
+    }
+
+    #[test]
+    fn test_hash_password_is_salted() {
+        let a = hash_password("same").unwrap();
+        let b = hash_password("same").unwrap();
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_verify_password_invalid_hash() {
+        assert!(!verify_password("s3cret", "not-a-phc-string"));
+    }
+
+    // ============ unlock cookie 테스트 ============
+
+    #[test]
+    fn test_unlock_cookie_roundtrip() {
+        let hash = hash_password("s3cret").unwrap();
+        let cookie = unlock_cookie(42, &hash);
+
+        assert_eq!(cookie.name(), "unlock_42");
+        assert!(cookie.http_only().unwrap_or(false));
+        assert!(is_unlocked(Some(&cookie), &hash));
+    }
+
+    #[test]
+    fn test_unlock_cookie_rejects_other_password() {
+        let cookie = unlock_cookie(42, "$argon2id$old");
+
+        assert!(!is_unlocked(Some(&cookie), "$argon2id$new"));
+    }
+
+    #[test]
+    fn test_unlock_cookie_rejects_tampered_value() {
+        let hash = "$argon2id$hash";
+        let forged = Cookie::new(unlock_cookie_name(42), unlock_token(hash));
+
+        assert!(!is_unlocked(Some(&forged), hash));
+        assert!(!is_unlocked(None, hash));
+    }
+}
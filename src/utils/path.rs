@@ -0,0 +1,139 @@
+//! Path suffix utilities.
+//!
+//! Encodes the path a visitor appended to a short URL
+//! (`/{short_key}/{*rest}`) and joins it onto destination URLs.
+
+/// Uppercase hex digits of percent-encoded bytes.
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Characters kept as-is in a path segment (RFC 3986 `pchar` without `%`).
+const fn is_path_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'-' | b'.'
+                | b'_'
+                | b'~'
+                | b'!'
+                | b'$'
+                | b'&'
+                | b'\''
+                | b'('
+                | b')'
+                | b'*'
+                | b'+'
+                | b','
+                | b';'
+                | b'='
+                | b':'
+                | b'@'
+        )
+}
+
+/// Percent-encodes a decoded path segment.
+fn encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if is_path_char(byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push('%');
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte & 0x0F)]));
+        }
+    }
+    encoded
+}
+
+/// Encodes a decoded path suffix for joining onto destinations.
+///
+/// Empty and dot segments (`.`, `..`) are dropped, so the suffix can only
+/// extend a destination's path, never climb out of it. Returns `None`
+/// when nothing is left.
+#[must_use]
+pub fn path_suffix(rest: &str) -> Option<String> {
+    let segments: Vec<String> = rest
+        .split('/')
+        .filter(|segment| !matches!(*segment, "" | "." | ".."))
+        .map(encode_segment)
+        .collect();
+
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+/// Appends an encoded path suffix to the path of a URL or URL path.
+///
+/// The suffix goes after the last path segment, before the query and the
+/// fragment, which are kept as written: `https://example.com/shop?id=1`
+/// joined with `products/42` gives `https://example.com/shop/products/42?id=1`.
+#[must_use]
+pub fn join_path(target: &str, suffix: &str) -> String {
+    let end = target.find(['?', '#']).unwrap_or(target.len());
+    let (base, tail) = target.split_at(end);
+
+    let mut joined = base.to_string();
+    if !base.is_empty() && !base.ends_with('/') {
+        joined.push('/');
+    }
+    joined.push_str(suffix);
+    joined.push_str(tail);
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ path_suffix 테스트 ============
+
+    #[test]
+    fn test_path_suffix_encodes_segments() {
+        assert_eq!(path_suffix("products/42").as_deref(), Some("products/42"));
+        assert_eq!(
+            path_suffix("summer sale/신발?").as_deref(),
+            Some("summer%20sale/%EC%8B%A0%EB%B0%9C%3F")
+        );
+        assert_eq!(path_suffix("a#b/c%d").as_deref(), Some("a%23b/c%25d"));
+        assert_eq!(path_suffix("v1:item@2").as_deref(), Some("v1:item@2"));
+    }
+
+    #[test]
+    fn test_path_suffix_drops_empty_and_dot_segments() {
+        assert_eq!(
+            path_suffix("../admin/./x//y/").as_deref(),
+            Some("admin/x/y")
+        );
+        assert_eq!(path_suffix("...").as_deref(), Some("..."));
+        assert!(path_suffix("").is_none());
+        assert!(path_suffix("/../.").is_none());
+    }
+
+    // ============ join_path 테스트 ============
+
+    #[test]
+    fn test_join_path_http_urls() {
+        assert_eq!(
+            join_path("https://example.com", "products/42"),
+            "https://example.com/products/42"
+        );
+        assert_eq!(
+            join_path("https://example.com/shop/", "products/42"),
+            "https://example.com/shop/products/42"
+        );
+        assert_eq!(
+            join_path("https://example.com/shop?id=1#top", "products/42"),
+            "https://example.com/shop/products/42?id=1#top"
+        );
+    }
+
+    #[test]
+    fn test_join_path_deep_links_and_paths() {
+        assert_eq!(join_path("myapp://", "products/42"), "myapp://products/42");
+        assert_eq!(
+            join_path("myapp://shop?ref=a", "products/42"),
+            "myapp://shop/products/42?ref=a"
+        );
+        assert_eq!(join_path("item/1", "reviews"), "item/1/reviews");
+        assert_eq!(join_path("", "reviews"), "reviews");
+    }
+}
@@ -0,0 +1,562 @@
+//! Client platform detection module.
+//!
+//! Classifies a request by its `User-Agent` header into an OS family
+//! (or bot), a browser family and, for embedded webviews, the hosting
+//! in-app browser.
+//! This is the single classification shared by redirects, click
+//! analytics, webhooks and the redirect page script.
+
+use serde::{Deserialize, Serialize};
+
+/// `User-Agent` fragments of crawlers, link preview fetchers and HTTP tools (lowercase).
+const BOT_MARKERS: [&str; 17] = [
+    "bot/",
+    "bot;",
+    "bot-",
+    "bot)",
+    "crawler",
+    "spider",
+    "slurp",
+    "facebookexternalhit",
+    "kakaotalk-scrap",
+    "whatsapp/",
+    "yeti/",
+    "daumoa",
+    "headlesschrome",
+    "curl/",
+    "wget/",
+    "python-requests",
+    "go-http-client",
+];
+
+/// Client platform derived from the `User-Agent` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Platform {
+    /// iPhone, iPad or iPod
+    Ios,
+    /// Android phones and tablets
+    Android,
+    /// Windows desktops
+    Windows,
+    /// macOS desktops
+    #[serde(rename = "macos")]
+    MacOs,
+    /// Desktop Linux
+    Linux,
+    /// Chromebooks
+    #[serde(rename = "chromeos")]
+    ChromeOs,
+    /// Crawlers, link preview fetchers and command-line HTTP clients
+    Bot,
+    /// Anything else (unknown clients, missing header)
+    Other,
+}
+
+impl Platform {
+    /// Detects the platform from a `User-Agent` header value.
+    #[must_use]
+    pub fn from_user_agent(user_agent: &str) -> Self {
+        UserAgentInfo::parse(user_agent).platform
+    }
+
+    /// Returns the stored (database) representation of the platform.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ios => "ios",
+            Self::Android => "android",
+            Self::Windows => "windows",
+            Self::MacOs => "macos",
+            Self::Linux => "linux",
+            Self::ChromeOs => "chromeos",
+            Self::Bot => "bot",
+            Self::Other => "other",
+        }
+    }
+
+    /// Detects the OS family from a lowercase `User-Agent`.
+    fn from_lowercase(ua: &str) -> Self {
+        // Mobile first: Android UAs contain "Linux", iOS UAs contain "Mac OS X"
+        if ua.contains("android") {
+            Self::Android
+        } else if ua.contains("iphone") || ua.contains("ipad") || ua.contains("ipod") {
+            Self::Ios
+        } else if ua.contains(" cros ") {
+            Self::ChromeOs
+        } else if ua.contains("windows") {
+            Self::Windows
+        } else if ua.contains("macintosh") || ua.contains("mac os x") {
+            Self::MacOs
+        } else if ua.contains("linux") || ua.contains("x11") {
+            Self::Linux
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// In-app browser (webview of a social or messaging app) hosting the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InAppBrowser {
+    Instagram,
+    Facebook,
+    #[serde(rename = "kakaotalk")]
+    KakaoTalk,
+    Line,
+}
+
+impl InAppBrowser {
+    /// Returns the app name shown to visitors.
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Instagram => "Instagram",
+            Self::Facebook => "Facebook",
+            Self::KakaoTalk => "KakaoTalk",
+            Self::Line => "LINE",
+        }
+    }
+
+    /// Detects the in-app browser from a lowercase `User-Agent`.
+    fn from_lowercase(ua: &str) -> Option<Self> {
+        if ua.contains("instagram") {
+            Some(Self::Instagram)
+        } else if ua.contains("fban/") || ua.contains("fbav/") || ua.contains("fb_iab") {
+            Some(Self::Facebook)
+        } else if ua.contains("kakaotalk") {
+            Some(Self::KakaoTalk)
+        } else if ua.contains(" line/") {
+            Some(Self::Line)
+        } else {
+            None
+        }
+    }
+}
+
+/// Browser family derived from the `User-Agent` header.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserFamily {
+    Safari,
+    Chrome,
+    Firefox,
+    Edge,
+    Opera,
+    #[serde(rename = "samsung")]
+    SamsungInternet,
+    /// App webviews, including in-app browsers
+    Webview,
+    /// Bots, unknown clients and missing headers
+    #[default]
+    Other,
+}
+
+impl BrowserFamily {
+    /// Detects the browser family from a lowercase `User-Agent`.
+    ///
+    /// Chromium-based browsers also claim Chrome and Safari, and Chrome
+    /// claims Safari, so the more specific tokens are checked first.
+    fn from_lowercase(ua: &str, platform: Platform) -> Self {
+        if ua.contains("; wv)") {
+            Self::Webview
+        } else if ua.contains("edg/") || ua.contains("edga/") || ua.contains("edgios/") {
+            Self::Edge
+        } else if ua.contains("opr/") || ua.contains("opt/") {
+            Self::Opera
+        } else if ua.contains("samsungbrowser/") {
+            Self::SamsungInternet
+        } else if ua.contains("firefox/") || ua.contains("fxios/") {
+            Self::Firefox
+        } else if ua.contains("chrome/") || ua.contains("crios/") {
+            Self::Chrome
+        } else if ua.contains("safari/") {
+            Self::Safari
+        } else if platform == Platform::Ios && ua.contains("applewebkit/") {
+            // iOS webviews omit the Safari token
+            Self::Webview
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Classification of a `User-Agent` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserAgentInfo {
+    pub platform: Platform,
+    pub browser: BrowserFamily,
+    /// Set when the request comes from an app's embedded webview
+    pub in_app_browser: Option<InAppBrowser>,
+}
+
+impl UserAgentInfo {
+    /// Classifies a `User-Agent` header value.
+    ///
+    /// Bots take precedence over the OS they claim (e.g. the smartphone
+    /// Googlebot), and are never reported as in-app browsers.
+    #[must_use]
+    pub fn parse(user_agent: &str) -> Self {
+        let ua = user_agent.to_ascii_lowercase();
+
+        if is_bot(&ua) {
+            return Self {
+                platform: Platform::Bot,
+                browser: BrowserFamily::Other,
+                in_app_browser: None,
+            };
+        }
+
+        let platform = Platform::from_lowercase(&ua);
+        let in_app_browser = InAppBrowser::from_lowercase(&ua);
+        let browser = if in_app_browser.is_some() {
+            BrowserFamily::Webview
+        } else {
+            BrowserFamily::from_lowercase(&ua, platform)
+        };
+
+        Self {
+            platform,
+            browser,
+            in_app_browser,
+        }
+    }
+}
+
+/// Returns whether a lowercase `User-Agent` belongs to a bot.
+fn is_bot(ua: &str) -> bool {
+    ua.ends_with("bot") || BOT_MARKERS.iter().any(|marker| ua.contains(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `User-Agent` corpus: (header, platform, in-app browser).
+    const CORPUS: &[(&str, Platform, Option<InAppBrowser>)] = &[
+        // iOS
+        (
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1",
+            Platform::Ios,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (iPad; CPU OS 16_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.6 Mobile/15E148 Safari/604.1",
+            Platform::Ios,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/119.0.6045.109 Mobile/15E148 Safari/604.1",
+            Platform::Ios,
+            None,
+        ),
+        // Android
+        (
+            "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+            Platform::Android,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 13; SAMSUNG SM-S918N) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/23.0 Chrome/115.0.0.0 Mobile Safari/537.36",
+            Platform::Android,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Android 14; Mobile; rv:121.0) Gecko/121.0 Firefox/121.0",
+            Platform::Android,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 10; CUBOT NOTE 20) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+            Platform::Android,
+            None,
+        ),
+        // Desktop
+        (
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+            Platform::Windows,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Edg/120.0.0.0",
+            Platform::Windows,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.1 Safari/605.1.15",
+            Platform::MacOs,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 14.1; rv:121.0) Gecko/20100101 Firefox/121.0",
+            Platform::MacOs,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+            Platform::Linux,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0",
+            Platform::Linux,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (X11; CrOS x86_64 14541.0.0) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+            Platform::ChromeOs,
+            None,
+        ),
+        // In-app browsers
+        (
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 Instagram 307.0.0.34.111 (iPhone14,5; iOS 17_0; ko_KR; ko; scale=3.00; 1170x2532; 532277883)",
+            Platform::Ios,
+            Some(InAppBrowser::Instagram),
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 14; SM-S911N Build/UP1A.231005.007; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/120.0.6099.43 Mobile Safari/537.36 Instagram 309.1.0.41.113 Android (34/14; 480dpi; 1080x2340; samsung; SM-S911N; dm1q; qcom; ko_KR; 541635890)",
+            Platform::Android,
+            Some(InAppBrowser::Instagram),
+        ),
+        (
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 [FBAN/FBIOS;FBAV/442.0.0.33.114;FBBV/538436834;FBDV/iPhone15,2;FBMD/iPhone;FBSN/iOS;FBSV/17.1;FBSS/3;FBCR/;FBID/phone;FBLC/ko_KR;FBOP/80]",
+            Platform::Ios,
+            Some(InAppBrowser::Facebook),
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 14; Pixel 7 Build/UQ1A.231205.015; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/120.0.6099.43 Mobile Safari/537.36 [FB_IAB/FB4A;FBAV/443.0.0.32.118;]",
+            Platform::Android,
+            Some(InAppBrowser::Facebook),
+        ),
+        (
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 KAKAOTALK 10.4.0",
+            Platform::Ios,
+            Some(InAppBrowser::KakaoTalk),
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 13; SM-G998N Build/TP1A.220624.014; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/119.0.6045.163 Mobile Safari/537.36;KAKAOTALK 2610420",
+            Platform::Android,
+            Some(InAppBrowser::KakaoTalk),
+        ),
+        (
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 16_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 Safari Line/13.16.0",
+            Platform::Ios,
+            Some(InAppBrowser::Line),
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 12; SM-A325F Build/SP1A.210812.016; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/118.0.5993.111 Mobile Safari/537.36 Line/13.19.1/IAB",
+            Platform::Android,
+            Some(InAppBrowser::Line),
+        ),
+        // Bots
+        (
+            "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+            Platform::Bot,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (Linux; Android 6.0.1; Nexus 5X Build/MMB29P) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.6099.71 Mobile Safari/537.36 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+            Platform::Bot,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)",
+            Platform::Bot,
+            None,
+        ),
+        (
+            "facebookexternalhit/1.1 (+http://www.facebook.com/externalhit_uatext.php)",
+            Platform::Bot,
+            None,
+        ),
+        ("Twitterbot/1.0", Platform::Bot, None),
+        (
+            "Slackbot-LinkExpanding 1.0 (+https://api.slack.com/robots)",
+            Platform::Bot,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (compatible; Discordbot/2.0; +https://discordapp.com)",
+            Platform::Bot,
+            None,
+        ),
+        ("TelegramBot (like TwitterBot)", Platform::Bot, None),
+        ("WhatsApp/2.23.20.0 A", Platform::Bot, None),
+        (
+            "Mozilla/5.0 (compatible; kakaotalk-scrap/1.0; +https://devtalk.kakao.com/)",
+            Platform::Bot,
+            None,
+        ),
+        (
+            "Mozilla/5.0 (compatible; Yeti/1.1; +http://naver.me/spd)",
+            Platform::Bot,
+            None,
+        ),
+        ("curl/8.4.0", Platform::Bot, None),
+        ("python-requests/2.31.0", Platform::Bot, None),
+        // Unknown
+        ("", Platform::Other, None),
+        ("SomeCustomClient/1.0", Platform::Other, None),
+    ];
+
+    // ============ UserAgentInfo::parse 테스트 ============
+
+    #[test]
+    fn test_user_agent_corpus() {
+        for (ua, platform, in_app_browser) in CORPUS {
+            let info = UserAgentInfo::parse(ua);
+            assert_eq!(info.platform, *platform, "platform of {ua:?}");
+            assert_eq!(
+                info.in_app_browser, *in_app_browser,
+                "in-app browser of {ua:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_user_agent_corpus_matches_from_user_agent() {
+        for (ua, platform, _) in CORPUS {
+            assert_eq!(Platform::from_user_agent(ua), *platform, "{ua:?}");
+        }
+    }
+
+    // ============ BrowserFamily 테스트 ============
+
+    #[test]
+    fn test_browser_family() {
+        let cases = [
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1",
+                BrowserFamily::Safari,
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/119.0.6045.109 Mobile/15E148 Safari/604.1",
+                BrowserFamily::Chrome,
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+                BrowserFamily::Chrome,
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 13; SAMSUNG SM-S918N) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/23.0 Chrome/115.0.0.0 Mobile Safari/537.36",
+                BrowserFamily::SamsungInternet,
+            ),
+            (
+                "Mozilla/5.0 (Android 14; Mobile; rv:121.0) Gecko/121.0 Firefox/121.0",
+                BrowserFamily::Firefox,
+            ),
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Edg/120.0.0.0",
+                BrowserFamily::Edge,
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14; Pixel 7 Build/UQ1A.231205.015; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/120.0.6099.43 Mobile Safari/537.36",
+                BrowserFamily::Webview,
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148",
+                BrowserFamily::Webview,
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 16_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 Safari Line/13.16.0",
+                BrowserFamily::Webview,
+            ),
+            ("Twitterbot/1.0", BrowserFamily::Other),
+            ("SomeCustomClient/1.0", BrowserFamily::Other),
+        ];
+
+        for (ua, browser) in cases {
+            assert_eq!(UserAgentInfo::parse(ua).browser, browser, "{ua:?}");
+        }
+    }
+
+    // ============ Platform::from_user_agent 테스트 ============
+
+    #[test]
+    fn test_platform_iphone() {
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15";
+        assert_eq!(Platform::from_user_agent(ua), Platform::Ios);
+    }
+
+    #[test]
+    fn test_platform_ipad() {
+        let ua = "Mozilla/5.0 (iPad; CPU OS 16_6 like Mac OS X) AppleWebKit/605.1.15";
+        assert_eq!(Platform::from_user_agent(ua), Platform::Ios);
+    }
+
+    #[test]
+    fn test_platform_android() {
+        let ua = "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 Chrome/120.0";
+        assert_eq!(Platform::from_user_agent(ua), Platform::Android);
+    }
+
+    #[test]
+    fn test_platform_desktop_is_windows() {
+        let ua = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 Chrome/120.0";
+        assert_eq!(Platform::from_user_agent(ua), Platform::Windows);
+    }
+
+    #[test]
+    fn test_platform_empty_is_other() {
+        assert_eq!(Platform::from_user_agent(""), Platform::Other);
+    }
+
+    #[test]
+    fn test_platform_case_insensitive() {
+        assert_eq!(Platform::from_user_agent("ANDROID"), Platform::Android);
+        assert_eq!(Platform::from_user_agent("iphone"), Platform::Ios);
+        assert_eq!(Platform::from_user_agent("GOOGLEBOT/2.1"), Platform::Bot);
+    }
+
+    // ============ Platform::as_str 테스트 ============
+
+    #[test]
+    fn test_platform_as_str() {
+        assert_eq!(Platform::Ios.as_str(), "ios");
+        assert_eq!(Platform::Android.as_str(), "android");
+        assert_eq!(Platform::MacOs.as_str(), "macos");
+        assert_eq!(Platform::ChromeOs.as_str(), "chromeos");
+        assert_eq!(Platform::Other.as_str(), "other");
+    }
+
+    #[test]
+    fn test_platform_serialize_matches_as_str() {
+        for platform in [
+            Platform::Ios,
+            Platform::Android,
+            Platform::Windows,
+            Platform::MacOs,
+            Platform::Linux,
+            Platform::ChromeOs,
+            Platform::Bot,
+            Platform::Other,
+        ] {
+            let json = serde_json::to_string(&platform).unwrap();
+            assert_eq!(json, format!("\"{}\"", platform.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_in_app_browser_serialize() {
+        let names: Vec<String> = [
+            InAppBrowser::Instagram,
+            InAppBrowser::Facebook,
+            InAppBrowser::KakaoTalk,
+            InAppBrowser::Line,
+        ]
+        .iter()
+        .map(|browser| serde_json::to_string(browser).unwrap())
+        .collect();
+
+        assert_eq!(
+            names,
+            [
+                r#""instagram""#,
+                r#""facebook""#,
+                r#""kakaotalk""#,
+                r#""line""#
+            ]
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! QR code rendering module.
+//!
+//! Renders QR codes as PNG or SVG images with a configurable size,
+//! error-correction level and margin.
+
+use std::fmt::Write;
+
+use qrcode::{Color, EcLevel, QrCode};
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+
+/// Output image format of a QR code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QrFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+impl QrFormat {
+    /// Returns the format name.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Svg => "svg",
+        }
+    }
+
+    /// Returns the MIME type of the format.
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+/// QR code error-correction level (share of damaged modules the code survives).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum QrErrorCorrection {
+    /// About 7%
+    #[serde(rename = "L", alias = "l")]
+    Low,
+    /// About 15%
+    #[default]
+    #[serde(rename = "M", alias = "m")]
+    Medium,
+    /// About 25%
+    #[serde(rename = "Q", alias = "q")]
+    Quartile,
+    /// About 30%
+    #[serde(rename = "H", alias = "h")]
+    High,
+}
+
+impl QrErrorCorrection {
+    /// Returns the single-letter level name.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "L",
+            Self::Medium => "M",
+            Self::Quartile => "Q",
+            Self::High => "H",
+        }
+    }
+
+    const fn ec_level(self) -> EcLevel {
+        match self {
+            Self::Low => EcLevel::L,
+            Self::Medium => EcLevel::M,
+            Self::Quartile => EcLevel::Q,
+            Self::High => EcLevel::H,
+        }
+    }
+}
+
+/// QR code rendering options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QrOptions {
+    pub format: QrFormat,
+    /// Requested image width and height in pixels
+    pub size: u32,
+    pub error_correction: QrErrorCorrection,
+    /// Quiet zone around the code, in modules
+    pub margin: u32,
+}
+
+impl QrOptions {
+    /// Returns a string identifying these options, for use in cache keys.
+    pub fn cache_suffix(&self) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.format.as_str(),
+            self.size,
+            self.error_correction.as_str(),
+            self.margin
+        )
+    }
+}
+
+/// Renders `data` as a QR code image.
+///
+/// Modules are drawn at a whole number of pixels, so the PNG may be slightly
+/// smaller than `size` (but never smaller than one pixel per module). The SVG
+/// is scaled to exactly `size`.
+pub fn render_qr(data: &str, options: &QrOptions) -> AppResult<Vec<u8>> {
+    let code = QrCode::with_error_correction_level(data, options.error_correction.ec_level())
+        .map_err(|e| AppError::Internal(format!("Failed to encode QR code: {e}")))?;
+
+    let modules = code.width();
+    let dark: Vec<bool> = code
+        .to_colors()
+        .into_iter()
+        .map(|color| color == Color::Dark)
+        .collect();
+
+    match options.format {
+        QrFormat::Png => render_png(&dark, modules, options),
+        QrFormat::Svg => Ok(render_svg(&dark, modules, options).into_bytes()),
+    }
+}
+
+/// Renders dark modules as an 8-bit grayscale PNG.
+fn render_png(dark: &[bool], modules: usize, options: &QrOptions) -> AppResult<Vec<u8>> {
+    let margin = options.margin as usize;
+    let total = modules + margin * 2;
+    let scale = (options.size as usize / total).max(1);
+    let side = total * scale;
+
+    let mut pixels = vec![u8::MAX; side * side];
+    for (index, _) in dark.iter().enumerate().filter(|(_, is_dark)| **is_dark) {
+        let (x, y) = (index % modules + margin, index / modules + margin);
+        for row in y * scale..(y + 1) * scale {
+            pixels[row * side + x * scale..row * side + (x + 1) * scale].fill(0);
+        }
+    }
+
+    let side = u32::try_from(side)
+        .map_err(|_| AppError::Internal("QR code image is too large".to_string()))?;
+    let mut image = Vec::new();
+    let mut encoder = png::Encoder::new(&mut image, side, side);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels))
+        .map_err(|e| AppError::Internal(format!("Failed to encode PNG: {e}")))?;
+
+    Ok(image)
+}
+
+/// Renders dark modules as a single SVG path over a white background.
+fn render_svg(dark: &[bool], modules: usize, options: &QrOptions) -> String {
+    let margin = options.margin as usize;
+    let total = modules + margin * 2;
+
+    let mut path = String::new();
+    for (index, _) in dark.iter().enumerate().filter(|(_, is_dark)| **is_dark) {
+        let (x, y) = (index % modules + margin, index / modules + margin);
+        let _ = write!(path, "M{x} {y}h1v1h-1z");
+    }
+
+    format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" "#,
+            r#"viewBox="0 0 {total} {total}" shape-rendering="crispEdges">"#,
+            r##"<rect width="100%" height="100%" fill="#fff"/>"##,
+            r##"<path fill="#000" d="{path}"/></svg>"##
+        ),
+        size = options.size,
+        total = total,
+        path = path
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(format: QrFormat, size: u32, margin: u32) -> QrOptions {
+        QrOptions {
+            format,
+            size,
+            error_correction: QrErrorCorrection::Medium,
+            margin,
+        }
+    }
+
+    // ============ QrFormat / QrErrorCorrection 테스트 ============
+
+    #[test]
+    fn test_qr_format_content_type() {
+        assert_eq!(QrFormat::Png.content_type(), "image/png");
+        assert_eq!(QrFormat::Svg.content_type(), "image/svg+xml");
+        assert_eq!(QrFormat::default(), QrFormat::Png);
+    }
+
+    #[test]
+    fn test_qr_error_correction_deserialize() {
+        let level: QrErrorCorrection = serde_json::from_str(r#""H""#).unwrap();
+        assert_eq!(level, QrErrorCorrection::High);
+
+        let level: QrErrorCorrection = serde_json::from_str(r#""q""#).unwrap();
+        assert_eq!(level, QrErrorCorrection::Quartile);
+
+        assert!(serde_json::from_str::<QrErrorCorrection>(r#""X""#).is_err());
+        assert_eq!(QrErrorCorrection::default(), QrErrorCorrection::Medium);
+    }
+
+    #[test]
+    fn test_qr_options_cache_suffix() {
+        let opts = options(QrFormat::Svg, 512, 2);
+        assert_eq!(opts.cache_suffix(), "svg:512:M:2");
+    }
+
+    // ============ render_qr 테스트 ============
+
+    #[test]
+    fn test_render_qr_png() {
+        let image =
+            render_qr("https://s.example/Ab3D7Xy", &options(QrFormat::Png, 256, 4)).unwrap();
+
+        assert_eq!(&image[..8], b"\x89PNG\r\n\x1a\n");
+        let decoder = png::Decoder::new(image.as_slice());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!(info.width, info.height);
+        assert!(info.width <= 256);
+        assert!(info.width > 128);
+    }
+
+    #[test]
+    fn test_render_qr_png_never_below_one_pixel_per_module() {
+        let image = render_qr("https://s.example/Ab3D7Xy", &options(QrFormat::Png, 1, 0)).unwrap();
+
+        let reader = png::Decoder::new(image.as_slice()).read_info().unwrap();
+        // 최소 버전(21x21 모듈) 이상
+        assert!(reader.info().width >= 21);
+    }
+
+    #[test]
+    fn test_render_qr_svg() {
+        let image =
+            render_qr("https://s.example/Ab3D7Xy", &options(QrFormat::Svg, 300, 4)).unwrap();
+        let svg = String::from_utf8(image).unwrap();
+
+        assert!(svg.contains(r#"width="300" height="300""#));
+        assert!(svg.contains("<path"));
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_render_qr_margin_changes_viewbox() {
+        let render = |margin| {
+            String::from_utf8(
+                render_qr(
+                    "https://s.example/Ab3D7Xy",
+                    &options(QrFormat::Svg, 300, margin),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        let modules = QrCode::with_error_correction_level(
+            "https://s.example/Ab3D7Xy",
+            QrErrorCorrection::Medium.ec_level(),
+        )
+        .unwrap()
+        .width();
+        let view_box = |side: usize| format!(r#"viewBox="0 0 {side} {side}""#);
+
+        assert!(render(0).contains(&view_box(modules)));
+        assert!(render(4).contains(&view_box(modules + 8)));
+    }
+
+    #[test]
+    fn test_render_qr_higher_correction_is_denser() {
+        let data = "https://s.example/Ab3D7Xy-with-a-longer-path";
+        let low = QrCode::with_error_correction_level(data, QrErrorCorrection::Low.ec_level())
+            .unwrap()
+            .width();
+        let high = QrCode::with_error_correction_level(data, QrErrorCorrection::High.ec_level())
+            .unwrap()
+            .width();
+
+        assert!(high > low);
+    }
+}
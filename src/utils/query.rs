@@ -0,0 +1,171 @@
+//! Query string utilities.
+//!
+//! Parses the query of a short URL visit and merges query parameters into
+//! destination URLs.
+
+use url::form_urlencoded;
+
+/// Parses a raw query string into decoded name-value pairs, in order.
+#[must_use]
+pub fn parse_query(raw_query: Option<&str>) -> Vec<(String, String)> {
+    raw_query
+        .map(|q| form_urlencoded::parse(q.as_bytes()).into_owned().collect())
+        .unwrap_or_default()
+}
+
+/// Merges query parameters into a URL or URL path.
+///
+/// Parameters of `target` named in `overrides` are replaced by them, and
+/// `defaults` are only added for names neither `target` nor `overrides`
+/// set. The remaining parameters and the fragment are kept as written;
+/// added parameters are form-encoded. Returns `target` unchanged when
+/// there is nothing to merge.
+#[must_use]
+pub fn merge_query(
+    target: &str,
+    defaults: &[(&str, &str)],
+    overrides: &[(String, String)],
+) -> String {
+    if defaults.is_empty() && overrides.is_empty() {
+        return target.to_string();
+    }
+
+    let (rest, fragment) = target
+        .split_once('#')
+        .map_or((target, None), |(rest, fragment)| (rest, Some(fragment)));
+    let (base, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let mut present: Vec<String> = Vec::new();
+    let mut segments: Vec<String> = Vec::new();
+    for segment in query.split('&').filter(|s| !s.is_empty()) {
+        let name = form_urlencoded::parse(segment.as_bytes())
+            .next()
+            .map(|(name, _)| name.into_owned())
+            .unwrap_or_default();
+        if !overrides.iter().any(|(n, _)| *n == name) {
+            segments.push(segment.to_string());
+        }
+        present.push(name);
+    }
+
+    let encode = |name: &str, value: &str| {
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair(name, value)
+            .finish()
+    };
+    segments.extend(overrides.iter().map(|(name, value)| encode(name, value)));
+    segments.extend(
+        defaults
+            .iter()
+            .filter(|(name, _)| {
+                !present.iter().any(|n| n == name) && !overrides.iter().any(|(n, _)| n == name)
+            })
+            .map(|(name, value)| encode(name, value)),
+    );
+
+    let mut merged = base.to_string();
+    if !segments.is_empty() {
+        merged.push('?');
+        merged.push_str(&segments.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        merged.push('#');
+        merged.push_str(fragment);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(n, v)| ((*n).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    // ============ parse_query 테스트 ============
+
+    #[test]
+    fn test_parse_query_decodes_pairs() {
+        assert_eq!(
+            parse_query(Some("ref=news%20letter&tag=a+b&tag=c&flag")),
+            pairs(&[
+                ("ref", "news letter"),
+                ("tag", "a b"),
+                ("tag", "c"),
+                ("flag", "")
+            ])
+        );
+        assert!(parse_query(None).is_empty());
+        assert!(parse_query(Some("")).is_empty());
+    }
+
+    // ============ merge_query 테스트 ============
+
+    #[test]
+    fn test_merge_query_appends_parameters() {
+        assert_eq!(
+            merge_query("https://example.com/p", &[("utm_source", "mail")], &[]),
+            "https://example.com/p?utm_source=mail"
+        );
+        assert_eq!(
+            merge_query(
+                "https://example.com/p?id=1",
+                &[],
+                &pairs(&[("ref", "news")])
+            ),
+            "https://example.com/p?id=1&ref=news"
+        );
+        assert_eq!(
+            merge_query("product/42", &[], &pairs(&[("ref", "news")])),
+            "product/42?ref=news"
+        );
+    }
+
+    #[test]
+    fn test_merge_query_precedence() {
+        let merged = merge_query(
+            "https://example.com/?utm_medium=cpc&ref=site&id=1",
+            &[
+                ("utm_source", "link"),
+                ("utm_medium", "link"),
+                ("utm_campaign", "link"),
+            ],
+            &pairs(&[("ref", "visit"), ("utm_campaign", "visit")]),
+        );
+
+        // 방문 파라미터 > 목적지 파라미터 > UTM 기본값
+        assert_eq!(
+            merged,
+            "https://example.com/?utm_medium=cpc&id=1&ref=visit&utm_campaign=visit&utm_source=link"
+        );
+    }
+
+    #[test]
+    fn test_merge_query_encodes_values_and_keeps_the_rest() {
+        assert_eq!(
+            merge_query(
+                "https://example.com/a%20b?q=%ED%95%9C&x=1#top",
+                &[("utm_campaign", "spring sale")],
+                &pairs(&[("next", "/p?a=1&b=2"), ("name", "한")]),
+            ),
+            "https://example.com/a%20b?q=%ED%95%9C&x=1&next=%2Fp%3Fa%3D1%26b%3D2\
+             &name=%ED%95%9C&utm_campaign=spring+sale#top"
+        );
+    }
+
+    #[test]
+    fn test_merge_query_without_parameters_keeps_target() {
+        assert_eq!(
+            merge_query("https://example.com/?a=b%20c#f", &[], &[]),
+            "https://example.com/?a=b%20c#f"
+        );
+        assert_eq!(
+            merge_query("https://example.com/?&", &[], &pairs(&[("a", "1")])),
+            "https://example.com/?a=1"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! URL safety utilities.
+//!
+//! Checks the URLs of a link against the schemes its field accepts and the
+//! domain blocklist, so links cannot run scripts or lead to blocked sites.
+
+use crate::config::DomainBlocklist;
+use crate::error::AppError;
+
+/// Schemes the browser handles itself, never accepted as deep links.
+const BROWSER_SCHEMES: &[&str] = &[
+    "javascript",
+    "vbscript",
+    "data",
+    "file",
+    "blob",
+    "about",
+    "filesystem",
+    "view-source",
+];
+
+/// Schemes a URL field accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemePolicy {
+    /// `http` and `https` only, for pages opened in a browser (fallbacks,
+    /// routing rule and variant destinations)
+    Web,
+    /// `http(s)` and custom app schemes (`myapp://`), for deep links
+    App,
+}
+
+/// Checks a URL against the schemes of its field and the domain blocklist.
+///
+/// `field` names the request field in the error message. The blocklist
+/// applies to `http(s)` URLs, including deep links that are web links.
+///
+/// # Errors
+///
+/// Returns [`AppError::Validation`] if the URL is malformed, uses a scheme
+/// the field does not accept or points to a blocked domain
+pub fn check_url_safety(
+    field: &str,
+    value: &str,
+    policy: SchemePolicy,
+    blocklist: &DomainBlocklist,
+) -> Result<(), AppError> {
+    let url = url::Url::parse(value)
+        .map_err(|_| AppError::Validation(format!("{field} must be a valid URL")))?;
+
+    let scheme = url.scheme();
+    let is_web = matches!(scheme, "http" | "https");
+    if policy == SchemePolicy::Web && !is_web {
+        return Err(AppError::Validation(format!(
+            "{field} must be an http or https URL"
+        )));
+    }
+    if BROWSER_SCHEMES.contains(&scheme) {
+        return Err(AppError::Validation(format!(
+            "{field} must not use the '{scheme}' scheme"
+        )));
+    }
+
+    if is_web {
+        if let Some(domain) = url.host_str().and_then(|host| blocklist.blocked_by(host)) {
+            return Err(AppError::Validation(format!(
+                "{field} points to a blocked domain ({domain})"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocklist() -> DomainBlocklist {
+        let mut blocklist = DomainBlocklist::default();
+        blocklist.extend(["phish.example"]);
+        blocklist
+    }
+
+    fn message(result: Result<(), AppError>) -> String {
+        match result {
+            Err(AppError::Validation(message)) => message,
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    // ============ 스킴 허용 목록 테스트 ============
+
+    #[test]
+    fn test_web_policy_accepts_only_http() {
+        let blocklist = blocklist();
+        for url in ["https://example.com/a", "http://example.com"] {
+            assert!(check_url_safety("f", url, SchemePolicy::Web, &blocklist).is_ok());
+        }
+        assert_eq!(
+            message(check_url_safety(
+                "iosFallbackUrl",
+                "myapp://open",
+                SchemePolicy::Web,
+                &blocklist
+            )),
+            "iosFallbackUrl must be an http or https URL"
+        );
+        assert!(check_url_safety("f", "ftp://example.com", SchemePolicy::Web, &blocklist).is_err());
+    }
+
+    #[test]
+    fn test_app_policy_accepts_custom_schemes() {
+        let blocklist = blocklist();
+        for url in [
+            "myapp://product/42",
+            "fb://profile",
+            "intent://open#Intent;scheme=myapp;end",
+            "https://example.com/app",
+        ] {
+            assert!(
+                check_url_safety("f", url, SchemePolicy::App, &blocklist).is_ok(),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_browser_schemes_are_rejected() {
+        let blocklist = blocklist();
+        for url in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "vbscript:msgbox(1)",
+            "file:///etc/passwd",
+        ] {
+            assert!(
+                check_url_safety("f", url, SchemePolicy::App, &blocklist).is_err(),
+                "{url}"
+            );
+        }
+        assert_eq!(
+            message(check_url_safety(
+                "iosDeepLink",
+                "javascript:alert(1)",
+                SchemePolicy::App,
+                &blocklist
+            )),
+            "iosDeepLink must not use the 'javascript' scheme"
+        );
+    }
+
+    #[test]
+    fn test_malformed_url_is_rejected() {
+        assert_eq!(
+            message(check_url_safety(
+                "defaultFallbackUrl",
+                "not a url",
+                SchemePolicy::Web,
+                &blocklist()
+            )),
+            "defaultFallbackUrl must be a valid URL"
+        );
+    }
+
+    // ============ 도메인 차단 목록 테스트 ============
+
+    #[test]
+    fn test_blocked_domains_are_rejected() {
+        let blocklist = blocklist();
+        assert_eq!(
+            message(check_url_safety(
+                "defaultFallbackUrl",
+                "https://login.PHISH.example/account",
+                SchemePolicy::Web,
+                &blocklist
+            )),
+            "defaultFallbackUrl points to a blocked domain (phish.example)"
+        );
+        assert!(
+            check_url_safety("f", "https://phish.example", SchemePolicy::App, &blocklist).is_err()
+        );
+        // 커스텀 스킴의 호스트는 도메인이 아님
+        assert!(
+            check_url_safety("f", "myapp://phish.example", SchemePolicy::App, &blocklist).is_ok()
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Visitor identity utility module.
+//!
+//! Provides the long-lived cookie that identifies a returning visitor, so
+//! that per-visitor choices such as A/B variants stay the same across visits.
+
+use cookie::{time::Duration, Cookie, SameSite};
+
+use crate::config::APP_CONFIG;
+use crate::utils::gen_rand_str;
+
+/// Name of the visitor ID cookie.
+pub const VISITOR_COOKIE_NAME: &str = "visitor_id";
+
+/// Length of a generated visitor ID.
+const VISITOR_ID_LEN: usize = 22;
+
+/// Lifetime of the visitor ID cookie, in seconds (one year).
+const VISITOR_COOKIE_MAX_AGE_SECS: i64 = 365 * 86_400;
+
+/// Generates a new random visitor ID.
+#[must_use]
+pub fn new_visitor_id() -> String {
+    gen_rand_str(VISITOR_ID_LEN)
+}
+
+/// Reads the visitor ID from a request cookie.
+///
+/// Returns `None` for a missing cookie or a value that is not a generated ID.
+#[must_use]
+pub fn visitor_id(cookie: Option<&Cookie<'_>>) -> Option<String> {
+    cookie
+        .map(Cookie::value)
+        .filter(|id| id.len() == VISITOR_ID_LEN && id.chars().all(|c| c.is_ascii_alphanumeric()))
+        .map(str::to_string)
+}
+
+/// Builds the cookie carrying a visitor ID.
+#[must_use]
+pub fn visitor_cookie(visitor_id: String) -> Cookie<'static> {
+    Cookie::build((VISITOR_COOKIE_NAME, visitor_id))
+        .path("/")
+        .http_only(true)
+        .secure(APP_CONFIG.is_production)
+        .same_site(SameSite::Lax)
+        .max_age(Duration::seconds(VISITOR_COOKIE_MAX_AGE_SECS))
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ visitor_id 함수 테스트 ============
+
+    #[test]
+    fn test_visitor_id_roundtrip() {
+        let id = new_visitor_id();
+        let cookie = visitor_cookie(id.clone());
+
+        assert_eq!(cookie.name(), VISITOR_COOKIE_NAME);
+        assert_eq!(cookie.path(), Some("/"));
+        assert_eq!(cookie.http_only(), Some(true));
+        assert_eq!(visitor_id(Some(&cookie)), Some(id));
+    }
+
+    #[test]
+    fn test_visitor_id_rejects_foreign_values() {
+        assert!(visitor_id(None).is_none());
+        for value in [
+            "",
+            "short",
+            "0123456789abcdefghij-_",
+            "0123456789abcdefghijklm",
+        ] {
+            let cookie = Cookie::new(VISITOR_COOKIE_NAME, value);
+            assert!(visitor_id(Some(&cookie)).is_none(), "{value}");
+        }
+    }
+}
@@ -2,13 +2,18 @@
 //!
 //! Contains end-to-end tests for the URL shortening service.
 
+#![allow(clippy::needless_collect, clippy::map_unwrap_or)]
+
 use url_shortener::api::schemas::{
     validate_short_key, CreateShortUrlRequest, CreateShortUrlResponse,
 };
 use url_shortener::error::{AppError, AppResult};
-use url_shortener::models::{NewUrl, Url, UrlCacheData};
+use url_shortener::models::{
+    InAppBrowserMode, NewUrl, RedirectMode, RoutingRule, RoutingRules, RuleConditions, Url,
+    UrlCacheData, UrlVariants, UtmParams,
+};
 use url_shortener::utils::{
-    gen_rand_str, gen_token, merge_short_key, parse_token, split_short_key,
+    gen_rand_str, gen_token, merge_short_key, parse_token, split_short_key, Scope,
 };
 use validator::Validate;
 
@@ -23,11 +28,29 @@ fn test_url_shortening_flow_without_db() {
         ios_fallback_url: Some("https://apps.apple.com".to_string()),
         android_deep_link: Some("https://android.example.com".to_string()),
         android_fallback_url: Some("https://play.google.com".to_string()),
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: Some("https://example.com".to_string()),
         webhook_url: Some("https://webhook.example.com".to_string()),
         og_title: Some("Test Title".to_string()),
         og_description: Some("Test Description".to_string()),
         og_image_url: Some("https://example.com/image.png".to_string()),
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password: None,
     };
 
     // 2. 유효성 검사
@@ -56,7 +79,7 @@ fn test_url_shortening_flow_without_db() {
 fn test_jwt_authentication_flow() {
     // 1. 토큰 생성
     let subject = "test_user_123";
-    let token = gen_token(subject).expect("Failed to generate token");
+    let token = gen_token(subject, Scope::GUEST).expect("Failed to generate token");
 
     // 2. 토큰 구조 확인
     let parts: Vec<&str> = token.split('.').collect();
@@ -68,6 +91,10 @@ fn test_jwt_authentication_flow() {
 
     // 4. 만료 시간 확인
     assert!(claims.exp > claims.iat);
+
+    // 5. 게스트 스코프 확인 (관리자 권한 없음)
+    assert!(claims.has_scope(Scope::UrlsCreate));
+    assert!(!claims.has_scope(Scope::Admin));
 }
 
 /// Short key 생성 및 검증 통합 테스트
@@ -133,6 +160,18 @@ fn test_url_to_cache_data_conversion() {
         ios_fallback_url: Some("https://apps.apple.com".to_string()),
         android_deep_link: Some("app://android".to_string()),
         android_fallback_url: Some("https://play.google.com".to_string()),
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: "https://example.com".to_string(),
         hashed_value: "hash123".to_string(),
         webhook_url: Some("https://webhook.example.com".to_string()),
@@ -143,6 +182,15 @@ fn test_url_to_cache_data_conversion() {
         created_at: Utc::now(),
         updated_at: Utc::now(),
         deleted_at: None,
+        owner: "guest".to_string(),
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        click_count: 0,
+        alias: None,
+        password_hash: None,
+        webhook_secret: "test-webhook-secret".to_string(),
     };
 
     let cache_data: UrlCacheData = url.clone().into();
@@ -164,6 +212,18 @@ fn test_new_url_creation() {
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: "https://example.com".to_string(),
         hashed_value: "hash123abc".to_string(),
         webhook_url: None,
@@ -171,6 +231,13 @@ fn test_new_url_creation() {
         og_description: None,
         og_image_url: None,
         is_active: true,
+        owner: "guest".to_string(),
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password_hash: None,
     };
 
     assert_eq!(new_url.random_key.len(), 4);
@@ -205,12 +272,30 @@ fn test_url_cache_data_messagepack_serialization() {
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: "https://example.com".to_string(),
         webhook_url: None,
         og_title: Some("Title".to_string()),
         og_description: None,
         og_image_url: None,
         is_active: true,
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        password_hash: None,
+        alias: None,
     };
 
     // MessagePack 직렬화
@@ -237,11 +322,29 @@ fn test_request_validation_scenarios() {
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: Some("https://example.com".to_string()),
         webhook_url: None,
         og_title: None,
         og_description: None,
         og_image_url: None,
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password: None,
     };
     assert!(valid_req.validate().is_ok());
 
@@ -251,11 +354,29 @@ fn test_request_validation_scenarios() {
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: None,
         webhook_url: None,
         og_title: None,
         og_description: None,
         og_image_url: None,
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password: None,
     };
     assert!(missing_url.validate().is_err());
 
@@ -265,29 +386,123 @@ fn test_request_validation_scenarios() {
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: Some("not-a-valid-url".to_string()),
         webhook_url: None,
         og_title: None,
         og_description: None,
         og_image_url: None,
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password: None,
     };
     assert!(invalid_url.validate().is_err());
+}
 
+#[test]
+fn test_og_field_validation_scenarios() {
     // OG 필드 길이 초과
     let long_title = CreateShortUrlRequest {
         ios_deep_link: None,
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: Some("https://example.com".to_string()),
         webhook_url: None,
         og_title: Some("a".repeat(256)),
         og_description: None,
         og_image_url: None,
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password: None,
     };
     assert!(long_title.validate().is_err());
 }
 
+#[test]
+fn test_routing_rule_validation_scenarios() {
+    let rule = |destination: &str| RoutingRule {
+        conditions: RuleConditions {
+            countries: Some(vec!["kr".to_string()]),
+            ..RuleConditions::default()
+        },
+        destination: destination.to_string(),
+    };
+
+    // 유효한 규칙
+    let req = CreateShortUrlRequest {
+        ios_deep_link: None,
+        ios_fallback_url: None,
+        android_deep_link: None,
+        android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules(vec![rule("https://example.kr")]),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
+        default_fallback_url: Some("https://example.com".to_string()),
+        webhook_url: None,
+        og_title: None,
+        og_description: None,
+        og_image_url: None,
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password: None,
+    };
+    assert!(req.validate().is_ok());
+    let rules = req.routing_rules.normalize().expect("Valid routing rules");
+    assert_eq!(
+        rules.0[0].conditions.countries,
+        Some(vec!["KR".to_string()])
+    );
+
+    // 잘못된 목적지 URL
+    let invalid_destination = RoutingRules(vec![rule("not-a-valid-url")]);
+    assert!(matches!(
+        invalid_destination.normalize(),
+        Err(AppError::Validation(_))
+    ));
+}
+
 // ============ 랜덤 문자열 통합 테스트 ============
 
 #[test]
@@ -335,7 +550,7 @@ fn test_hash_generation_for_duplicate_detection() {
 
 #[test]
 fn test_jwt_claims_expiration() {
-    let token = gen_token("test_user").expect("Failed to generate token");
+    let token = gen_token("test_user", Scope::GUEST).expect("Failed to generate token");
     let claims = parse_token(&token).expect("Failed to parse token");
 
     let now = chrono::Utc::now().timestamp();
@@ -420,6 +635,18 @@ fn test_complete_url_creation_simulation() {
         ios_fallback_url: None,
         android_deep_link: None,
         android_fallback_url: None,
+        android_package: None,
+        android_scheme: None,
+        android_path: None,
+        desktop_deep_link: None,
+        macos_fallback_url: None,
+        windows_fallback_url: None,
+        linux_fallback_url: None,
+        routing_rules: RoutingRules::default(),
+        variants: UrlVariants::default(),
+        utm_params: UtmParams::default(),
+        query_passthrough: false,
+        allow_path_forwarding: false,
         default_fallback_url: default_fallback.to_string(),
         hashed_value,
         webhook_url: None,
@@ -427,6 +654,13 @@ fn test_complete_url_creation_simulation() {
         og_description: None,
         og_image_url: None,
         is_active: true,
+        owner: "guest".to_string(),
+        redirect_mode: RedirectMode::Interstitial,
+        in_app_browser_mode: InAppBrowserMode::Escape,
+        expires_at: None,
+        max_clicks: None,
+        alias: None,
+        password_hash: None,
     };
 
     // 5. ID 시뮬레이션 (DB에서 반환될 값)